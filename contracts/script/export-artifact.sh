@@ -0,0 +1,18 @@
+#!/usr/bin/env bash
+# Exports the Distributor artifact embedded in the crate by the `embedded-contracts` feature
+# to src/distributor/artifacts. Run it after changing contracts/src/Distributor.sol and commit
+# the result; CI fails when the committed artifact differs from a fresh `forge build`.
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+
+forge build
+
+source_hash=$(cast keccak "0x$(od -An -v -tx1 src/Distributor.sol | tr -d ' \n')")
+
+jq --arg source_hash "$source_hash" '{
+    compiler_version: .metadata.compiler.version,
+    source_hash: $source_hash,
+    abi: .abi,
+    bytecode: .bytecode.object
+}' out/Distributor.sol/Distributor.json > ../src/distributor/artifacts/Distributor.json
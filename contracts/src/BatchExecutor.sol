@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.27;
+
+import {ECDSA} from "@openzeppelin-contracts-5.1.0/utils/cryptography/ECDSA.sol";
+import {MessageHashUtils} from "@openzeppelin-contracts-5.1.0/utils/cryptography/MessageHashUtils.sol";
+import {ERC721Holder} from "@openzeppelin-contracts-5.1.0/token/ERC721/utils/ERC721Holder.sol";
+import {ERC1155Holder} from "@openzeppelin-contracts-5.1.0/token/ERC1155/utils/ERC1155Holder.sol";
+
+/**
+ * @dev Error that occurs when a delegated call is not initiated by the batch executor.
+ * @param caller The address that attempted the delegated call.
+ */
+error UnauthorizedCaller(address caller);
+
+/**
+ * @dev Error that occurs when a delegated call has failed.
+ * @param account The delegated account that performed the call.
+ * @param target The address that was called.
+ */
+error DelegatedCallFail(address account, address target);
+
+/**
+ * @dev Error that occurs when an intent is used after its deadline.
+ * @param deadline The timestamp after which the intent is no longer valid.
+ */
+error IntentExpired(uint256 deadline);
+
+/**
+ * @dev Error that occurs when an intent is not signed by the delegated account.
+ * @param account The delegated account the intent was presented to.
+ * @param signer The address that signed the intent.
+ */
+error InvalidIntentSigner(address account, address signer);
+
+/**
+ * @dev Error that occurs when the number of accounts and signatures differ.
+ */
+error LengthMismatch();
+
+/**
+ * @title EIP-7702 Batch Executor
+ * @author Confucian-e
+ * @notice Delegation target that lets a single sponsored transaction
+ * perform the same call on behalf of many EOAs.
+ * @dev Every account only performs a call it signed an intent for, bound to the chain,
+ * this executor, the target, the calldata, a nonce and a deadline, so nobody can make
+ * a delegated account call anything else. Delegated accounts keep accepting ether and
+ * safe ERC721 and ERC1155 transfers like the EOAs they were.
+ */
+contract BatchExecutor is ERC721Holder, ERC1155Holder {
+    /**
+     * @dev Storage slot of the intent nonce in the delegated account, namespaced so it
+     * does not collide with the storage of other delegation targets.
+     */
+    bytes32 private constant NONCE_SLOT = keccak256("stormint.BatchExecutor.nonce");
+
+    /**
+     * @dev Address of the deployed executor. Immutables live in the runtime
+     * bytecode, so delegated accounts read the same value.
+     */
+    address private immutable SELF;
+
+    constructor() payable {
+        SELF = address(this);
+    }
+
+    /**
+     * @dev Accepts plain ether transfers, e.g. the funding of a delegated account.
+     */
+    receive() external payable {}
+
+    /**
+     * @dev Performs `target.call(data)` in the context of the delegated account, if the
+     * account signed the intent.
+     * @notice Only callable by the batch executor itself, and only with an intent signed
+     * by the delegated account, which consumes its nonce.
+     * @param target the address to call
+     * @param data the calldata to forward
+     * @param deadline the timestamp after which the intent is no longer valid
+     * @param signature the EIP-191 signature of `intentHash` by the delegated account
+     */
+    function execute(address target, bytes calldata data, uint256 deadline, bytes calldata signature) external {
+        require(msg.sender == SELF, UnauthorizedCaller(msg.sender));
+        require(block.timestamp <= deadline, IntentExpired(deadline));
+
+        bytes32 digest = MessageHashUtils.toEthSignedMessageHash(intentHash(target, data, _useNonce(), deadline));
+        address signer = ECDSA.recover(digest, signature);
+        require(signer == address(this), InvalidIntentSigner(address(this), signer));
+
+        // solhint-disable-next-line avoid-low-level-calls
+        (bool success,) = target.call(data);
+        require(success, DelegatedCallFail(address(this), target));
+    }
+
+    /**
+     * @dev Makes every delegated account in `accounts` call `target` with `data`.
+     * @param accounts the delegated accounts acting as callers
+     * @param target the address to call
+     * @param data the calldata to forward
+     * @param deadline the timestamp after which the intents are no longer valid
+     * @param signatures the signed intent of every account, in the order of `accounts`
+     */
+    function executeFor(
+        address[] calldata accounts,
+        address target,
+        bytes calldata data,
+        uint256 deadline,
+        bytes[] calldata signatures
+    ) external {
+        uint256 length = accounts.length;
+        require(signatures.length == length, LengthMismatch());
+
+        for (uint256 i; i < length; ++i) {
+            BatchExecutor(accounts[i]).execute(target, data, deadline, signatures[i]);
+        }
+    }
+
+    /**
+     * @dev Returns the hash an account signs to allow a call, with the EIP-191 prefix
+     * added on top. Called on a delegated account, it binds the intent to that account.
+     * @param target the address to call
+     * @param data the calldata to forward
+     * @param intentNonce the nonce of the account
+     * @param deadline the timestamp after which the intent is no longer valid
+     */
+    function intentHash(address target, bytes calldata data, uint256 intentNonce, uint256 deadline)
+        public
+        view
+        returns (bytes32)
+    {
+        return keccak256(abi.encode(block.chainid, SELF, address(this), target, keccak256(data), intentNonce, deadline));
+    }
+
+    /**
+     * @dev Returns the next intent nonce of the delegated account.
+     */
+    function nonce() public view returns (uint256 current) {
+        bytes32 slot = NONCE_SLOT;
+        // solhint-disable-next-line no-inline-assembly
+        assembly {
+            current := sload(slot)
+        }
+    }
+
+    /**
+     * @dev Returns the current intent nonce of the delegated account and increments it.
+     */
+    function _useNonce() private returns (uint256 current) {
+        current = nonce();
+        bytes32 slot = NONCE_SLOT;
+        // solhint-disable-next-line no-inline-assembly
+        assembly {
+            sstore(slot, add(current, 1))
+        }
+    }
+}
@@ -1,6 +1,9 @@
 // SPDX-License-Identifier: MIT
 pragma solidity ^0.8.27;
 
+import {IERC20} from "@openzeppelin-contracts-5.1.0/token/ERC20/IERC20.sol";
+import {SafeERC20} from "@openzeppelin-contracts-5.1.0/token/ERC20/utils/SafeERC20.sol";
+
 /**
  * @dev Transaction struct for the transaction payload.
  */
@@ -9,6 +12,31 @@ struct Transaction {
     uint256 amount;
 }
 
+/**
+ * @dev Transaction struct for the transaction payload carrying a memo.
+ */
+struct TransactionWithMemo {
+    address payable recipient;
+    uint256 amount;
+    bytes memo;
+}
+
+/**
+ * @dev Emitted for every ether distribution.
+ * @param sender The address that funded the distribution.
+ * @param recipients The addresses that received the ether.
+ * @param amounts The amounts of ether, denominated in wei, in the same order as `recipients`.
+ */
+event Distribute(address indexed sender, address[] recipients, uint256[] amounts);
+
+/**
+ * @dev Emitted for every recipient of a distribution with memo.
+ * @param recipient The address that received the ether.
+ * @param amount The amount of ether, denominated in wei.
+ * @param memo The tag attached to the transfer.
+ */
+event Memo(address indexed recipient, uint256 amount, bytes memo);
+
 /**
  * @dev Error that occurs when transferring ether has failed.
  * @param sender The address that attempted to transfer the ether.
@@ -19,9 +47,11 @@ error EtherTransferFail(address sender, address receiver);
 /**
  * @title Native Token Distributor
  * @author Confucian-e
- * @notice Helper smart contract for batch sending native tokens.
+ * @notice Helper smart contract for batch sending native and ERC20 tokens.
  */
 contract Distributor {
+    using SafeERC20 for IERC20;
+
     /**
      * @dev You can cut out 10 opcodes to save gas in the creation-time EVM bytecode
      * if you declare a constructor `payable`.
@@ -50,14 +80,73 @@ contract Distributor {
          * @dev If a variable is not set/initialised, it is assumed to have
          * the default value. The default value for the `uint` types is 0.
          */
+        address[] memory recipients = new address[](length);
+        uint256[] memory amounts = new uint256[](length);
+
         for (uint256 i; i < length; ++i) {
+            address recipient = txns[i].recipient;
+            uint256 amount = txns[i].amount;
             // solhint-disable-next-line avoid-low-level-calls
+            (bool sent,) = recipient.call{value: amount}("");
+            require(sent, EtherTransferFail(sender, recipient));
+            recipients[i] = recipient;
+            amounts[i] = amount;
+        }
+
+        emit Distribute(msg.sender, recipients, amounts);
+        _refund(sender);
+    }
+
+    /**
+     * @dev Distributes ether, denominated in wei, to a predefined batch
+     * of recipient addresses, emitting a `Memo` event for each of them.
+     * @notice In the event that excessive ether is sent, the residual
+     * amount is returned back to the `msg.sender`.
+     * @param txns the array of TransactionWithMemo
+     */
+    function distributeEtherWithMemo(TransactionWithMemo[] calldata txns) external payable {
+        address sender = address(this);
+        uint256 length = txns.length;
+        address[] memory recipients = new address[](length);
+        uint256[] memory amounts = new uint256[](length);
+
+        for (uint256 i; i < length; ++i) {
             address recipient = txns[i].recipient;
             uint256 amount = txns[i].amount;
+            // solhint-disable-next-line avoid-low-level-calls
             (bool sent,) = recipient.call{value: amount}("");
             require(sent, EtherTransferFail(sender, recipient));
+            emit Memo(recipient, amount, txns[i].memo);
+            recipients[i] = recipient;
+            amounts[i] = amount;
         }
 
+        emit Distribute(msg.sender, recipients, amounts);
+        _refund(sender);
+    }
+
+    /**
+     * @dev Distributes ERC20 tokens from the `msg.sender` to a predefined
+     * batch of recipient addresses.
+     * @notice The `msg.sender` must have approved this contract to spend
+     * at least the sum of all amounts.
+     * @param token the ERC20 token to distribute
+     * @param txns the array of Transaction
+     */
+    function distributeToken(IERC20 token, Transaction[] calldata txns) external {
+        address sender = msg.sender;
+        uint256 length = txns.length;
+
+        for (uint256 i; i < length; ++i) {
+            token.safeTransferFrom(sender, txns[i].recipient, txns[i].amount);
+        }
+    }
+
+    /**
+     * @dev Returns the remaining balance of this contract to the `msg.sender`.
+     * @param sender the address of this contract
+     */
+    function _refund(address sender) private {
         uint256 balance = sender.balance;
         if (balance != 0) {
             address receiver = msg.sender;
@@ -13,7 +13,7 @@ contract FreeMint is ERC20 {
     uint256 public constant MAX_SUPPLY = 210000000000 * 1 ether;
     uint256 public constant MINT_AMOUNT = 5000000 * 1 ether;
 
-    mapping(address => bool) private hasMinted;
+    mapping(address => bool) public hasMinted;
 
     constructor() ERC20("Daram", "Daram") {}
 
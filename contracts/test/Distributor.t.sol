@@ -2,7 +2,8 @@
 pragma solidity ^0.8.27;
 
 import {Test} from "forge-std/Test.sol";
-import {Distributor, Transaction} from "../src/Distributor.sol";
+import {Distributor, Transaction, TransactionWithMemo, Memo} from "../src/Distributor.sol";
+import {MockToken} from "./mocks/MockToken.sol";
 
 contract DistributorTest is Test {
     Distributor public distributor;
@@ -35,4 +36,39 @@ contract DistributorTest is Test {
         assertEq(alice.balance, amount_alice);
         assertEq(bob.balance, amount_bob);
     }
+
+    function test_distributeEtherWithMemo() public {
+        address alice = makeAddr("Alice");
+        address bob = makeAddr("Bob");
+
+        TransactionWithMemo[] memory txns = new TransactionWithMemo[](2);
+        txns[0] = TransactionWithMemo(payable(alice), 1 ether, "batch-1");
+        txns[1] = TransactionWithMemo(payable(bob), 2 ether, "");
+
+        deal(address(this), 3 ether);
+        vm.expectEmit(true, false, false, true);
+        emit Memo(alice, 1 ether, "batch-1");
+        distributor.distributeEtherWithMemo{value: 3 ether}(txns);
+
+        assertEq(alice.balance, 1 ether);
+        assertEq(bob.balance, 2 ether);
+    }
+
+    function test_distributeToken() public {
+        MockToken token = new MockToken();
+        address alice = makeAddr("Alice");
+        address bob = makeAddr("Bob");
+
+        Transaction[] memory txns = new Transaction[](2);
+        txns[0] = Transaction(payable(alice), 1 ether);
+        txns[1] = Transaction(payable(bob), 2 ether);
+
+        token.mintTo(address(this), 3 ether);
+        token.approve(address(distributor), 3 ether);
+        distributor.distributeToken(token, txns);
+
+        assertEq(token.balanceOf(alice), 1 ether);
+        assertEq(token.balanceOf(bob), 2 ether);
+        assertEq(token.balanceOf(address(this)), 0);
+    }
 }
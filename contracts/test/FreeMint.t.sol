@@ -18,6 +18,15 @@ contract FreeMintTest is Test {
         assertEq(freeMint.balanceOf(alice), freeMint.MINT_AMOUNT());
     }
 
+    function test_hasMinted() public {
+        address alice = makeAddr("Alice");
+        assertFalse(freeMint.hasMinted(alice));
+
+        vm.prank(alice, alice);
+        freeMint.mint();
+        assertTrue(freeMint.hasMinted(alice));
+    }
+
     function testFail_doubleMint() public {
         address alice = makeAddr("Alice");
         vm.startPrank(alice, alice);
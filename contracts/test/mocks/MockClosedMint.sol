@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.27;
+
+/**
+ * @title Mock Closed Mint
+ * @notice Mint whose sale never opens, so every mint reverts; used by the Rust test suite.
+ */
+contract MockClosedMint {
+    function mint() external pure {
+        revert("Sale not started");
+    }
+}
@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.27;
+
+/**
+ * @title Mock Disperse
+ * @notice Batch sender with the calling convention of Disperse.app, used by the Rust test suite.
+ * @dev Recipients and values are passed as two parallel arrays.
+ */
+contract MockDisperse {
+    function disperseEther(address[] calldata recipients, uint256[] calldata values) external payable {
+        require(recipients.length == values.length, "Length mismatch");
+
+        for (uint256 i = 0; i < recipients.length; i++) {
+            payable(recipients[i]).transfer(values[i]);
+        }
+
+        uint256 balance = address(this).balance;
+        if (balance > 0) {
+            payable(msg.sender).transfer(balance);
+        }
+    }
+}
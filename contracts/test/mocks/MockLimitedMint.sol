@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.27;
+
+import {ERC20} from "@openzeppelin-contracts-5.1.0/token/ERC20/ERC20.sol";
+
+/**
+ * @title Mock Limited Mint
+ * @notice ERC20 allowing a few mints per address, used by the Rust test suite.
+ */
+contract MockLimitedMint is ERC20 {
+    uint256 public constant MINT_AMOUNT = 1000 * 1 ether;
+    uint256 public constant MAX_MINTS_PER_ADDRESS = 3;
+
+    mapping(address => uint256) public mintCount;
+
+    constructor() ERC20("Limited", "LMT") {}
+
+    function mint() external {
+        require(mintCount[msg.sender] < MAX_MINTS_PER_ADDRESS, "Mint allowance exhausted");
+
+        mintCount[msg.sender] += 1;
+        _mint(msg.sender, MINT_AMOUNT);
+    }
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.27;
+
+import {ERC20} from "@openzeppelin-contracts-5.1.0/token/ERC20/ERC20.sol";
+import {MerkleProof} from "@openzeppelin-contracts-5.1.0/utils/cryptography/MerkleProof.sol";
+
+/**
+ * @title Mock Merkle Mint
+ * @notice Whitelist ERC20 used by the Rust test suite.
+ * @dev Leaves are `keccak256(abi.encodePacked(account))`, and pairs are hashed
+ * in sorted order as expected by `MerkleProof`.
+ */
+contract MockMerkleMint is ERC20 {
+    uint256 public constant MINT_AMOUNT = 1000 * 1 ether;
+
+    bytes32 public immutable MERKLE_ROOT;
+
+    constructor(bytes32 merkleRoot) ERC20("Mock Merkle", "MMKL") {
+        MERKLE_ROOT = merkleRoot;
+    }
+
+    function mint(bytes32[] calldata proof) external {
+        bytes32 leaf = keccak256(abi.encodePacked(msg.sender));
+        require(MerkleProof.verify(proof, MERKLE_ROOT, leaf), "Invalid proof");
+
+        _mint(msg.sender, MINT_AMOUNT);
+    }
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.27;
+
+/**
+ * @title Mock MultiSend
+ * @notice Batch caller with the encoding of Safe's MultiSendCallOnly, used by the Rust test suite.
+ * @dev Every call is packed as `operation (uint8, always 0) | to (address) | value (uint256)
+ * | data length (uint256) | data`. A failing call reverts the whole batch.
+ */
+contract MockMultiSend {
+    function multiSend(bytes calldata transactions) external payable {
+        uint256 i;
+        while (i < transactions.length) {
+            require(uint8(transactions[i]) == 0, "Only calls are supported");
+
+            address to = address(bytes20(transactions[i + 1:i + 21]));
+            uint256 value = uint256(bytes32(transactions[i + 21:i + 53]));
+            uint256 dataLength = uint256(bytes32(transactions[i + 53:i + 85]));
+            bytes calldata data = transactions[i + 85:i + 85 + dataLength];
+
+            // solhint-disable-next-line avoid-low-level-calls
+            (bool success,) = to.call{value: value}(data);
+            require(success, "Call failed");
+
+            i += 85 + dataLength;
+        }
+    }
+}
@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.27;
+
+import {ERC721} from "@openzeppelin-contracts-5.1.0/token/ERC721/ERC721.sol";
+
+/**
+ * @title Mock NFT
+ * @notice Unrestricted ERC721 used by the Rust test suite.
+ * @dev Token ids are assigned sequentially starting from zero.
+ */
+contract MockNFT is ERC721 {
+    uint256 public nextTokenId;
+
+    constructor() ERC721("Mock NFT", "MNFT") {}
+
+    function mint() external {
+        _mint(msg.sender, nextTokenId++);
+    }
+}
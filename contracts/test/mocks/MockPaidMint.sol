@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.27;
+
+/**
+ * @title Mock Paid Mint
+ * @notice Payable mint used by the Rust test suite.
+ * @dev Any non-zero price is accepted and recorded per minter, so tests can
+ * check the value every account paid.
+ */
+contract MockPaidMint {
+    mapping(address => uint256) public paid;
+
+    function mint() external payable {
+        require(msg.value > 0, "Mint is not free");
+        paid[msg.sender] += msg.value;
+    }
+}
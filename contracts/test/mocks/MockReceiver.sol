@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.27;
+
+/**
+ * @title Mock Receiver
+ * @notice Contract whose `receive` function needs more gas than a plain transfer provides;
+ * used by the Rust test suite.
+ */
+contract MockReceiver {
+    uint256 public received;
+
+    receive() external payable {
+        received += msg.value;
+    }
+}
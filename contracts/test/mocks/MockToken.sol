@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.27;
+
+import {ERC20} from "@openzeppelin-contracts-5.1.0/token/ERC20/ERC20.sol";
+
+/**
+ * @title Mock Token
+ * @notice Unrestricted ERC20 used by the Rust test suite.
+ * @dev Unlike `FreeMint`, contracts (including EIP-7702 delegated accounts)
+ * are allowed to mint, and every address may mint more than once.
+ */
+contract MockToken is ERC20 {
+    uint256 public constant MINT_AMOUNT = 1000 * 1 ether;
+
+    constructor() ERC20("Mock", "MOCK") {}
+
+    function mint() external {
+        _mint(msg.sender, MINT_AMOUNT);
+    }
+
+    function mintTo(address to, uint256 amount) external {
+        _mint(to, amount);
+    }
+}
@@ -0,0 +1,95 @@
+use alloy::primitives::Address;
+
+/// Checks whether an address string carries a valid EIP-55 checksum.
+///
+/// The check catches typos that still parse as an address, since a wrongly cased letter
+/// breaks the checksum. Addresses whose checksum happens to use a single case pass, but
+/// other all-lowercase or all-uppercase strings do not, as they carry no checksum.
+///
+/// # Arguments
+///
+/// * `address_str` - The hex address, with or without the `0x` prefix.
+///
+/// # Returns
+///
+/// * `bool` - `true` if the string is a 20-byte address cased as its EIP-55 checksum.
+pub fn has_valid_eip55_checksum(address_str: &str) -> bool {
+    let hex = address_str.strip_prefix("0x").unwrap_or(address_str);
+    if hex.len() != 40 {
+        return false;
+    }
+
+    match hex.parse::<Address>() {
+        Ok(address) => to_eip55_checksum(address)[2..] == *hex,
+        Err(_) => false,
+    }
+}
+
+/// Formats an address with its EIP-55 mixed-case checksum.
+///
+/// # Arguments
+///
+/// * `address` - The address to format.
+///
+/// # Returns
+///
+/// * `String` - The `0x`-prefixed checksummed address.
+pub fn to_eip55_checksum(address: Address) -> String {
+    address.to_checksum(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the test vectors of EIP-55
+    const VECTORS: [&str; 8] = [
+        "0x52908400098527886E0F7030069857D2E4169EE7",
+        "0x8617E340B3D01FA5F11F306F4090FD50E238070D",
+        "0xde709f2102306220921060314715629080e2fb77",
+        "0x27b1fdb04752bbc536007a920d24acb045561c26",
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+        "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+        "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+    ];
+
+    #[test]
+    fn test_valid_checksums() {
+        for vector in VECTORS {
+            assert!(has_valid_eip55_checksum(vector), "{vector}");
+            assert!(has_valid_eip55_checksum(&vector[2..]), "{vector}");
+        }
+    }
+
+    #[test]
+    fn test_to_checksum() {
+        for vector in VECTORS {
+            let address: Address = vector.parse().unwrap();
+            assert_eq!(to_eip55_checksum(address), vector);
+        }
+    }
+
+    #[test]
+    fn test_invalid_checksums() {
+        // one letter with the wrong case
+        assert!(!has_valid_eip55_checksum(
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"
+        ));
+        // no checksum at all
+        assert!(!has_valid_eip55_checksum(
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"
+        ));
+        assert!(!has_valid_eip55_checksum(
+            "0x5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED"
+        ));
+        // not an address
+        assert!(!has_valid_eip55_checksum(
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA"
+        ));
+        assert!(!has_valid_eip55_checksum(
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAzz"
+        ));
+        assert!(!has_valid_eip55_checksum(""));
+    }
+}
@@ -1,11 +1,14 @@
+use crate::account::{account_path, MnemonicStrength, BIP44_ETH_PATH_PREFIX};
+use crate::progress::ProgressBarReporter;
 use alloy::signers::local::{coins_bip39::English, MnemonicBuilder, PrivateKeySigner};
 use eyre::Result;
-use indicatif::{ProgressBar, ProgressStyle};
 use rayon::prelude::*;
-use std::sync::Mutex;
 
 /// Generates multiple Ethereum accounts from a single mnemonic phrase.
 ///
+/// Accounts are derived under [`BIP44_ETH_PATH_PREFIX`]. Both 12 and 24-word phrases are
+/// accepted; use [`GenerateOptions::strength`] to require one of them.
+///
 /// # Arguments
 ///
 /// * `mnemonic` - A BIP39 mnemonic phrase string
@@ -20,37 +23,131 @@ pub fn generate_accounts(
     start_index: u32,
     end_index: u32,
 ) -> Result<Vec<PrivateKeySigner>> {
+    generate_accounts_with_path(mnemonic, BIP44_ETH_PATH_PREFIX, start_index, end_index)
+}
+
+/// Options for account generation.
+///
+/// # Fields
+///
+/// * `path_prefix` - The derivation path prefix, e.g. [`BIP44_ETH_PATH_PREFIX`]
+/// * `announce` - Whether to print the count and the first/last addresses once generation completes
+/// * `strength` - The required length of the mnemonic phrase; if `None`, both 12 and 24 words
+///   are accepted
+#[derive(Debug, Clone)]
+pub struct GenerateOptions {
+    pub path_prefix: String,
+    pub announce: bool,
+    pub strength: Option<MnemonicStrength>,
+}
+
+impl Default for GenerateOptions {
+    fn default() -> Self {
+        Self {
+            path_prefix: BIP44_ETH_PATH_PREFIX.to_string(),
+            announce: false,
+            strength: None,
+        }
+    }
+}
+
+/// Generates multiple accounts from a single mnemonic phrase under a custom derivation path.
+///
+/// # Arguments
+///
+/// * `mnemonic` - A BIP39 mnemonic phrase string
+/// * `path_prefix` - The derivation path prefix, e.g. [`BIP44_ETH_PATH_PREFIX`]
+/// * `start_index` - The starting index for the derivation path
+/// * `end_index` - The ending index for the derivation path (exclusive)
+///
+/// # Returns
+///
+/// * `Result<Vec<PrivateKeySigner>>` - A vector of private key signers on success
+pub fn generate_accounts_with_path(
+    mnemonic: &str,
+    path_prefix: &str,
+    start_index: u32,
+    end_index: u32,
+) -> Result<Vec<PrivateKeySigner>> {
+    let options = GenerateOptions {
+        path_prefix: path_prefix.to_string(),
+        ..Default::default()
+    };
+
+    generate_accounts_with_options(mnemonic, start_index, end_index, &options)
+}
+
+/// Generates multiple accounts from a single mnemonic phrase with custom options.
+///
+/// # Arguments
+///
+/// * `mnemonic` - A BIP39 mnemonic phrase string
+/// * `start_index` - The starting index for the derivation path
+/// * `end_index` - The ending index for the derivation path (exclusive)
+/// * `options` - The derivation path prefix, announcement and phrase length settings
+///
+/// # Returns
+///
+/// * `Result<Vec<PrivateKeySigner>>` - A vector of private key signers, ordered by index, on success
+pub fn generate_accounts_with_options(
+    mnemonic: &str,
+    start_index: u32,
+    end_index: u32,
+    options: &GenerateOptions,
+) -> Result<Vec<PrivateKeySigner>> {
+    if let Some(strength) = options.strength {
+        strength.validate(mnemonic)?;
+    }
+
     let account_count = end_index - start_index;
 
     // set process bar
-    let pb = ProgressBar::new(account_count as u64);
-    pb.set_style(ProgressStyle::default_bar()
-        .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} accounts generation ({percent}%) ETA: {eta_precise}")?
-        .progress_chars("=>-"));
-
-    // preallocate a vector and create a structure for storing the results
-    let accounts = Mutex::new(Vec::with_capacity(account_count as usize));
+    let pb = ProgressBarReporter::new(account_count as u64, "accounts generation")?;
 
     // generate initial builder
     let builder = MnemonicBuilder::<English>::default().phrase(mnemonic);
 
-    // parallel account generation
-    (start_index..end_index)
+    // parallel account generation, collected in index order
+    let accounts = (start_index..end_index)
         .into_par_iter()
-        .try_for_each(|index| -> Result<()> {
-            let wallet = builder.clone().index(index)?.build()?;
-            accounts.lock().unwrap().push(wallet);
+        .map(|index| -> Result<PrivateKeySigner> {
+            let wallet = builder
+                .clone()
+                .derivation_path(account_path(&options.path_prefix, index))?
+                .build()?;
             pb.inc(1);
-            Ok(())
-        })?;
+            Ok(wallet)
+        })
+        .collect::<Result<Vec<_>>>()?;
 
-    // finish process bar and fetch result
+    // finish process bar
     pb.finish_with_message("Account generation completed successfully!");
-    let accounts = accounts.into_inner()?;
+
+    if options.announce {
+        if let Some(announcement) = announcement(&accounts) {
+            println!("{announcement}");
+        }
+    }
 
     Ok(accounts)
 }
 
+/// Formats the count and the first/last addresses of generated accounts.
+///
+/// # Returns
+///
+/// * `Option<String>` - The announcement, or `None` if no account was generated
+fn announcement(accounts: &[PrivateKeySigner]) -> Option<String> {
+    let (first, last) = (accounts.first()?, accounts.last()?);
+
+    Some(format!(
+        "Generated {} accounts: first={} last={}",
+        accounts.len(),
+        first.address(),
+        last.address()
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -79,4 +176,69 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_accounts_generation_with_default_path() {
+        let accounts = generate_accounts(PHRASE, 0, 1).unwrap();
+        let with_path = generate_accounts_with_path(PHRASE, BIP44_ETH_PATH_PREFIX, 0, 1).unwrap();
+
+        assert_eq!(accounts[0].address(), with_path[0].address());
+        assert_eq!(
+            crate::account::BIP44_ETH_ACCOUNT_PATH(0),
+            "m/44'/60'/0'/0/0"
+        );
+    }
+
+    #[test]
+    fn test_accounts_generation_with_other_path() {
+        let eth = generate_accounts(PHRASE, 0, 1).unwrap();
+        let etc = generate_accounts_with_path(PHRASE, crate::account::BIP44_ETC_PATH_PREFIX, 0, 1)
+            .unwrap();
+
+        assert_ne!(eth[0].address(), etc[0].address());
+    }
+
+    #[test]
+    fn test_accounts_generation_order() {
+        let accounts = generate_accounts(PHRASE, 0, 20).unwrap();
+
+        for (index, account) in accounts.iter().enumerate() {
+            let expected = generate_accounts(PHRASE, index as u32, index as u32 + 1).unwrap();
+            assert_eq!(account.address(), expected[0].address());
+        }
+    }
+
+    #[test]
+    fn test_accounts_generation_with_strength() {
+        let phrase_24 = format!("{}art", "abandon ".repeat(23));
+        let options = GenerateOptions {
+            strength: Some(MnemonicStrength::TwentyFour),
+            ..Default::default()
+        };
+
+        let accounts_24 = generate_accounts_with_options(&phrase_24, 0, 3, &options).unwrap();
+        assert!(generate_accounts_with_options(PHRASE, 0, 3, &options).is_err());
+
+        // same word, different length
+        let phrase_12 = format!("{}about", "abandon ".repeat(11));
+        let accounts_12 = generate_accounts(&phrase_12, 0, 3).unwrap();
+        for (account_12, account_24) in accounts_12.iter().zip(&accounts_24) {
+            assert_ne!(account_12.address(), account_24.address());
+        }
+    }
+
+    #[test]
+    fn test_announcement() {
+        let accounts = generate_accounts(PHRASE, 0, 3).unwrap();
+
+        assert_eq!(
+            announcement(&accounts).unwrap(),
+            format!(
+                "Generated 3 accounts: first={} last={}",
+                accounts[0].address(),
+                accounts[2].address()
+            )
+        );
+        assert!(announcement(&[]).is_none());
+    }
 }
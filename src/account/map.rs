@@ -0,0 +1,123 @@
+use alloy::{primitives::Address, signers::local::PrivateKeySigner};
+use std::{collections::HashMap, fmt, ops::Deref};
+
+/// Indexes accounts by their address.
+///
+/// If two accounts share an address, the later one is kept; use [`AccountMap::try_from`]
+/// to reject duplicates instead.
+///
+/// # Arguments
+///
+/// * `accounts` - A vector of private key signers
+///
+/// # Returns
+///
+/// * `HashMap<Address, PrivateKeySigner>` - The accounts keyed by address
+pub fn accounts_to_map(accounts: Vec<PrivateKeySigner>) -> HashMap<Address, PrivateKeySigner> {
+    accounts
+        .into_iter()
+        .map(|account| (account.address(), account))
+        .collect()
+}
+
+/// Error returned when two accounts have the same address.
+///
+/// # Fields
+///
+/// * `address` - The duplicated address
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateAccountError {
+    pub address: Address,
+}
+
+impl fmt::Display for DuplicateAccountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "duplicate account {}", self.address)
+    }
+}
+
+impl std::error::Error for DuplicateAccountError {}
+
+/// Accounts keyed by their address, guaranteed free of duplicates.
+///
+/// Built with `AccountMap::try_from(accounts)`; dereferences to the underlying `HashMap`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountMap(HashMap<Address, PrivateKeySigner>);
+
+impl AccountMap {
+    /// Returns the underlying `HashMap`.
+    pub fn into_inner(self) -> HashMap<Address, PrivateKeySigner> {
+        self.0
+    }
+}
+
+impl Deref for AccountMap {
+    type Target = HashMap<Address, PrivateKeySigner>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<AccountMap> for HashMap<Address, PrivateKeySigner> {
+    fn from(map: AccountMap) -> Self {
+        map.0
+    }
+}
+
+impl TryFrom<Vec<PrivateKeySigner>> for AccountMap {
+    type Error = DuplicateAccountError;
+
+    fn try_from(accounts: Vec<PrivateKeySigner>) -> Result<Self, Self::Error> {
+        let mut map = HashMap::with_capacity(accounts.len());
+        for account in accounts {
+            let address = account.address();
+            if map.insert(address, account).is_some() {
+                return Err(DuplicateAccountError { address });
+            }
+        }
+
+        Ok(Self(map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::account::generate_accounts;
+
+    const PHRASE: &str = "test test test test test test test test test test test junk";
+
+    #[test]
+    fn test_accounts_to_map() {
+        let accounts = generate_accounts(PHRASE, 0, 5).unwrap();
+        let map = accounts_to_map(accounts.clone());
+
+        assert_eq!(map.len(), accounts.len());
+        for account in &accounts {
+            assert_eq!(map[&account.address()].address(), account.address());
+        }
+    }
+
+    #[test]
+    fn test_try_from() {
+        let accounts = generate_accounts(PHRASE, 0, 5).unwrap();
+        let map: HashMap<Address, PrivateKeySigner> =
+            AccountMap::try_from(accounts.clone()).unwrap().into();
+
+        assert_eq!(map.len(), accounts.len());
+        assert!(map.contains_key(&accounts[4].address()));
+    }
+
+    #[test]
+    fn test_try_from_rejects_duplicates() {
+        let mut accounts = generate_accounts(PHRASE, 0, 3).unwrap();
+        accounts.push(accounts[1].clone());
+
+        let error = AccountMap::try_from(accounts.clone()).unwrap_err();
+        assert_eq!(error.address, accounts[1].address());
+
+        // the infallible version keeps one entry per address
+        assert_eq!(accounts_to_map(accounts).len(), 3);
+    }
+}
@@ -0,0 +1,239 @@
+use alloy::{
+    hex,
+    signers::local::coins_bip39::{English, Mnemonic},
+};
+use eyre::{ensure, Result};
+
+/// The length of a BIP39 mnemonic phrase.
+///
+/// # Variants
+///
+/// * `Twelve` - 12 words, 128 bits of entropy.
+/// * `TwentyFour` - 24 words, 256 bits of entropy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MnemonicStrength {
+    #[default]
+    Twelve,
+    TwentyFour,
+}
+
+impl MnemonicStrength {
+    /// Returns the number of words of a phrase of this strength.
+    pub fn word_count(self) -> usize {
+        match self {
+            Self::Twelve => 12,
+            Self::TwentyFour => 24,
+        }
+    }
+
+    /// Checks that a mnemonic phrase has the number of words of this strength.
+    ///
+    /// # Arguments
+    ///
+    /// * `mnemonic` - A BIP39 mnemonic phrase string
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - An error naming both word counts if they differ
+    pub fn validate(self, mnemonic: &str) -> Result<()> {
+        let word_count = mnemonic_word_count(mnemonic);
+        ensure!(
+            word_count == self.word_count(),
+            "expected a {}-word mnemonic, got {word_count} words",
+            self.word_count()
+        );
+
+        Ok(())
+    }
+}
+
+/// Generates a random BIP39 English mnemonic phrase.
+///
+/// # Arguments
+///
+/// * `strength` - The number of words of the phrase
+///
+/// # Returns
+///
+/// * `Result<String>` - The phrase, words separated by single spaces
+pub fn generate_mnemonic(strength: MnemonicStrength) -> Result<String> {
+    let mnemonic =
+        Mnemonic::<English>::new_with_count(&mut rand::thread_rng(), strength.word_count())?;
+
+    Ok(mnemonic.to_phrase())
+}
+
+/// Checks whether a mnemonic phrase is a valid BIP39 English phrase, including its checksum.
+///
+/// Words may be separated by any whitespace and are matched case-insensitively.
+///
+/// # Arguments
+///
+/// * `mnemonic` - A BIP39 mnemonic phrase string
+///
+/// # Returns
+///
+/// * `bool` - `true` if every word is in the wordlist and the checksum matches
+pub fn verify_mnemonic_checksum(mnemonic: &str) -> bool {
+    Mnemonic::<English>::new_from_phrase(&normalize_phrase(mnemonic)).is_ok()
+}
+
+/// Derives the 512-bit BIP39 seed of a mnemonic phrase with PBKDF2-HMAC-SHA512.
+///
+/// The phrase is normalized like in [`verify_mnemonic_checksum`] and must pass its checksum.
+///
+/// # Arguments
+///
+/// * `mnemonic` - A BIP39 mnemonic phrase string
+/// * `passphrase` - The optional BIP39 passphrase, empty for none
+///
+/// # Returns
+///
+/// * `Result<[u8; 64]>` - The seed, or an error if the phrase is not a valid BIP39 phrase
+pub fn mnemonic_to_seed_bytes(mnemonic: &str, passphrase: &str) -> Result<[u8; 64]> {
+    let mnemonic = Mnemonic::<English>::new_from_phrase(&normalize_phrase(mnemonic))?;
+    let seed = mnemonic.to_seed(Some(passphrase))?;
+
+    Ok(seed)
+}
+
+/// Derives the BIP39 seed of a mnemonic phrase as a hex string, see [`mnemonic_to_seed_bytes`].
+///
+/// # Arguments
+///
+/// * `mnemonic` - A BIP39 mnemonic phrase string
+/// * `passphrase` - The optional BIP39 passphrase, empty for none
+///
+/// # Returns
+///
+/// * `Result<String>` - The 128 lowercase hex characters of the seed, without `0x` prefix
+pub fn mnemonic_to_seed_hex(mnemonic: &str, passphrase: &str) -> Result<String> {
+    Ok(hex::encode(mnemonic_to_seed_bytes(mnemonic, passphrase)?))
+}
+
+/// Counts the words of a mnemonic phrase, ignoring extra whitespace.
+///
+/// # Arguments
+///
+/// * `mnemonic` - A BIP39 mnemonic phrase string
+///
+/// # Returns
+///
+/// * `usize` - The number of words
+pub fn mnemonic_word_count(mnemonic: &str) -> usize {
+    mnemonic.split_whitespace().count()
+}
+
+/// Lowercases a phrase and joins its words with single spaces.
+fn normalize_phrase(mnemonic: &str) -> String {
+    mnemonic
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE_12: &str = "test test test test test test test test test test test junk";
+    const PHRASE_24: &str = "abandon abandon abandon abandon abandon abandon abandon abandon \
+                             abandon abandon abandon abandon abandon abandon abandon abandon \
+                             abandon abandon abandon abandon abandon abandon abandon art";
+
+    #[test]
+    fn test_valid_phrases() {
+        assert!(verify_mnemonic_checksum(PHRASE_12));
+        assert!(verify_mnemonic_checksum(PHRASE_24));
+        assert_eq!(mnemonic_word_count(PHRASE_12), 12);
+        assert_eq!(mnemonic_word_count(PHRASE_24), 24);
+    }
+
+    #[test]
+    fn test_invalid_checksum() {
+        let prefix = "abandon ".repeat(11);
+
+        assert!(verify_mnemonic_checksum(&format!("{prefix}about")));
+        // valid words, but the checksum word should be "about"
+        assert!(!verify_mnemonic_checksum(&format!("{prefix}abandon")));
+    }
+
+    #[test]
+    fn test_invalid_words() {
+        assert!(!verify_mnemonic_checksum("not a mnemonic"));
+        assert!(!verify_mnemonic_checksum(""));
+    }
+
+    #[test]
+    fn test_generate_mnemonic() {
+        for strength in [MnemonicStrength::Twelve, MnemonicStrength::TwentyFour] {
+            let phrase = generate_mnemonic(strength).unwrap();
+
+            assert_eq!(mnemonic_word_count(&phrase), strength.word_count());
+            assert!(verify_mnemonic_checksum(&phrase));
+            assert!(strength.validate(&phrase).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_validate_strength() {
+        assert!(MnemonicStrength::Twelve.validate(PHRASE_12).is_ok());
+        assert!(MnemonicStrength::TwentyFour.validate(PHRASE_24).is_ok());
+        assert!(MnemonicStrength::TwentyFour.validate(PHRASE_12).is_err());
+        assert!(MnemonicStrength::Twelve.validate(PHRASE_24).is_err());
+    }
+
+    #[test]
+    fn test_mnemonic_to_seed() {
+        // test vectors of the BIP39 specification, all with the passphrase "TREZOR"
+        let vectors = [
+            (
+                "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+                 abandon about",
+                "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264\
+                 c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+            ),
+            (
+                "legal winner thank year wave sausage worth useful legal winner thank yellow",
+                "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6fa457fe1296106559a3\
+                 c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607",
+            ),
+            (
+                "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo \
+                 zoo zoo vote",
+                "dd48c104698c30cfe2b6142103248622fb7bb0ff692eebb00089b32d22484e1613912f0a5b694407be\
+                 899ffd31ed3992c456cdf60f5d4564b8ba3f05a69890ad",
+            ),
+        ];
+
+        for (phrase, seed) in vectors {
+            assert_eq!(mnemonic_to_seed_hex(phrase, "TREZOR").unwrap(), seed);
+            assert_eq!(
+                mnemonic_to_seed_bytes(phrase, "TREZOR").unwrap().as_slice(),
+                hex::decode(seed).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_mnemonic_to_seed_passphrase() {
+        let seed = mnemonic_to_seed_hex(PHRASE_12, "").unwrap();
+        assert_eq!(
+            seed,
+            "9dfc3c64c2f8bede1533b6a79f8570e5943e0b8fd1cf77107adf7b72cef42185d564a3aee24cab43f80e3c4538\
+             087d70fc824eabbad596a23c97b6ee8322ccc0"
+        );
+        assert_ne!(seed, mnemonic_to_seed_hex(PHRASE_12, "TREZOR").unwrap());
+
+        assert!(mnemonic_to_seed_bytes("not a mnemonic", "").is_err());
+    }
+
+    #[test]
+    fn test_whitespace_normalization() {
+        let phrase = "  test test\ttest test test test\n test test test test TEST   junk ";
+
+        assert!(verify_mnemonic_checksum(phrase));
+        assert_eq!(mnemonic_word_count(phrase), 12);
+    }
+}
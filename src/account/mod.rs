@@ -1,2 +1,25 @@
 mod generate;
-pub use generate::generate_accounts;
+pub use generate::{
+    generate_accounts, generate_accounts_with_options, generate_accounts_with_path, GenerateOptions,
+};
+
+mod path;
+pub use path::{
+    account_path, BIP44_ETC_PATH_PREFIX, BIP44_ETH_ACCOUNT_PATH, BIP44_ETH_PATH_PREFIX,
+    BIP44_RSK_PATH_PREFIX, BIP44_TESTNET_PATH_PREFIX,
+};
+
+mod xpub;
+pub use xpub::derive_addresses_from_xpub;
+
+mod map;
+pub use map::{accounts_to_map, AccountMap, DuplicateAccountError};
+
+mod mnemonic;
+pub use mnemonic::{
+    generate_mnemonic, mnemonic_to_seed_bytes, mnemonic_to_seed_hex, mnemonic_word_count,
+    verify_mnemonic_checksum, MnemonicStrength,
+};
+
+mod checksum;
+pub use checksum::{has_valid_eip55_checksum, to_eip55_checksum};
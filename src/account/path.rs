@@ -0,0 +1,23 @@
+/// BIP44 derivation path prefix for Ethereum (coin type 60), used by [`generate_accounts`](crate::account::generate_accounts).
+pub const BIP44_ETH_PATH_PREFIX: &str = "m/44'/60'/0'/0";
+
+/// BIP44 derivation path prefix for Ethereum Classic (coin type 61).
+pub const BIP44_ETC_PATH_PREFIX: &str = "m/44'/61'/0'/0";
+
+/// BIP44 derivation path prefix for Rootstock (coin type 137).
+pub const BIP44_RSK_PATH_PREFIX: &str = "m/44'/137'/0'/0";
+
+/// BIP44 derivation path prefix shared by all testnets (coin type 1).
+pub const BIP44_TESTNET_PATH_PREFIX: &str = "m/44'/1'/0'/0";
+
+/// Returns the full Ethereum derivation path of the account at `index`.
+pub const BIP44_ETH_ACCOUNT_PATH: fn(u32) -> String = eth_account_path;
+
+/// Returns the full derivation path of the account at `index` under `prefix`.
+pub fn account_path(prefix: &str, index: u32) -> String {
+    format!("{prefix}/{index}")
+}
+
+fn eth_account_path(index: u32) -> String {
+    account_path(BIP44_ETH_PATH_PREFIX, index)
+}
@@ -0,0 +1,63 @@
+use alloy::primitives::Address;
+use coins_bip32::{
+    enc::{MainnetEncoder, XKeyEncoder},
+    xkeys::Parent,
+};
+use eyre::Result;
+use rayon::prelude::*;
+
+/// Derives Ethereum addresses from an extended public key, without access to any private key.
+///
+/// The xpub must be exported at the derivation path prefix, e.g. [`BIP44_ETH_PATH_PREFIX`](crate::account::BIP44_ETH_PATH_PREFIX),
+/// so that child `i` matches the account at index `i` of [`generate_accounts`](crate::account::generate_accounts).
+///
+/// # Arguments
+///
+/// * `xpub` - A base58-encoded extended public key (`xpub...`)
+/// * `start` - The starting child index
+/// * `end` - The ending child index (exclusive)
+///
+/// # Returns
+///
+/// * `Result<Vec<Address>>` - A vector of addresses, in index order, on success
+pub fn derive_addresses_from_xpub(xpub: &str, start: u32, end: u32) -> Result<Vec<Address>> {
+    let parent = MainnetEncoder::xpub_from_base58(xpub)?;
+
+    (start..end)
+        .into_par_iter()
+        .map(|index| -> Result<Address> {
+            let child = parent.derive_child(index)?;
+            Ok(Address::from_public_key(child.as_ref()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::account::{generate_accounts, BIP44_ETH_PATH_PREFIX};
+    use alloy::signers::local::coins_bip39::{English, Mnemonic};
+
+    const MNEMONIC: &str = "test test test test test test test test test test test junk";
+
+    #[test]
+    fn test_derive_matches_generate_accounts() {
+        let xpub = Mnemonic::<English>::new_from_phrase(MNEMONIC)
+            .unwrap()
+            .derive_key(BIP44_ETH_PATH_PREFIX, None)
+            .unwrap()
+            .verify_key();
+        let xpub = MainnetEncoder::xpub_to_base58(&xpub).unwrap();
+
+        let addresses = derive_addresses_from_xpub(&xpub, 5, 15).unwrap();
+        let accounts = generate_accounts(MNEMONIC, 5, 15).unwrap();
+
+        let expected: Vec<Address> = accounts.iter().map(|account| account.address()).collect();
+        assert_eq!(addresses, expected);
+    }
+
+    #[test]
+    fn test_invalid_xpub() {
+        assert!(derive_addresses_from_xpub("xpub-invalid", 0, 1).is_err());
+    }
+}
@@ -0,0 +1,518 @@
+use crate::distributor::{
+    distribute::{ensure_unique_receivers, prepare_distribution},
+    encode, normalize, validate_params, DistributeOptions, DistributeParam, DistributeResult,
+    ValidationRules, DEFAULT_MAX_RECIPIENTS, RECEIPT_POLL_INTERVAL, RECEIPT_TIMEOUT,
+};
+use crate::executor::{
+    get_block, submit_with_options, watch_pending_tx, ExecuteOptions, ProviderConfig, TxStatus,
+};
+use crate::progress::{Progress, ProgressUpdate};
+use alloy::{
+    eips::BlockId,
+    json_abi::JsonAbi,
+    network::TransactionBuilder,
+    primitives::Address,
+    providers::{Provider, ProviderBuilder},
+    rpc::types::TransactionRequest,
+    signers::local::PrivateKeySigner,
+};
+use eyre::{ensure, eyre, Report, Result};
+use std::fmt;
+
+/// Number of params sampled when the gas of a full batch cannot be estimated.
+const SAMPLE_SIZE: usize = 10;
+
+/// Error returned by [`distribute_chunked`] when a chunk fails.
+///
+/// # Fields
+///
+/// * `funded` - The number of recipients funded by the chunks that succeeded.
+/// * `completed` - The results of the chunks that succeeded.
+/// * `stranded_nonces` - The nonces of the chunks that were submitted but not mined when the
+///   sequence stopped; they may still land, or can be cancelled or sped up.
+/// * `source` - The error that stopped the sequence.
+#[derive(Debug)]
+pub struct DistributeChunkError {
+    pub funded: usize,
+    pub completed: Vec<DistributeResult>,
+    pub stranded_nonces: Vec<u64>,
+    pub source: Report,
+}
+
+impl fmt::Display for DistributeChunkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "distribution stopped after {} chunks ({} recipients funded",
+            self.completed.len(),
+            self.funded,
+        )?;
+        if !self.stranded_nonces.is_empty() {
+            let nonces: Vec<String> = self.stranded_nonces.iter().map(u64::to_string).collect();
+            write!(f, ", nonces {} stranded", nonces.join(", "))?;
+        }
+        write!(f, "): {}", self.source)
+    }
+}
+
+impl std::error::Error for DistributeChunkError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+/// The progress of a distribution, reported after each chunk or transfer.
+///
+/// # Fields
+///
+/// * `chunks_completed` - The number of chunks (or direct transfers) processed so far.
+/// * `chunk_count` - The total number of chunks (or direct transfers).
+/// * `recipients_funded` - The number of recipients funded so far.
+/// * `gas_used` - The cumulative gas used so far.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DistributeProgress {
+    pub chunks_completed: usize,
+    pub chunk_count: usize,
+    pub recipients_funded: usize,
+    pub gas_used: u64,
+}
+
+impl ProgressUpdate for DistributeProgress {
+    fn position(&self) -> u64 {
+        self.chunks_completed as u64
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "{} recipients funded, {} gas used",
+            self.recipients_funded, self.gas_used
+        )
+    }
+}
+
+/// Distributes Ether to multiple receivers, split into chunks of at most `chunk_size`.
+///
+/// Every chunk is checked first, then the chunks are submitted back to back with
+/// consecutive nonces, fetched once, and their receipts are awaited in order. The first
+/// failing chunk stops the sequence with a [`DistributeChunkError`] reporting how many
+/// recipients were already funded and which nonces are left pending.
+///
+/// # Arguments
+///
+/// * `sender` - The private key signer of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `params` - A vector of `DistributeParam` containing receiver addresses and amounts.
+/// * `chunk_size` - The maximum number of receivers per transaction, see [`estimate_chunk_size`].
+///
+/// # Returns
+///
+/// * `Result<Vec<DistributeResult>>` - The result of every chunk, in order.
+pub async fn distribute_chunked(
+    sender: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    params: Vec<DistributeParam>,
+    chunk_size: usize,
+) -> Result<Vec<DistributeResult>> {
+    distribute_chunked_with_progress(
+        sender,
+        rpc_http,
+        abi,
+        contract_address,
+        params,
+        chunk_size,
+        None,
+    )
+    .await
+}
+
+/// Distributes Ether in chunks like [`distribute_chunked`], reporting progress after each chunk.
+///
+/// # Arguments
+///
+/// * `sender` - The private key signer of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `params` - A vector of `DistributeParam` containing receiver addresses and amounts.
+/// * `chunk_size` - The maximum number of receivers per transaction, see [`estimate_chunk_size`].
+/// * `progress` - Receives a [`DistributeProgress`] after every successful chunk (optional, `None` is silent).
+///
+/// # Returns
+///
+/// * `Result<Vec<DistributeResult>>` - The result of every chunk, in order.
+pub async fn distribute_chunked_with_progress(
+    sender: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    params: Vec<DistributeParam>,
+    chunk_size: usize,
+    progress: Option<&dyn Progress<DistributeProgress>>,
+) -> Result<Vec<DistributeResult>> {
+    distribute_chunked_with_options(
+        sender,
+        rpc_http,
+        abi,
+        contract_address,
+        params,
+        chunk_size,
+        DistributeOptions::default(),
+        progress,
+    )
+    .await
+}
+
+/// Distributes Ether in chunks with the given options, reporting progress after each chunk.
+///
+/// The whole list is normalized, validated, sorted and checked for duplicate receivers
+/// before it is split, so amount caps such as [`ValidationRules`]' `max_total` and the
+/// duplicate check apply to the entire distribution and nothing is sent if any entry breaks
+/// them. The `max_recipients` limit caps the size of each chunk. `progress` is finished
+/// once the distribution returns, whether it succeeded or not.
+///
+/// # Arguments
+///
+/// * `sender` - The private key signer of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `params` - A vector of `DistributeParam` containing receiver addresses and amounts.
+/// * `chunk_size` - The maximum number of receivers per transaction, see [`estimate_chunk_size`].
+/// * `options` - The options of the distribution, applied to the whole list and to every chunk.
+/// * `progress` - Receives a [`DistributeProgress`] after every successful chunk (optional, `None` is silent).
+///
+/// # Returns
+///
+/// * `Result<Vec<DistributeResult>>` - The result of every chunk, in order.
+#[allow(clippy::too_many_arguments)]
+pub async fn distribute_chunked_with_options(
+    sender: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    params: Vec<DistributeParam>,
+    chunk_size: usize,
+    options: DistributeOptions,
+    progress: Option<&dyn Progress<DistributeProgress>>,
+) -> Result<Vec<DistributeResult>> {
+    let results = send_chunks(
+        sender,
+        rpc_http.into(),
+        abi,
+        contract_address,
+        params,
+        chunk_size,
+        options,
+        progress,
+    )
+    .await;
+
+    if let Some(progress) = progress {
+        progress.finish();
+    }
+
+    results
+}
+
+/// Checks, submits and awaits the chunks of [`distribute_chunked_with_options`], reporting
+/// progress after each successful chunk without finishing it.
+#[allow(clippy::too_many_arguments)]
+async fn send_chunks(
+    sender: PrivateKeySigner,
+    config: ProviderConfig,
+    abi: JsonAbi,
+    contract_address: Address,
+    params: Vec<DistributeParam>,
+    chunk_size: usize,
+    options: DistributeOptions,
+    progress: Option<&dyn Progress<DistributeProgress>>,
+) -> Result<Vec<DistributeResult>> {
+    ensure!(chunk_size > 0, "chunk_size must be greater than 0");
+
+    let params = if options.normalize {
+        normalize(params)?.params
+    } else {
+        params
+    };
+    // the whole list is validated once, and every chunk stays within `max_recipients`
+    let (mut params, chunk_size) = match &options.validation {
+        Some(rules) => {
+            let whole_list = ValidationRules {
+                max_recipients: usize::MAX,
+                ..rules.clone()
+            };
+            (
+                validate_params(sender.address(), params, &whole_list)?,
+                chunk_size.min(rules.max_recipients.max(1)),
+            )
+        }
+        None => (params, chunk_size),
+    };
+    options.sort.sort(&mut params);
+    // a receiver repeated in two chunks is a duplicate too
+    if options.check_duplicates {
+        ensure_unique_receivers(&params)?;
+    }
+
+    let options = DistributeOptions {
+        normalize: false,
+        validation: None,
+        check_duplicates: false,
+        ..options
+    };
+
+    // run the checks of every chunk before anything is sent
+    let mut prepared = Vec::with_capacity(params.len().div_ceil(chunk_size));
+    for chunk in params.chunks(chunk_size) {
+        prepared.push(
+            prepare_distribution(
+                sender.address(),
+                config.clone(),
+                contract_address,
+                chunk.to_vec(),
+                &options,
+            )
+            .await?,
+        );
+    }
+
+    // submit the chunks back to back with consecutive nonces, then await their receipts
+    let provider = ProviderBuilder::new().on_client(config.client()?);
+    let first_nonce = provider
+        .get_transaction_count(sender.address())
+        .pending()
+        .await?;
+    let nonces: Vec<u64> = (first_nonce..).take(prepared.len()).collect();
+
+    let mut submitted = Vec::with_capacity(prepared.len());
+    for (prepared, &nonce) in prepared.into_iter().zip(&nonces) {
+        let execute_options = ExecuteOptions {
+            value: Some(prepared.value),
+            nonce: Some(nonce),
+            ..Default::default()
+        };
+        let submission = submit_with_options(
+            sender.clone(),
+            config.clone(),
+            abi.clone(),
+            contract_address,
+            &prepared.function_name,
+            &prepared.args,
+            execute_options,
+        )
+        .await;
+
+        match submission {
+            Ok(execution) => submitted.push((execution.tx_hash, prepared)),
+            Err(source) => {
+                return Err(DistributeChunkError {
+                    funded: 0,
+                    completed: Vec::new(),
+                    stranded_nonces: nonces[..submitted.len()].to_vec(),
+                    source,
+                }
+                .into())
+            }
+        }
+    }
+
+    let mut results: Vec<DistributeResult> = Vec::with_capacity(submitted.len());
+    let mut state = DistributeProgress {
+        chunk_count: submitted.len(),
+        ..Default::default()
+    };
+
+    for (index, (tx_hash, prepared)) in submitted.into_iter().enumerate() {
+        let status = watch_pending_tx(
+            config.clone(),
+            tx_hash,
+            RECEIPT_POLL_INTERVAL,
+            RECEIPT_TIMEOUT,
+        )
+        .await;
+
+        let (source, mined) = match status {
+            Ok(TxStatus::Confirmed(receipt)) if receipt.status() => {
+                let result = prepared.into_result(&receipt);
+                state.chunks_completed += 1;
+                state.recipients_funded += result.recipient_count;
+                state.gas_used += result.gas_used;
+                if let Some(progress) = progress {
+                    progress.update(&state);
+                }
+                results.push(result);
+                continue;
+            }
+            Ok(TxStatus::Confirmed(_)) => (eyre!("transaction {tx_hash} reverted"), true),
+            Ok(TxStatus::Dropped) => (eyre!("transaction {tx_hash} was dropped"), false),
+            Ok(TxStatus::TimedOut) => (eyre!("transaction {tx_hash} is still pending"), false),
+            Err(error) => (error, false),
+        };
+
+        // a mined chunk used its nonce, the ones after it may still be pending
+        let stranded = if mined { index + 1 } else { index };
+        return Err(DistributeChunkError {
+            funded: state.recipients_funded,
+            completed: results,
+            stranded_nonces: nonces[stranded..].to_vec(),
+            source,
+        }
+        .into());
+    }
+
+    Ok(results)
+}
+
+/// Estimates how many receivers fit in one distribution transaction.
+///
+/// The gas of a sample chunk is estimated and extrapolated per receiver, keeping the
+/// chunk within half of the latest block gas limit.
+///
+/// # Arguments
+///
+/// * `sender` - The address of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `sample` - A sample of the params to estimate the gas of (e.g. the first few of the full list).
+///
+/// # Returns
+///
+/// * `Result<usize>` - The suggested chunk size, at least 1 and at most [`DEFAULT_MAX_RECIPIENTS`].
+pub async fn estimate_chunk_size(
+    sender: Address,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    sample: &[DistributeParam],
+) -> Result<usize> {
+    ensure!(!sample.is_empty(), "sample must not be empty");
+
+    let config: ProviderConfig = rpc_http.into();
+    let gas =
+        estimate_distribute_gas(sender, config.clone(), &abi, contract_address, sample).await?;
+
+    let block = get_block(config, BlockId::latest()).await?;
+
+    Ok(chunk_size_within(block.gas_limit / 2, gas, sample.len()))
+}
+
+/// Estimates the gas of distributing to all params in a single transaction.
+///
+/// # Arguments
+///
+/// * `sender` - The address of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `params` - The params of the distribution.
+///
+/// # Returns
+///
+/// * `Result<u64>` - The estimated gas, or an error suggesting to split the batch if the
+///   estimation fails (e.g. because the batch exceeds the block gas limit).
+pub async fn estimate_distribute_gas(
+    sender: Address,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: &JsonAbi,
+    contract_address: Address,
+    params: &[DistributeParam],
+) -> Result<u64> {
+    let tx = distribute_request(sender, abi, contract_address, params)?;
+
+    let provider = ProviderBuilder::new().on_client(rpc_http.into().client()?);
+    provider.estimate_gas(&tx).await.map_err(|error| {
+        eyre!(
+            "gas estimation failed for {} recipients: {error}; \
+             split the batch with `distribute_chunked` or use `distribute_safe`",
+            params.len()
+        )
+    })
+}
+
+/// Builds the unsigned transaction distributing to all params in a single call.
+///
+/// # Arguments
+///
+/// * `sender` - The address of the sender.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `params` - The params of the distribution.
+///
+/// # Returns
+///
+/// * `Result<TransactionRequest>` - The transaction request on success.
+pub(crate) fn distribute_request(
+    sender: Address,
+    abi: &JsonAbi,
+    contract_address: Address,
+    params: &[DistributeParam],
+) -> Result<TransactionRequest> {
+    let encoded = encode(params, abi)?;
+
+    Ok(TransactionRequest::default()
+        .with_from(sender)
+        .with_to(contract_address)
+        .with_input(encoded.calldata)
+        .with_value(encoded.total_value))
+}
+
+/// Distributes Ether to multiple receivers, splitting the batch when it is too large.
+///
+/// The whole batch is sent in one transaction if its estimated gas is within `gas_threshold`.
+/// Otherwise, or if the estimation fails, the gas per receiver is estimated on a sample and
+/// the batch is sent with [`distribute_chunked`] in chunks that stay within the threshold.
+///
+/// # Arguments
+///
+/// * `sender` - The private key signer of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `params` - A vector of `DistributeParam` containing receiver addresses and amounts.
+/// * `gas_threshold` - The maximum gas a single transaction may use.
+///
+/// # Returns
+///
+/// * `Result<Vec<DistributeResult>>` - The result of every transaction, in order.
+pub async fn distribute_safe(
+    sender: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    params: Vec<DistributeParam>,
+    gas_threshold: u64,
+) -> Result<Vec<DistributeResult>> {
+    ensure!(!params.is_empty(), "no receivers to distribute to");
+
+    let config: ProviderConfig = rpc_http.into();
+    let address = sender.address();
+    let estimate =
+        estimate_distribute_gas(address, config.clone(), &abi, contract_address, &params).await;
+
+    let chunk_size = match estimate {
+        Ok(gas) if gas <= gas_threshold => params.len(),
+        Ok(gas) => chunk_size_within(gas_threshold, gas, params.len()),
+        Err(_) => {
+            let sample = &params[..params.len().min(SAMPLE_SIZE)];
+            let gas =
+                estimate_distribute_gas(address, config.clone(), &abi, contract_address, sample)
+                    .await?;
+            chunk_size_within(gas_threshold, gas, sample.len())
+        }
+    };
+
+    distribute_chunked(sender, config, abi, contract_address, params, chunk_size).await
+}
+
+/// Returns how many receivers fit within `gas_threshold`, given the gas of `count` receivers,
+/// at most [`DEFAULT_MAX_RECIPIENTS`].
+fn chunk_size_within(gas_threshold: u64, gas: u64, count: usize) -> usize {
+    let per_receiver = (gas / count as u64).max(1);
+
+    ((gas_threshold / per_receiver) as usize).clamp(1, DEFAULT_MAX_RECIPIENTS)
+}
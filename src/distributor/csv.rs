@@ -0,0 +1,328 @@
+use crate::distributor::{distribute_chunked_with_progress, DistributeParam, DistributeResult};
+use crate::executor::ProviderConfig;
+use crate::progress::ProgressBarReporter;
+use alloy::{
+    json_abi::JsonAbi,
+    primitives::{
+        utils::{format_ether, parse_ether},
+        Address, TxHash, U256,
+    },
+    signers::local::PrivateKeySigner,
+};
+use eyre::{ensure, eyre, Result, WrapErr};
+use std::{fs, path::Path, str::FromStr};
+
+/// The unit of the amounts in a funding list.
+///
+/// # Variants
+///
+/// * `Ether` - Decimal amounts in ether, e.g. `0.001`.
+/// * `Wei` - Integer amounts in wei, e.g. `1000000000000000`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmountUnit {
+    Ether,
+    Wei,
+}
+
+impl AmountUnit {
+    /// Parses an amount expressed in this unit into wei.
+    pub(crate) fn parse(&self, amount: &str) -> Result<U256> {
+        match self {
+            Self::Ether => Ok(parse_ether(amount)?),
+            Self::Wei => Ok(U256::from_str(amount)?),
+        }
+    }
+}
+
+/// Reads distribution params from a CSV file of `address,amount` rows.
+///
+/// The first row is skipped as a header when neither of its columns parses, e.g.
+/// `address,amount`; a first row with only one invalid column is an error. Empty lines are
+/// ignored.
+///
+/// # Arguments
+///
+/// * `path` - The path of the CSV file.
+/// * `amount_unit` - The unit the amounts are expressed in.
+///
+/// # Returns
+///
+/// * `Result<Vec<DistributeParam>>` - The params on success, or an error naming the invalid row.
+pub fn params_from_csv(
+    path: impl AsRef<Path>,
+    amount_unit: AmountUnit,
+) -> Result<Vec<DistributeParam>> {
+    let path = path.as_ref();
+    let content =
+        fs::read_to_string(path).wrap_err_with(|| format!("failed to read {}", path.display()))?;
+
+    params_from_csv_str(&content, amount_unit)
+}
+
+/// Parses distribution params from CSV content of `address,amount` rows, with an optional
+/// header row as described in [`params_from_csv`].
+///
+/// # Arguments
+///
+/// * `content` - The CSV content.
+/// * `amount_unit` - The unit the amounts are expressed in.
+///
+/// # Returns
+///
+/// * `Result<Vec<DistributeParam>>` - The params on success, or an error naming the invalid row.
+pub fn params_from_csv_str(content: &str, amount_unit: AmountUnit) -> Result<Vec<DistributeParam>> {
+    let mut params = Vec::new();
+    let mut first_row = true;
+
+    for (index, line) in content.lines().enumerate() {
+        let row = index + 1;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut columns = line.split(',').map(str::trim);
+        let (address, amount) = match (columns.next(), columns.next(), columns.next()) {
+            (Some(address), Some(amount), None) => (address, amount),
+            _ => return Err(eyre!("row {row}: expected `address,amount`")),
+        };
+
+        let (receiver, parsed_amount) = (Address::from_str(address), amount_unit.parse(amount));
+        // a header names its columns, so neither of them parses
+        let is_header = first_row && receiver.is_err() && parsed_amount.is_err();
+        first_row = false;
+        if is_header {
+            continue;
+        }
+
+        let receiver =
+            receiver.wrap_err_with(|| format!("row {row}: invalid address `{address}`"))?;
+        let amount =
+            parsed_amount.wrap_err_with(|| format!("row {row}: invalid amount `{amount}`"))?;
+
+        params.push(DistributeParam { receiver, amount });
+    }
+
+    Ok(params)
+}
+
+/// Distributes Ether to the receivers listed in a CSV file, in waves, with a progress bar.
+///
+/// The file holds `address,amount` rows with amounts in ether, as read by [`params_from_csv`].
+/// Each wave is one distribution transaction, sent with [`distribute_chunked_with_progress`].
+///
+/// # Arguments
+///
+/// * `sender` - The private key signer of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `csv_path` - The path of the CSV file.
+/// * `wave_size` - The maximum number of receivers per transaction (optional, defaults to all in one).
+///
+/// # Returns
+///
+/// * `Result<Vec<TxHash>>` - The transaction hash of every wave, in order.
+pub async fn batch_distribute_from_csv(
+    sender: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    csv_path: &Path,
+    wave_size: Option<usize>,
+) -> Result<Vec<TxHash>> {
+    let params = params_from_csv(csv_path, AmountUnit::Ether)?;
+    ensure!(
+        !params.is_empty(),
+        "{} contains no distribution params",
+        csv_path.display()
+    );
+
+    let wave_size = wave_size.unwrap_or(params.len());
+    ensure!(wave_size > 0, "wave_size must be greater than 0");
+
+    let progress = ProgressBarReporter::new(
+        params.len().div_ceil(wave_size) as u64,
+        "distribution waves",
+    )?;
+    let results = distribute_chunked_with_progress(
+        sender,
+        rpc_http,
+        abi,
+        contract_address,
+        params,
+        wave_size,
+        Some(&progress),
+    )
+    .await?;
+
+    Ok(results.into_iter().map(|result| result.tx_hash).collect())
+}
+
+/// Writes a per-recipient record of a distribution to a CSV file.
+///
+/// Rows are `receiver,amount_wei,amount_ether,tx_hash,block_number,status`, one per param,
+/// with the params of every chunk attributed to the transaction of that chunk.
+///
+/// # Arguments
+///
+/// * `results` - The result of every chunk, in order.
+/// * `params_by_chunk` - The params of every chunk, in the same order as `results`.
+/// * `path` - The path of the CSV file to write.
+///
+/// # Returns
+///
+/// * `Result<()>` - An error if the chunk counts differ or the file cannot be written.
+pub fn export_receipts_csv(
+    results: &[DistributeResult],
+    params_by_chunk: &[Vec<DistributeParam>],
+    path: impl AsRef<Path>,
+) -> Result<()> {
+    ensure!(
+        results.len() == params_by_chunk.len(),
+        "{} results for {} chunks of params",
+        results.len(),
+        params_by_chunk.len()
+    );
+
+    let mut content =
+        String::from("receiver,amount_wei,amount_ether,tx_hash,block_number,status\n");
+    for (result, params) in results.iter().zip(params_by_chunk) {
+        for param in params {
+            content.push_str(&format!(
+                "{},{},{},{},{},{}\n",
+                param.receiver,
+                param.amount,
+                format_ether(param.amount),
+                result.tx_hash,
+                result.block_number,
+                if result.status { "success" } else { "reverted" }
+            ));
+        }
+    }
+
+    let path = path.as_ref();
+    fs::write(path, content).wrap_err_with(|| format!("failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALICE: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+    const BOB: &str = "0x70997970C51812dc3A010C7d01b50e0d17dc79C8";
+
+    #[test]
+    fn test_parse_valid_csv() {
+        let content = format!("{ALICE},0.5\n{BOB},1\n");
+        let params = params_from_csv_str(&content, AmountUnit::Ether).unwrap();
+
+        assert_eq!(params.len(), 2);
+        assert_eq!(params[0].receiver, Address::from_str(ALICE).unwrap());
+        assert_eq!(params[0].amount, parse_ether("0.5").unwrap());
+        assert_eq!(params[1].amount, parse_ether("1").unwrap());
+    }
+
+    #[test]
+    fn test_parse_csv_with_header_in_wei() {
+        let content = format!("address,amount\n{ALICE},1000\n\n{BOB},2000\n");
+        let params = params_from_csv_str(&content, AmountUnit::Wei).unwrap();
+
+        assert_eq!(params.len(), 2);
+        assert_eq!(params[0].amount, U256::from(1000));
+        assert_eq!(params[1].amount, U256::from(2000));
+    }
+
+    #[test]
+    fn test_parse_csv_header_after_empty_line() {
+        let content = format!("\nreceiver,value\n{ALICE},1\n");
+        let params = params_from_csv_str(&content, AmountUnit::Ether).unwrap();
+
+        assert_eq!(params.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_csv_first_row_is_not_skipped() {
+        // an address without the 0x prefix is still an address
+        let content = format!("{},1\n{BOB},1\n", &ALICE[2..]);
+        let params = params_from_csv_str(&content, AmountUnit::Ether).unwrap();
+        assert_eq!(params.len(), 2);
+        assert_eq!(params[0].receiver, Address::from_str(ALICE).unwrap());
+
+        // a mistyped address with a valid amount is not a header
+        let content = format!("0x1234,1\n{BOB},1\n");
+        let error = params_from_csv_str(&content, AmountUnit::Ether).unwrap_err();
+        assert!(error.to_string().starts_with("row 1:"));
+    }
+
+    #[test]
+    fn test_parse_csv_bad_address_reports_row() {
+        let content = format!("{ALICE},1\n{BOB},1\n0x1234,1\n");
+        let error = params_from_csv_str(&content, AmountUnit::Ether).unwrap_err();
+
+        assert!(error.to_string().starts_with("row 3:"));
+    }
+
+    #[test]
+    fn test_parse_csv_too_many_decimals() {
+        let content = format!("{ALICE},0.0000000000000000001\n");
+        let error = params_from_csv_str(&content, AmountUnit::Ether).unwrap_err();
+
+        assert!(error.to_string().starts_with("row 1:"));
+    }
+
+    #[test]
+    fn test_read_csv_file() {
+        let path = std::env::temp_dir().join("stormint_params_from_csv.csv");
+        fs::write(&path, format!("{ALICE},0.5\n")).unwrap();
+
+        let params = params_from_csv(&path, AmountUnit::Ether).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(params.len(), 1);
+    }
+
+    #[test]
+    fn test_export_receipts_csv() {
+        let param = |receiver: &str, amount: u64| DistributeParam {
+            receiver: Address::from_str(receiver).unwrap(),
+            amount: U256::from(amount),
+        };
+        let result = |byte: u8, total: u64, recipient_count: usize| DistributeResult {
+            tx_hash: TxHash::repeat_byte(byte),
+            gas_used: 50_000,
+            effective_gas_price: 1,
+            block_number: byte as u64,
+            status: true,
+            total_distributed: U256::from(total),
+            recipient_count,
+            contract_recipients: Vec::new(),
+        };
+        let params_by_chunk = vec![
+            vec![param(ALICE, 1_000), param(BOB, 2_000)],
+            vec![param(ALICE, 500_000_000_000_000_000)],
+        ];
+        let results = vec![result(1, 3_000, 2), result(2, 500_000_000_000_000_000, 1)];
+
+        let path = std::env::temp_dir().join("stormint_export_receipts.csv");
+        export_receipts_csv(&results, &params_by_chunk, &path).unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let rows: Vec<Vec<&str>> = content
+            .lines()
+            .skip(1)
+            .map(|line| line.split(',').collect())
+            .collect();
+        assert_eq!(rows.len(), 3);
+
+        let total: U256 = rows.iter().map(|row| U256::from_str(row[1]).unwrap()).sum();
+        assert_eq!(total, U256::from(500_000_000_000_003_000u64));
+        assert_eq!(rows[2][2], "0.500000000000000000");
+        assert_eq!(rows[2][3], TxHash::repeat_byte(2).to_string());
+        assert_eq!(rows[2][5], "success");
+
+        // every chunk needs its params
+        assert!(export_receipts_csv(&results, &params_by_chunk[..1], &path).is_err());
+    }
+}
@@ -0,0 +1,179 @@
+use crate::distributor::{sweep::TRANSFER_GAS, DistributeParam, DistributeProgress};
+use crate::executor::ProviderConfig;
+use crate::progress::Progress;
+use alloy::{
+    network::{EthereumWallet, TransactionBuilder},
+    primitives::{Address, TxHash, U256},
+    providers::{Provider, ProviderBuilder},
+    rpc::types::TransactionRequest,
+    signers::local::PrivateKeySigner,
+};
+use eyre::{ensure, eyre, Report, Result};
+
+/// Represents the result of a direct transfer to one receiver.
+///
+/// # Fields
+///
+/// * `receiver` - The address of the receiver.
+/// * `amount` - The amount sent.
+/// * `result` - The transaction hash on success, or an error report if the transfer failed
+///   or reverted.
+#[derive(Debug)]
+pub struct DirectTransfer {
+    pub receiver: Address,
+    pub amount: U256,
+    pub result: Result<TxHash, Report>,
+}
+
+/// Represents the result of a distribution made of plain transfers.
+///
+/// # Fields
+///
+/// * `transfers` - The result of every transfer, in the same order as the params.
+/// * `gas_used` - The total gas used by the mined transfers, reverted ones included.
+/// * `warning` - A reminder of the cost compared with the batched distributor path.
+#[derive(Debug)]
+pub struct DirectDistribution {
+    pub transfers: Vec<DirectTransfer>,
+    pub gas_used: u64,
+    pub warning: String,
+}
+
+/// Distributes Ether with plain transfers, without a distributor contract.
+///
+/// Transfers are submitted sequentially from `sender` with locally managed nonces,
+/// then every receipt is awaited. After a failed submission, which may still have taken its
+/// nonce, the pending nonce is read again; if that fails too, the remaining transfers are
+/// reported as failed without being sent. The gas of every transfer is estimated, so receivers with
+/// code get what their `receive` function needs, and a transfer that reverts is reported as
+/// failed. Each transfer costs at least 21000 gas, so prefer
+/// [`distribute`](crate::distributor::distribute) when a distributor contract is deployed.
+///
+/// # Arguments
+///
+/// * `sender` - The private key signer of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `params` - A vector of `DistributeParam` containing receiver addresses and amounts.
+///
+/// # Returns
+///
+/// * `Result<DirectDistribution>` - The per-receiver results on success.
+pub async fn distribute_direct(
+    sender: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    params: Vec<DistributeParam>,
+) -> Result<DirectDistribution> {
+    distribute_direct_with_progress(sender, rpc_http, params, None).await
+}
+
+/// Distributes Ether with plain transfers like [`distribute_direct`], reporting progress
+/// as each receipt is awaited.
+///
+/// Every transfer counts as one chunk; failed and reverted transfers advance
+/// `chunks_completed` but not `recipients_funded`.
+///
+/// # Arguments
+///
+/// * `sender` - The private key signer of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `params` - A vector of `DistributeParam` containing receiver addresses and amounts.
+/// * `progress` - Receives a [`DistributeProgress`] after every transfer (optional, `None` is silent).
+///
+/// # Returns
+///
+/// * `Result<DirectDistribution>` - The per-receiver results on success.
+pub async fn distribute_direct_with_progress(
+    sender: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    params: Vec<DistributeParam>,
+    progress: Option<&dyn Progress<DistributeProgress>>,
+) -> Result<DirectDistribution> {
+    let address = sender.address();
+    let wallet = EthereumWallet::new(sender);
+    let provider = ProviderBuilder::new()
+        .with_recommended_fillers()
+        .wallet(wallet)
+        .on_client(rpc_http.into().client()?);
+
+    // fetch the nonce once and increment it locally
+    let mut nonce = provider.get_transaction_count(address).await?;
+
+    let mut pending = Vec::with_capacity(params.len());
+    let mut nonce_lost = false;
+    for param in &params {
+        if nonce_lost {
+            pending.push(Err(eyre!(
+                "transfer not sent, the nonce of {address} is unknown after a failed transfer"
+            )));
+            continue;
+        }
+
+        let tx = TransactionRequest::default()
+            .with_to(param.receiver)
+            .with_value(param.amount)
+            .with_nonce(nonce);
+
+        let sent = provider.send_transaction(tx).await.map_err(Report::from);
+        match &sent {
+            Ok(_) => nonce += 1,
+            // the request may have reached the node and taken the nonce anyway
+            Err(_) => match provider.get_transaction_count(address).pending().await {
+                Ok(pending_nonce) => nonce = pending_nonce,
+                Err(_) => nonce_lost = true,
+            },
+        }
+        pending.push(sent);
+    }
+
+    let mut transfers = Vec::with_capacity(params.len());
+    let mut state = DistributeProgress {
+        chunk_count: params.len(),
+        ..Default::default()
+    };
+    for (param, sent) in params.iter().zip(pending) {
+        let result = match sent {
+            Ok(builder) => builder.get_receipt().await.map_err(Report::from),
+            Err(error) => Err(error),
+        };
+        let result = result.and_then(|receipt| {
+            state.gas_used += receipt.gas_used;
+            // a reverted transfer pays for its gas but funds nobody
+            if receipt.status() {
+                state.recipients_funded += 1;
+            }
+            ensure!(
+                receipt.status(),
+                "transfer {} reverted",
+                receipt.transaction_hash
+            );
+            Ok(receipt.transaction_hash)
+        });
+
+        state.chunks_completed += 1;
+        if let Some(progress) = progress {
+            progress.update(&state);
+        }
+
+        transfers.push(DirectTransfer {
+            receiver: param.receiver,
+            amount: param.amount,
+            result,
+        });
+    }
+
+    let warning = format!(
+        "direct transfers cost at least {TRANSFER_GAS} gas per recipient ({} recipients); \
+         a distributor contract batches them into a single transaction",
+        params.len()
+    );
+
+    if let Some(progress) = progress {
+        progress.finish();
+    }
+
+    Ok(DirectDistribution {
+        transfers,
+        gas_used: state.gas_used,
+        warning,
+    })
+}
@@ -1,12 +1,22 @@
-use crate::executor::execute;
+use crate::distributor::{
+    has_duplicate_receivers, normalize, validate::checked_params, validate_params, ValidationRules,
+    ZeroAmountPolicy,
+};
+use crate::executor::{ensure_contract, is_contract, ProviderConfig};
 use alloy::{
-    dyn_abi::DynSolValue,
+    dyn_abi::{DynSolValue, JsonAbiExt},
     json_abi::JsonAbi,
-    primitives::{Address, TxHash, U256},
+    network::{EthereumWallet, TransactionBuilder},
+    primitives::{Address, Bytes, TxHash, U256},
+    providers::{Provider, ProviderBuilder},
+    rpc::types::{TransactionReceipt, TransactionRequest},
     signers::local::PrivateKeySigner,
-    transports::http::reqwest::Url,
 };
-use eyre::Result;
+use eyre::{ensure, eyre, Result};
+use futures::future::try_join_all;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet, VecDeque};
 
 /// Parameters for the `distribute` function.
 ///
@@ -14,18 +24,429 @@ use eyre::Result;
 ///
 /// * `receiver` - The address of the receiver.
 /// * `amount` - The amount to be distributed.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DistributeParam {
     pub receiver: Address,
+    #[cfg_attr(feature = "serde", serde(with = "crate::distributor::json::amount"))]
     pub amount: U256,
 }
 
+/// Parameters for the `distributeEtherWithMemo` function.
+///
+/// # Fields
+///
+/// * `receiver` - The address of the receiver.
+/// * `amount` - The amount to be distributed.
+/// * `memo` - The tag attached to the transfer (e.g. for tracking), emitted in a `Memo` event.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DistributeParamWithMemo {
+    pub receiver: Address,
+    pub amount: U256,
+    pub memo: Vec<u8>,
+}
+
+impl DistributeParam {
+    /// Creates params sending the same amount to every receiver.
+    ///
+    /// This is the checked form of [`DistributeParam::from_addresses`], which accepts an
+    /// empty slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `receivers` - The addresses of the receivers.
+    /// * `amount` - The amount each receiver gets.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<DistributeParam>>` - One param per receiver, or an error if `receivers` is empty.
+    pub fn equal(receivers: &[Address], amount: U256) -> Result<Vec<DistributeParam>> {
+        ensure!(!receivers.is_empty(), "no receivers to distribute to");
+
+        Ok(Self::from_addresses(receivers, amount))
+    }
+
+    /// Creates params sending the same amount to the address of every signer.
+    ///
+    /// This is the checked form of [`DistributeParam::from_accounts`], which accepts an
+    /// empty slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `signers` - The private key signers whose addresses receive the amount.
+    /// * `amount` - The amount each receiver gets.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<DistributeParam>>` - One param per signer, or an error if `signers` is empty.
+    pub fn from_signers(
+        signers: &[PrivateKeySigner],
+        amount: U256,
+    ) -> Result<Vec<DistributeParam>> {
+        ensure!(!signers.is_empty(), "no receivers to distribute to");
+
+        Ok(Self::from_accounts(signers, amount))
+    }
+
+    /// Creates params sending the same amount to the address of every account.
+    ///
+    /// This is the infallible form of [`DistributeParam::from_signers`]: an empty slice
+    /// gives empty params instead of an error, e.g. to extend a list built elsewhere.
+    ///
+    /// # Arguments
+    ///
+    /// * `accounts` - The private key signers whose addresses receive the amount.
+    /// * `amount` - The amount each receiver gets.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<DistributeParam>` - One param per account, in the same order.
+    pub fn from_accounts(accounts: &[PrivateKeySigner], amount: U256) -> Vec<DistributeParam> {
+        accounts
+            .iter()
+            .map(|account| Self::new(account.address(), amount))
+            .collect()
+    }
+
+    /// Creates params sending the same amount to every address.
+    ///
+    /// This is the infallible form of [`DistributeParam::equal`]: an empty slice gives
+    /// empty params instead of an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `addresses` - The addresses of the receivers.
+    /// * `amount` - The amount each receiver gets.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<DistributeParam>` - One param per address, in the same order.
+    pub fn from_addresses(addresses: &[Address], amount: U256) -> Vec<DistributeParam> {
+        addresses
+            .iter()
+            .map(|address| Self::new(*address, amount))
+            .collect()
+    }
+
+    /// Creates params like [`DistributeParam::from_accounts`], deriving the addresses in parallel.
+    ///
+    /// Deriving an address hashes the public key of the account, which adds up for hundreds
+    /// of thousands of accounts.
+    ///
+    /// # Arguments
+    ///
+    /// * `accounts` - The private key signers whose addresses receive the amount.
+    /// * `amount` - The amount each receiver gets.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<DistributeParam>` - One param per account, in the same order.
+    pub fn par_from_accounts(accounts: &[PrivateKeySigner], amount: U256) -> Vec<DistributeParam> {
+        accounts
+            .par_iter()
+            .map(|account| Self::new(account.address(), amount))
+            .collect()
+    }
+
+    /// Creates a param.
+    fn new(receiver: Address, amount: U256) -> Self {
+        Self { receiver, amount }
+    }
+
+    /// Creates params sending every receiver a random amount in `[min, max]`.
+    ///
+    /// Amounts are drawn uniformly and independently. With a seed the amounts are reproducible;
+    /// use [`DistributeParam::total`] to check the funding wallet covers them.
+    ///
+    /// # Arguments
+    ///
+    /// * `receivers` - The addresses of the receivers.
+    /// * `min` - The smallest amount, inclusive.
+    /// * `max` - The largest amount, inclusive.
+    /// * `rng_seed` - The seed of the random generator (optional, defaults to a random seed).
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<DistributeParam>>` - One param per receiver, or an error if `receivers` is empty
+    ///   or `min` exceeds `max`.
+    pub fn randomized(
+        receivers: &[Address],
+        min: U256,
+        max: U256,
+        rng_seed: Option<u64>,
+    ) -> Result<Vec<DistributeParam>> {
+        ensure!(!receivers.is_empty(), "no receivers to distribute to");
+        ensure!(min <= max, "min ({min}) exceeds max ({max})");
+
+        let mut rng = match rng_seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        Ok(receivers
+            .iter()
+            .map(|receiver| DistributeParam {
+                receiver: *receiver,
+                amount: uniform_amount(&mut rng, min, max),
+            })
+            .collect())
+    }
+
+    /// Creates params splitting `total` evenly across receivers.
+    ///
+    /// Every receiver gets `total / n`, and the first `total % n` receivers get one more wei,
+    /// so the amounts sum to exactly `total` and differ by at most one wei.
+    ///
+    /// # Arguments
+    ///
+    /// * `receivers` - The addresses of the receivers.
+    /// * `total` - The amount to split.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<DistributeParam>>` - One param per receiver, or an error if `receivers` is empty.
+    pub fn split_evenly(receivers: &[Address], total: U256) -> Result<Vec<DistributeParam>> {
+        ensure!(!receivers.is_empty(), "no receivers to distribute to");
+
+        let count = U256::from(receivers.len());
+        let (share, remainder) = total.div_rem(count);
+        // the remainder is below the receiver count, so it fits in a usize
+        let remainder = remainder.to::<usize>();
+
+        Ok(receivers
+            .iter()
+            .enumerate()
+            .map(|(index, receiver)| DistributeParam {
+                receiver: *receiver,
+                amount: if index < remainder {
+                    share + U256::from(1)
+                } else {
+                    share
+                },
+            })
+            .collect())
+    }
+
+    /// Creates params splitting `total` across receivers in proportion to their weights.
+    ///
+    /// Each share is `total * weight / weight_sum`, rounded down; the remainder of the
+    /// divisions goes to the receiver with the largest weight (the first one on a tie), so
+    /// the shares always sum to exactly `total`. Receivers with a zero weight are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `receivers` - The addresses of the receivers and their weights.
+    /// * `total` - The amount to split.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<DistributeParam>>` - One param per receiver with a non-zero weight, in the
+    ///   order of `receivers`, or an error if every weight is zero or a share overflows.
+    pub fn weighted(receivers: &[(Address, u64)], total: U256) -> Result<Vec<DistributeParam>> {
+        let receivers: Vec<(Address, u64)> = receivers
+            .iter()
+            .copied()
+            .filter(|(_, weight)| *weight > 0)
+            .collect();
+        ensure!(!receivers.is_empty(), "no receivers with a non-zero weight");
+
+        let weight_sum: U256 = receivers
+            .iter()
+            .map(|(_, weight)| U256::from(*weight))
+            .sum();
+
+        let mut params = Vec::with_capacity(receivers.len());
+        for (receiver, weight) in &receivers {
+            let amount = total
+                .checked_mul(U256::from(*weight))
+                .ok_or_else(|| eyre!("share of {receiver} overflows"))?
+                / weight_sum;
+            params.push(DistributeParam {
+                receiver: *receiver,
+                amount,
+            });
+        }
+
+        // the first of the largest weights takes the remainder
+        let mut largest = 0;
+        for (index, (_, weight)) in receivers.iter().enumerate() {
+            if *weight > receivers[largest].1 {
+                largest = index;
+            }
+        }
+        let distributed: U256 = params.iter().map(|param| param.amount).sum();
+        params[largest].amount += total - distributed;
+
+        Ok(params)
+    }
+
+    /// Sums the amounts of params.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The params to sum.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<U256>` - The total amount, or an error if it overflows.
+    pub fn total(params: &[DistributeParam]) -> Result<U256> {
+        checked_total(params)
+    }
+}
+
+/// Draws an amount uniformly from `[min, max]`, rejecting samples outside the range.
+fn uniform_amount(rng: &mut impl Rng, min: U256, max: U256) -> U256 {
+    let range = max - min;
+    if range == U256::MAX {
+        return U256::from_be_bytes(rng.gen::<[u8; 32]>());
+    }
+
+    let span = range + U256::from(1);
+    let bits = span.bit_len();
+    loop {
+        let sample = U256::from_be_bytes(rng.gen::<[u8; 32]>()) >> (256 - bits);
+        if sample < span {
+            return min + sample;
+        }
+    }
+}
+
+/// The order in which receivers are encoded into a distribution.
+///
+/// # Variants
+///
+/// * `None` - Keep the order of the params.
+/// * `LargestFirst` - Largest amounts first.
+/// * `SmallestFirst` - Smallest amounts first.
+/// * `ByAddress` - Ascending receiver addresses.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DistributeSortOrder {
+    #[default]
+    None,
+    LargestFirst,
+    SmallestFirst,
+    ByAddress,
+}
+
+impl DistributeSortOrder {
+    /// Sorts params in this order, keeping the relative order of equal entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The params to sort in place.
+    pub fn sort(&self, params: &mut [DistributeParam]) {
+        match self {
+            Self::None => {}
+            Self::LargestFirst => params.sort_by(|a, b| b.amount.cmp(&a.amount)),
+            Self::SmallestFirst => params.sort_by_key(|param| param.amount),
+            Self::ByAddress => params.sort_by_key(|param| param.receiver),
+        }
+    }
+}
+
+/// The calling convention of a distributor contract.
+///
+/// # Variants
+///
+/// * `Distributor` - The stormint `Distributor`: `distributeEther((address,uint256)[])`.
+/// * `TupleArray` - A custom function taking one `(address,uint256)[]` array.
+/// * `ParallelArrays` - A custom function taking `(address[],uint256[])`, like Disperse's `disperseEther`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum DistributorKind {
+    #[default]
+    Distributor,
+    TupleArray {
+        function_name: String,
+    },
+    ParallelArrays {
+        function_name: String,
+    },
+}
+
+/// Options of a distribution.
+///
+/// # Fields
+///
+/// * `normalize` - Whether to merge duplicate receivers and drop zero amounts first, see [`normalize`].
+/// * `check_contract` - Whether to fail before sending if no code is deployed at the contract address.
+/// * `validation` - The rules the params are validated against before sending, see
+///   [`validate_params`] (optional, `None` only rejects zero addresses and zero amounts,
+///   see [`validate_all`](crate::distributor::validate_all)).
+/// * `sort` - The order in which receivers are funded.
+/// * `kind` - The calling convention of the distributor contract.
+/// * `verify_total` - Whether to check, before sending, that the value of the transaction equals
+///   the sum of the amounts actually encoded in the call.
+/// * `check_duplicates` - Whether to fail if a receiver appears more than once, see [`has_duplicate_receivers`].
+/// * `contract_recipients` - How receivers with code deployed at their address are handled.
+#[derive(Debug, Clone, Default)]
+pub struct DistributeOptions {
+    pub normalize: bool,
+    pub check_contract: bool,
+    pub validation: Option<ValidationRules>,
+    pub sort: DistributeSortOrder,
+    pub kind: DistributorKind,
+    pub verify_total: bool,
+    pub check_duplicates: bool,
+    pub contract_recipients: ContractRecipientPolicy,
+}
+
+/// How receivers that are contracts are handled by a distribution.
+///
+/// Sending Ether to a contract, e.g. a token address pasted as a receiver, is usually a
+/// mistake. `Warn` and `Reject` look up the code of every unique receiver concurrently.
+///
+/// # Variants
+///
+/// * `Allow` - Do not check receivers.
+/// * `Warn` - Send anyway, listing contract receivers in [`DistributeResult::contract_recipients`].
+/// * `Reject` - Fail before sending, naming the contract receivers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ContractRecipientPolicy {
+    #[default]
+    Allow,
+    Warn,
+    Reject,
+}
+
+/// Represents the result of a distribution.
+///
+/// # Fields
+///
+/// * `tx_hash` - The transaction hash of the distribution.
+/// * `gas_used` - The gas used by the transaction.
+/// * `effective_gas_price` - The price paid per unit of gas.
+/// * `block_number` - The number of the block the transaction was included in.
+/// * `status` - Whether the transaction succeeded.
+/// * `total_distributed` - The sum of all distributed amounts.
+/// * `recipient_count` - The number of receivers in the distribution.
+/// * `contract_recipients` - The receivers found to be contracts under [`ContractRecipientPolicy::Warn`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DistributeResult {
+    pub tx_hash: TxHash,
+    pub gas_used: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::distributor::json::decimal"))]
+    pub effective_gas_price: u128,
+    pub block_number: u64,
+    pub status: bool,
+    #[cfg_attr(feature = "serde", serde(with = "crate::distributor::json::amount"))]
+    pub total_distributed: U256,
+    pub recipient_count: usize,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub contract_recipients: Vec<Address>,
+}
+
 /// Distributes Ether to multiple receivers.
 ///
+/// This is a thin wrapper around [`distribute_with_receipt`] returning only the transaction hash.
+///
 /// # Arguments
 ///
 /// * `sender` - The private key signer of the sender.
-/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
 /// * `abi` - The JSON ABI of the contract.
 /// * `contract_address` - The address of the contract.
 /// * `params` - A vector of `DistributeParam` containing receiver addresses and amounts.
@@ -35,11 +456,520 @@ pub struct DistributeParam {
 /// * `Result<TxHash>` - The transaction hash on success.
 pub async fn distribute(
     sender: PrivateKeySigner,
-    rpc_http: Url,
+    rpc_http: impl Into<ProviderConfig>,
     abi: JsonAbi,
     contract_address: Address,
     params: Vec<DistributeParam>,
 ) -> Result<TxHash> {
+    let result = distribute_with_receipt(sender, rpc_http, abi, contract_address, params).await?;
+
+    Ok(result.tx_hash)
+}
+
+/// Distributes the same amount of Ether to every receiver.
+///
+/// # Arguments
+///
+/// * `sender` - The private key signer of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `receivers` - The addresses of the receivers.
+/// * `amount` - The amount each receiver gets.
+///
+/// # Returns
+///
+/// * `Result<TxHash>` - The transaction hash on success.
+pub async fn distribute_equal(
+    sender: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    receivers: &[Address],
+    amount: U256,
+) -> Result<TxHash> {
+    let params = DistributeParam::equal(receivers, amount)?;
+
+    distribute(sender, rpc_http, abi, contract_address, params).await
+}
+
+/// Distributes Ether to multiple receivers and waits for the receipt.
+///
+/// Nothing is sent if a param sends nothing or to the zero address, see
+/// [`validate_all`](crate::distributor::validate_all).
+///
+/// # Arguments
+///
+/// * `sender` - The private key signer of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `params` - A vector of `DistributeParam` containing receiver addresses and amounts.
+///
+/// # Returns
+///
+/// * `Result<DistributeResult>` - The receipt details of the distribution on success.
+pub async fn distribute_with_receipt(
+    sender: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    params: Vec<DistributeParam>,
+) -> Result<DistributeResult> {
+    distribute_with_options(
+        sender,
+        rpc_http,
+        abi,
+        contract_address,
+        params,
+        DistributeOptions::default(),
+    )
+    .await
+}
+
+/// Distributes Ether to multiple receivers with the given options and waits for the receipt.
+///
+/// # Arguments
+///
+/// * `sender` - The private key signer of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `params` - A vector of `DistributeParam` containing receiver addresses and amounts.
+/// * `options` - The options of the distribution.
+///
+/// # Returns
+///
+/// * `Result<DistributeResult>` - The receipt details of the distribution on success.
+pub async fn distribute_with_options(
+    sender: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    params: Vec<DistributeParam>,
+    options: DistributeOptions,
+) -> Result<DistributeResult> {
+    let config: ProviderConfig = rpc_http.into();
+    let prepared = prepare_distribution(
+        sender.address(),
+        config.clone(),
+        contract_address,
+        params,
+        &options,
+    )
+    .await?;
+
+    send_distribution(sender, config, &abi, contract_address, prepared).await
+}
+
+/// Distributes Ether to multiple receivers, tagging every transfer with a memo, and waits for the receipt.
+///
+/// `distributeEtherWithMemo` is called, which emits a `Memo` event for every receiver, so
+/// `options.kind` must be [`DistributorKind::Distributor`]. The params go through the checks
+/// of `options` like in [`distribute_with_options`], and every transfer keeps its memo; a
+/// receiver merged by `options.normalize` keeps its first memo.
+///
+/// Memos deliberately live in [`DistributeParamWithMemo`] rather than in an optional field
+/// of [`DistributeParam`], which callers build as a struct literal throughout, and the
+/// contract function has its own name rather than overloading `distributeEther`, since
+/// functions are looked up in the ABI by name.
+///
+/// # Arguments
+///
+/// * `sender` - The private key signer of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `params` - A vector of `DistributeParamWithMemo` containing receiver addresses, amounts and memos.
+/// * `options` - The options of the distribution.
+///
+/// # Returns
+///
+/// * `Result<DistributeResult>` - The receipt details of the distribution on success.
+pub async fn distribute_with_memo(
+    sender: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    params: Vec<DistributeParamWithMemo>,
+    options: DistributeOptions,
+) -> Result<DistributeResult> {
+    let config: ProviderConfig = rpc_http.into();
+    let memos = Memos::new(&params);
+    let params = params
+        .into_iter()
+        .map(|param| DistributeParam::new(param.receiver, param.amount))
+        .collect();
+    let prepared = prepare(
+        sender.address(),
+        config.clone(),
+        contract_address,
+        params,
+        Some(memos),
+        &options,
+    )
+    .await?;
+
+    send_distribution(sender, config, &abi, contract_address, prepared).await
+}
+
+/// Sends a prepared distribution and waits for its receipt.
+async fn send_distribution(
+    sender: PrivateKeySigner,
+    config: ProviderConfig,
+    abi: &JsonAbi,
+    contract_address: Address,
+    prepared: PreparedDistribution,
+) -> Result<DistributeResult> {
+    // send exactly the calldata `encode` previews
+    let encoded = prepared.encode(abi)?;
+    let tx = TransactionRequest::default()
+        .with_to(contract_address)
+        .with_input(encoded.calldata)
+        .with_value(encoded.total_value);
+
+    let wallet = EthereumWallet::new(sender);
+    let provider = ProviderBuilder::new()
+        .with_recommended_fillers()
+        .wallet(wallet)
+        .on_client(config.client()?);
+    let receipt = provider.send_transaction(tx).await?.get_receipt().await?;
+
+    Ok(prepared.into_result(&receipt))
+}
+
+/// The calldata of a distribution, as it is sent to the distributor contract.
+///
+/// # Fields
+///
+/// * `calldata` - The ABI-encoded input of the call, selector included.
+/// * `total_value` - The Ether value of the transaction, the sum of the amounts.
+/// * `function` - The name of the distributor function called.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodedDistribution {
+    pub calldata: Bytes,
+    pub total_value: U256,
+    pub function: String,
+}
+
+/// Encodes the call of a distribution without sending it, e.g. to review it beforehand.
+///
+/// The params are checked and encoded the way [`distribute`] does, so with the default
+/// [`DistributeOptions`] the calldata is exactly the input of the transaction it sends,
+/// and params it rejects are rejected here too.
+///
+/// # Arguments
+///
+/// * `params` - The params of the distribution.
+/// * `abi` - The JSON ABI of the contract.
+///
+/// # Returns
+///
+/// * `Result<EncodedDistribution>` - The calldata and value, or an error if a param sends
+///   nothing or to the zero address, the function is not in the ABI, or the amounts overflow.
+pub fn encode(params: &[DistributeParam], abi: &JsonAbi) -> Result<EncodedDistribution> {
+    encode_with(params, abi, ZeroAmountPolicy::Reject)
+}
+
+/// Encodes the call of a distribution like [`encode`], handling zero amounts with `zero_amount`.
+///
+/// Under [`ZeroAmountPolicy::Skip`] zero-amount params are left out of the calldata, as
+/// [`validate_params`] removes them before a distribution with that policy is sent.
+///
+/// # Arguments
+///
+/// * `params` - The params of the distribution.
+/// * `abi` - The JSON ABI of the contract.
+/// * `zero_amount` - How params with a zero amount are handled.
+///
+/// # Returns
+///
+/// * `Result<EncodedDistribution>` - The calldata and value, or an error if a param sends
+///   to the zero address or, under [`ZeroAmountPolicy::Reject`], nothing, the function is
+///   not in the ABI, or the amounts overflow.
+pub fn encode_with(
+    params: &[DistributeParam],
+    abi: &JsonAbi,
+    zero_amount: ZeroAmountPolicy,
+) -> Result<EncodedDistribution> {
+    let params = checked_params(params.to_vec(), zero_amount)?;
+    let (function_name, args, value) = distribute_call(&params, &DistributorKind::default())?;
+
+    encode_call(abi, function_name, &args, value)
+}
+
+/// Encodes a distributor call with the function of `abi` named `function_name`.
+fn encode_call(
+    abi: &JsonAbi,
+    function_name: &str,
+    args: &[DynSolValue],
+    value: U256,
+) -> Result<EncodedDistribution> {
+    let function = abi
+        .function(function_name)
+        .and_then(|functions| functions.first())
+        .ok_or_else(|| eyre!("`{function_name}` not found in the ABI"))?;
+
+    Ok(EncodedDistribution {
+        calldata: function.abi_encode_input(args)?.into(),
+        total_value: value,
+        function: function_name.to_string(),
+    })
+}
+
+/// A distribution call that passed the checks of its [`DistributeOptions`], ready to be sent.
+///
+/// # Fields
+///
+/// * `function_name` - The name of the distributor function to call.
+/// * `args` - The encoded arguments of the call.
+/// * `value` - The Ether value of the transaction, the sum of the amounts.
+/// * `recipient_count` - The number of receivers after normalization.
+/// * `contract_recipients` - The receivers found to be contracts under [`ContractRecipientPolicy::Warn`].
+pub(crate) struct PreparedDistribution {
+    pub function_name: String,
+    pub args: Vec<DynSolValue>,
+    pub value: U256,
+    pub recipient_count: usize,
+    pub contract_recipients: Vec<Address>,
+}
+
+impl PreparedDistribution {
+    /// Encodes the call with the function of `abi`.
+    pub fn encode(&self, abi: &JsonAbi) -> Result<EncodedDistribution> {
+        encode_call(abi, &self.function_name, &self.args, self.value)
+    }
+
+    /// Builds the result of the distribution from the receipt of its transaction.
+    pub fn into_result(self, receipt: &TransactionReceipt) -> DistributeResult {
+        DistributeResult {
+            tx_hash: receipt.transaction_hash,
+            gas_used: receipt.gas_used,
+            effective_gas_price: receipt.effective_gas_price,
+            block_number: receipt.block_number.unwrap_or_default(),
+            status: receipt.status(),
+            total_distributed: self.value,
+            recipient_count: self.recipient_count,
+            contract_recipients: self.contract_recipients,
+        }
+    }
+}
+
+/// Runs the pre-checks of `options` on a distribution and encodes its call.
+///
+/// # Arguments
+///
+/// * `sender` - The address of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `contract_address` - The address of the contract.
+/// * `params` - A vector of `DistributeParam` containing receiver addresses and amounts.
+/// * `options` - The options of the distribution.
+///
+/// # Returns
+///
+/// * `Result<PreparedDistribution>` - The call to send, or the first check that failed.
+pub(crate) async fn prepare_distribution(
+    sender: Address,
+    rpc_http: impl Into<ProviderConfig>,
+    contract_address: Address,
+    params: Vec<DistributeParam>,
+    options: &DistributeOptions,
+) -> Result<PreparedDistribution> {
+    prepare(
+        sender,
+        rpc_http.into(),
+        contract_address,
+        params,
+        None,
+        options,
+    )
+    .await
+}
+
+/// Runs the pre-checks of `options` like [`prepare_distribution`], encoding a
+/// `distributeEtherWithMemo` call if `memos` are given.
+async fn prepare(
+    sender: Address,
+    config: ProviderConfig,
+    contract_address: Address,
+    params: Vec<DistributeParam>,
+    memos: Option<Memos>,
+    options: &DistributeOptions,
+) -> Result<PreparedDistribution> {
+    if options.check_contract {
+        ensure_contract(config.clone(), contract_address).await?;
+    }
+
+    let params = if options.normalize {
+        normalize(params)?.params
+    } else {
+        params
+    };
+    // without rules, the params are still checked the way `encode` checks them
+    let mut params = match &options.validation {
+        Some(rules) => validate_params(sender, params, rules)?,
+        None => checked_params(params, ZeroAmountPolicy::Reject)?,
+    };
+    options.sort.sort(&mut params);
+
+    if options.check_duplicates {
+        ensure_unique_receivers(&params)?;
+    }
+
+    let contract_recipients = match options.contract_recipients {
+        ContractRecipientPolicy::Allow => Vec::new(),
+        ContractRecipientPolicy::Warn => contract_receivers(config, &params).await?,
+        ContractRecipientPolicy::Reject => {
+            let contracts = contract_receivers(config, &params).await?;
+            if !contracts.is_empty() {
+                let contracts: Vec<String> = contracts.iter().map(Address::to_string).collect();
+                return Err(eyre!("receivers are contracts: {}", contracts.join(", ")));
+            }
+            Vec::new()
+        }
+    };
+
+    let (function_name, args, value) = match memos {
+        Some(memos) => memo_call(&params, memos, options)?,
+        None => distribute_call(&params, &options.kind)?,
+    };
+    if options.verify_total {
+        let encoded = encoded_total(&args)?;
+        ensure!(
+            encoded == value,
+            "total value {value} does not match the encoded amounts {encoded}"
+        );
+    }
+
+    Ok(PreparedDistribution {
+        function_name: function_name.to_string(),
+        args,
+        value,
+        recipient_count: params.len(),
+        contract_recipients,
+    })
+}
+
+/// Fails if a receiver appears more than once, naming every duplicate.
+pub(crate) fn ensure_unique_receivers(params: &[DistributeParam]) -> Result<()> {
+    if let Some(duplicates) = has_duplicate_receivers(params) {
+        let duplicates: Vec<String> = duplicates.iter().map(Address::to_string).collect();
+        return Err(eyre!("duplicate receivers: {}", duplicates.join(", ")));
+    }
+
+    Ok(())
+}
+
+/// Returns the unique receivers that have code deployed at their address.
+///
+/// # Arguments
+///
+/// * `config` - The connection to the Ethereum RPC endpoint.
+/// * `params` - The params whose receivers are checked.
+///
+/// # Returns
+///
+/// * `Result<Vec<Address>>` - The contract receivers, in the order they first appear.
+async fn contract_receivers(
+    config: ProviderConfig,
+    params: &[DistributeParam],
+) -> Result<Vec<Address>> {
+    let mut receivers: Vec<Address> = params.iter().map(|param| param.receiver).collect();
+    let mut seen = HashSet::with_capacity(receivers.len());
+    receivers.retain(|receiver| seen.insert(*receiver));
+
+    let checks = try_join_all(
+        receivers
+            .iter()
+            .map(|receiver| is_contract(config.clone(), *receiver)),
+    )
+    .await?;
+
+    Ok(receivers
+        .into_iter()
+        .zip(checks)
+        .filter_map(|(receiver, is_contract)| is_contract.then_some(receiver))
+        .collect())
+}
+
+/// Encodes params into the arguments of a distribution call.
+///
+/// # Arguments
+///
+/// * `params` - The params to encode.
+/// * `kind` - The calling convention of the distributor contract.
+///
+/// # Returns
+///
+/// * `Result<(&str, Vec<DynSolValue>, U256)>` - The function name, the encoded arguments and the total value,
+///   or an error if the amounts overflow.
+pub(crate) fn distribute_call<'a>(
+    params: &[DistributeParam],
+    kind: &'a DistributorKind,
+) -> Result<(&'a str, Vec<DynSolValue>, U256)> {
+    let value = checked_total(params)?;
+
+    let tuples = || {
+        DynSolValue::Array(
+            params
+                .iter()
+                .map(|r| {
+                    DynSolValue::Tuple(vec![
+                        DynSolValue::from(r.receiver),
+                        DynSolValue::from(r.amount),
+                    ])
+                })
+                .collect(),
+        )
+    };
+
+    match kind {
+        DistributorKind::Distributor => Ok(("distributeEther", vec![tuples()], value)),
+        DistributorKind::TupleArray { function_name } => {
+            Ok((function_name.as_str(), vec![tuples()], value))
+        }
+        DistributorKind::ParallelArrays { function_name } => {
+            let receivers = params
+                .iter()
+                .map(|param| DynSolValue::from(param.receiver))
+                .collect();
+            let amounts = params
+                .iter()
+                .map(|param| DynSolValue::from(param.amount))
+                .collect();
+
+            Ok((
+                function_name.as_str(),
+                vec![DynSolValue::Array(receivers), DynSolValue::Array(amounts)],
+                value,
+            ))
+        }
+    }
+}
+
+/// Encodes params into the arguments of a `distributeEtherWithMemo` call.
+///
+/// # Arguments
+///
+/// * `params` - The params to encode, after the checks of `options`.
+/// * `memos` - The memos of the params.
+/// * `options` - The options the params were checked with.
+///
+/// # Returns
+///
+/// * `Result<(&str, Vec<DynSolValue>, U256)>` - The function name, the encoded arguments and the total value,
+///   or an error if the contract is not the stormint distributor or the amounts overflow.
+fn memo_call(
+    params: &[DistributeParam],
+    mut memos: Memos,
+    options: &DistributeOptions,
+) -> Result<(&'static str, Vec<DynSolValue>, U256)> {
+    ensure!(
+        options.kind == DistributorKind::Distributor,
+        "memos are only supported by the stormint distributor"
+    );
+    let value = checked_total(params)?;
+
     let txns = DynSolValue::Array(
         params
             .iter()
@@ -47,26 +977,509 @@ pub async fn distribute(
                 DynSolValue::Tuple(vec![
                     DynSolValue::from(r.receiver),
                     DynSolValue::from(r.amount),
+                    DynSolValue::Bytes(memos.take(r, options.normalize)),
                 ])
             })
             .collect(),
     );
 
-    let args = &[txns];
+    Ok(("distributeEtherWithMemo", vec![txns], value))
+}
 
-    let value: U256 = params.iter().map(|param| param.amount).sum();
+/// The memos of a distribution, matched back to its params once they are checked.
+///
+/// Checks may drop, merge and reorder params, so memos are looked up by receiver and amount,
+/// in their original order for identical params.
+struct Memos {
+    by_param: HashMap<(Address, U256), VecDeque<Vec<u8>>>,
+    first: HashMap<Address, Vec<u8>>,
+}
 
-    let tx_hash = execute(
-        sender,
-        rpc_http,
-        abi,
-        contract_address,
-        "distributeEther",
-        args,
-        Some(value),
-    )
-    .await?
-    .tx_hash;
+impl Memos {
+    /// Indexes the memos of params.
+    fn new(params: &[DistributeParamWithMemo]) -> Self {
+        let mut memos = Self {
+            by_param: HashMap::new(),
+            first: HashMap::new(),
+        };
+        for param in params {
+            memos
+                .by_param
+                .entry((param.receiver, param.amount))
+                .or_default()
+                .push_back(param.memo.clone());
+            memos
+                .first
+                .entry(param.receiver)
+                .or_insert_with(|| param.memo.clone());
+        }
+
+        memos
+    }
+
+    /// Takes the memo of a checked param, the first memo of its receiver if params were merged.
+    fn take(&mut self, param: &DistributeParam, merged: bool) -> Vec<u8> {
+        if merged {
+            return self.first.remove(&param.receiver).unwrap_or_default();
+        }
+
+        self.by_param
+            .get_mut(&(param.receiver, param.amount))
+            .and_then(VecDeque::pop_front)
+            .unwrap_or_default()
+    }
+}
+
+/// Sums the amounts of params, failing instead of wrapping on overflow.
+///
+/// # Arguments
+///
+/// * `params` - The params to sum.
+///
+/// # Returns
+///
+/// * `Result<U256>` - The total amount, or an error naming the param that overflowed.
+pub(crate) fn checked_total(params: &[DistributeParam]) -> Result<U256> {
+    params
+        .iter()
+        .enumerate()
+        .try_fold(U256::ZERO, |total, (index, param)| {
+            total.checked_add(param.amount).ok_or_else(|| {
+                eyre!(
+                    "total amount overflows uint256 at param {index} (receiver {})",
+                    param.receiver
+                )
+            })
+        })
+}
+
+/// Sums the amounts encoded in the arguments of a distribution call.
+///
+/// Amounts are read back from the `(address,uint256[,bytes])[]` tuples, or from the
+/// second array of a parallel-arrays call.
+fn encoded_total(args: &[DynSolValue]) -> Result<U256> {
+    let amounts: Vec<&DynSolValue> = match args {
+        [DynSolValue::Array(txns)] => txns
+            .iter()
+            .map(|txn| match txn {
+                DynSolValue::Tuple(fields) => fields
+                    .get(1)
+                    .ok_or_else(|| eyre!("missing amount in encoded transfer")),
+                _ => Err(eyre!("unexpected encoded transfer")),
+            })
+            .collect::<Result<_>>()?,
+        [DynSolValue::Array(_), DynSolValue::Array(amounts)] => amounts.iter().collect(),
+        _ => return Err(eyre!("unexpected distribution arguments")),
+    };
+
+    amounts.into_iter().try_fold(U256::ZERO, |total, amount| {
+        let (amount, _) = amount
+            .as_uint()
+            .ok_or_else(|| eyre!("unexpected encoded amount"))?;
+        total
+            .checked_add(amount)
+            .ok_or_else(|| eyre!("encoded amounts overflow uint256"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equal_params() {
+        let receivers = [Address::repeat_byte(1), Address::repeat_byte(2)];
+        let params = DistributeParam::equal(&receivers, U256::from(7)).unwrap();
+
+        assert_eq!(params.len(), 2);
+        for (param, receiver) in params.iter().zip(receivers) {
+            assert_eq!(param.receiver, receiver);
+            assert_eq!(param.amount, U256::from(7));
+        }
+    }
+
+    #[test]
+    fn test_params_from_signers() {
+        let signers = [PrivateKeySigner::random(), PrivateKeySigner::random()];
+        let params = DistributeParam::from_signers(&signers, U256::from(7)).unwrap();
+
+        let receivers: Vec<Address> = params.iter().map(|param| param.receiver).collect();
+        assert_eq!(receivers, [signers[0].address(), signers[1].address()]);
+    }
+
+    #[test]
+    fn test_params_from_accounts() {
+        let accounts: Vec<PrivateKeySigner> = (0..50).map(|_| PrivateKeySigner::random()).collect();
+        let addresses: Vec<Address> = accounts.iter().map(|account| account.address()).collect();
+
+        let params = DistributeParam::from_accounts(&accounts, U256::from(7));
+        assert_eq!(
+            params,
+            DistributeParam::from_addresses(&addresses, U256::from(7))
+        );
+        assert_eq!(
+            params,
+            DistributeParam::par_from_accounts(&accounts, U256::from(7))
+        );
+        assert_eq!(
+            params,
+            DistributeParam::equal(&addresses, U256::from(7)).unwrap()
+        );
+
+        assert!(DistributeParam::from_accounts(&[], U256::from(7)).is_empty());
+        assert!(DistributeParam::from_addresses(&[], U256::from(7)).is_empty());
+    }
+
+    #[test]
+    fn test_split_evenly() {
+        let receivers = [
+            Address::repeat_byte(1),
+            Address::repeat_byte(2),
+            Address::repeat_byte(3),
+        ];
+        let params = DistributeParam::split_evenly(&receivers, U256::from(11)).unwrap();
+
+        // the remainder of 2 wei goes to the first two receivers
+        let amounts: Vec<U256> = params.iter().map(|param| param.amount).collect();
+        assert_eq!(amounts, [U256::from(4), U256::from(4), U256::from(3)]);
+
+        assert!(DistributeParam::split_evenly(&[], U256::from(11)).is_err());
+    }
+
+    #[test]
+    fn test_split_evenly_random_totals() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..200 {
+            let count = rng.gen_range(1..=300);
+            let total = U256::from_be_bytes(rng.gen::<[u8; 32]>()) >> rng.gen_range(0..256usize);
+            let receivers: Vec<Address> = (0..count)
+                .map(|i: u16| Address::left_padding_from(&i.to_be_bytes()))
+                .collect();
+
+            let params = DistributeParam::split_evenly(&receivers, total).unwrap();
+            let amounts: Vec<U256> = params.iter().map(|param| param.amount).collect();
+            let (min, max) = (amounts.iter().min().unwrap(), amounts.iter().max().unwrap());
+
+            assert_eq!(params.len(), receivers.len());
+            assert_eq!(DistributeParam::total(&params).unwrap(), total);
+            assert!(*max - *min <= U256::from(1));
+        }
+    }
+
+    #[test]
+    fn test_weighted_params() {
+        let receivers = [
+            (Address::repeat_byte(1), 1),
+            (Address::repeat_byte(2), 0),
+            (Address::repeat_byte(3), 3),
+            (Address::repeat_byte(4), 2),
+        ];
+        let total = U256::from(1_000_000_000_000_000_001u64);
+        let params = DistributeParam::weighted(&receivers, total).unwrap();
+
+        // the zero weight is skipped and the shares sum to exactly the total
+        let addresses: Vec<Address> = params.iter().map(|param| param.receiver).collect();
+        assert_eq!(
+            addresses,
+            [
+                Address::repeat_byte(1),
+                Address::repeat_byte(3),
+                Address::repeat_byte(4)
+            ]
+        );
+        assert_eq!(DistributeParam::total(&params).unwrap(), total);
+
+        assert!(DistributeParam::weighted(&[(Address::repeat_byte(1), 0)], total).is_err());
+        assert!(DistributeParam::weighted(&[], total).is_err());
+    }
+
+    #[test]
+    fn test_weighted_remainder() {
+        // 11 splits into 2, 4 and 4, leaving 1 for the first of the largest weights
+        let receivers = [
+            (Address::repeat_byte(1), 1),
+            (Address::repeat_byte(2), 2),
+            (Address::repeat_byte(3), 2),
+        ];
+        let params = DistributeParam::weighted(&receivers, U256::from(11)).unwrap();
+
+        let amounts: Vec<U256> = params.iter().map(|param| param.amount).collect();
+        assert_eq!(amounts, [U256::from(2), U256::from(5), U256::from(4)]);
+    }
+
+    #[test]
+    fn test_weighted_equal_weights() {
+        let receivers = [Address::repeat_byte(1), Address::repeat_byte(2)];
+        let weighted: Vec<(Address, u64)> =
+            receivers.iter().map(|receiver| (*receiver, 5)).collect();
+
+        assert_eq!(
+            DistributeParam::weighted(&weighted, U256::from(14)).unwrap(),
+            DistributeParam::equal(&receivers, U256::from(7)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_sort_order_of_encoded_txns() {
+        let params = || {
+            vec![
+                DistributeParam {
+                    receiver: Address::repeat_byte(2),
+                    amount: U256::from(1),
+                },
+                DistributeParam {
+                    receiver: Address::repeat_byte(3),
+                    amount: U256::from(3),
+                },
+                DistributeParam {
+                    receiver: Address::repeat_byte(1),
+                    amount: U256::from(2),
+                },
+            ]
+        };
+        let encoded_receivers = |order: DistributeSortOrder| {
+            let mut params = params();
+            order.sort(&mut params);
+
+            let (_, mut args, _) = distribute_call(&params, &DistributorKind::default()).unwrap();
+            match args.remove(0) {
+                DynSolValue::Array(txns) => txns
+                    .into_iter()
+                    .map(|txn| match txn {
+                        DynSolValue::Tuple(fields) => fields[0].as_address().unwrap(),
+                        _ => panic!("expected a tuple"),
+                    })
+                    .collect::<Vec<_>>(),
+                _ => panic!("expected an array"),
+            }
+        };
+
+        let bytes = |receivers: Vec<Address>| -> Vec<u8> {
+            receivers.iter().map(|receiver| receiver[0]).collect()
+        };
+        assert_eq!(
+            bytes(encoded_receivers(DistributeSortOrder::None)),
+            [2, 3, 1]
+        );
+        assert_eq!(
+            bytes(encoded_receivers(DistributeSortOrder::LargestFirst)),
+            [3, 1, 2]
+        );
+        assert_eq!(
+            bytes(encoded_receivers(DistributeSortOrder::SmallestFirst)),
+            [2, 1, 3]
+        );
+        assert_eq!(
+            bytes(encoded_receivers(DistributeSortOrder::ByAddress)),
+            [1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn test_parallel_arrays_call() {
+        let params = DistributeParam::equal(&[Address::repeat_byte(1)], U256::from(7)).unwrap();
+        let kind = DistributorKind::ParallelArrays {
+            function_name: "disperseEther".to_string(),
+        };
+
+        let (function_name, args, value) = distribute_call(&params, &kind).unwrap();
+        assert_eq!(function_name, "disperseEther");
+        assert_eq!(
+            args,
+            [
+                DynSolValue::Array(vec![DynSolValue::from(Address::repeat_byte(1))]),
+                DynSolValue::Array(vec![DynSolValue::from(U256::from(7))]),
+            ]
+        );
+        assert_eq!(value, U256::from(7));
+    }
+
+    #[test]
+    fn test_memo_call() {
+        let memo_param = |byte: u8, amount: u64, memo: &[u8]| DistributeParamWithMemo {
+            receiver: Address::repeat_byte(byte),
+            amount: U256::from(amount),
+            memo: memo.to_vec(),
+        };
+        let with_memos = vec![
+            memo_param(1, 1, b"a"),
+            memo_param(2, 5, b"b"),
+            memo_param(1, 1, b"c"),
+        ];
+        let sorted = |with_memos: &[DistributeParamWithMemo]| {
+            let mut params: Vec<DistributeParam> = with_memos
+                .iter()
+                .map(|param| DistributeParam::new(param.receiver, param.amount))
+                .collect();
+            DistributeSortOrder::LargestFirst.sort(&mut params);
+            params
+        };
+        let memos_of = |args: &[DynSolValue]| -> Vec<DynSolValue> {
+            match args {
+                [DynSolValue::Array(txns)] => txns
+                    .iter()
+                    .map(|txn| match txn {
+                        DynSolValue::Tuple(fields) => fields[2].clone(),
+                        _ => unreachable!(),
+                    })
+                    .collect(),
+                _ => unreachable!(),
+            }
+        };
+
+        // reordered params keep their memos, identical ones in their original order
+        let options = DistributeOptions::default();
+        let (function_name, args, value) =
+            memo_call(&sorted(&with_memos), Memos::new(&with_memos), &options).unwrap();
+        assert_eq!(function_name, "distributeEtherWithMemo");
+        assert_eq!(value, U256::from(7));
+        assert_eq!(
+            memos_of(&args),
+            [
+                DynSolValue::Bytes(b"b".to_vec()),
+                DynSolValue::Bytes(b"a".to_vec()),
+                DynSolValue::Bytes(b"c".to_vec()),
+            ]
+        );
+
+        // a merged receiver keeps its first memo
+        let options = DistributeOptions {
+            normalize: true,
+            ..Default::default()
+        };
+        let merged = normalize(sorted(&with_memos)).unwrap().params;
+        let (_, args, _) = memo_call(&merged, Memos::new(&with_memos), &options).unwrap();
+        assert_eq!(
+            memos_of(&args),
+            [
+                DynSolValue::Bytes(b"b".to_vec()),
+                DynSolValue::Bytes(b"a".to_vec()),
+            ]
+        );
+
+        // only the stormint distributor takes memos
+        let options = DistributeOptions {
+            kind: DistributorKind::TupleArray {
+                function_name: "batchSend".to_string(),
+            },
+            ..Default::default()
+        };
+        assert!(memo_call(&sorted(&with_memos), Memos::new(&with_memos), &options).is_err());
+    }
+
+    #[test]
+    fn test_params_equality() {
+        let param = DistributeParam {
+            receiver: Address::repeat_byte(1),
+            amount: U256::from(7),
+        };
+
+        assert_eq!(param.clone(), param);
+        assert_ne!(
+            DistributeParam {
+                amount: U256::from(8),
+                ..param.clone()
+            },
+            param
+        );
+    }
+
+    #[test]
+    fn test_equal_params_rejects_empty_receivers() {
+        assert!(DistributeParam::equal(&[], U256::from(7)).is_err());
+        assert!(DistributeParam::from_signers(&[], U256::from(7)).is_err());
+    }
+
+    #[test]
+    fn test_total_overflow_errors() {
+        let params = vec![
+            DistributeParam {
+                receiver: Address::repeat_byte(1),
+                amount: U256::MAX,
+            },
+            DistributeParam {
+                receiver: Address::repeat_byte(2),
+                amount: U256::MAX,
+            },
+        ];
+
+        assert!(checked_total(&params).is_err());
+        assert!(distribute_call(&params, &DistributorKind::default()).is_err());
+    }
+
+    #[test]
+    fn test_encode_zero_amount_policy() {
+        let abi =
+            JsonAbi::parse(["function distributeEther((address,uint256)[] txns) payable"]).unwrap();
+        let params = vec![
+            DistributeParam::new(Address::repeat_byte(1), U256::from(7)),
+            DistributeParam::new(Address::repeat_byte(2), U256::ZERO),
+        ];
+
+        assert!(encode(&params, &abi).is_err());
+        assert!(encode_with(&params, &abi, ZeroAmountPolicy::Reject).is_err());
+
+        // the zero amount is left out, as `validate_params` removes it before sending
+        let encoded = encode_with(&params, &abi, ZeroAmountPolicy::Skip).unwrap();
+        let expected = encode(&params[..1], &abi).unwrap();
+        assert_eq!(encoded, expected);
+    }
+
+    #[test]
+    fn test_encoded_total_matches_value() {
+        let params = DistributeParam::equal(
+            &[Address::repeat_byte(1), Address::repeat_byte(2)],
+            U256::from(5),
+        )
+        .unwrap();
+        let kinds = [
+            DistributorKind::Distributor,
+            DistributorKind::ParallelArrays {
+                function_name: "disperseEther".to_string(),
+            },
+        ];
+
+        for kind in kinds {
+            let (_, args, value) = distribute_call(&params, &kind).unwrap();
+            assert_eq!(value, U256::from(10));
+            assert_eq!(encoded_total(&args).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_randomized_params() {
+        let receivers: Vec<Address> = (1..=50).map(Address::repeat_byte).collect();
+        let (min, max) = (U256::from(1_000), U256::from(2_000));
+
+        let params = DistributeParam::randomized(&receivers, min, max, Some(7)).unwrap();
+        assert_eq!(params.len(), receivers.len());
+        assert!(params
+            .iter()
+            .all(|param| param.amount >= min && param.amount <= max));
+
+        // the same seed gives the same amounts
+        assert_eq!(
+            DistributeParam::randomized(&receivers, min, max, Some(7)).unwrap(),
+            params
+        );
+
+        let sum = params
+            .iter()
+            .fold(U256::ZERO, |sum, param| sum + param.amount);
+        assert_eq!(DistributeParam::total(&params).unwrap(), sum);
+    }
+
+    #[test]
+    fn test_randomized_params_bounds() {
+        let receivers = [Address::repeat_byte(1), Address::repeat_byte(2)];
+
+        let params =
+            DistributeParam::randomized(&receivers, U256::from(5), U256::from(5), None).unwrap();
+        assert!(params.iter().all(|param| param.amount == U256::from(5)));
 
-    Ok(tx_hash)
+        assert!(
+            DistributeParam::randomized(&receivers, U256::from(6), U256::from(5), None).is_err()
+        );
+        assert!(DistributeParam::randomized(&[], U256::ZERO, U256::from(5), None).is_err());
+    }
 }
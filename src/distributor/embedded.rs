@@ -0,0 +1,83 @@
+use crate::executor::{self, ProviderConfig};
+use alloy::{
+    hex,
+    json_abi::JsonAbi,
+    primitives::{Address, Bytes, B256},
+    signers::local::PrivateKeySigner,
+};
+use eyre::{eyre, Result, WrapErr};
+use serde_json::Value;
+
+/// The ABI and bytecode of the `Distributor` contract, exported from `forge build` by
+/// `contracts/script/export-artifact.sh` and committed, so the crate builds without Foundry.
+const DISTRIBUTOR_ARTIFACT: &str = include_str!("artifacts/Distributor.json");
+
+/// The `Distributor` contract embedded in the crate.
+///
+/// # Fields
+///
+/// * `abi` - The JSON ABI of the contract.
+/// * `bytecode` - The creation bytecode of the contract.
+/// * `compiler_version` - The version of the Solidity compiler the artifact was built with,
+///   e.g. `0.8.28+commit.7893614a`.
+/// * `source_hash` - The keccak-256 hash of the `Distributor.sol` source the artifact was
+///   built from.
+#[derive(Debug, Clone)]
+pub struct DistributorArtifact {
+    pub abi: JsonAbi,
+    pub bytecode: Bytes,
+    pub compiler_version: String,
+    pub source_hash: B256,
+}
+
+/// Returns the embedded `Distributor` artifact.
+///
+/// # Returns
+///
+/// * `Result<DistributorArtifact>` - The ABI, bytecode, compiler version and source hash of
+///   the contract.
+pub fn distributor_artifact() -> Result<DistributorArtifact> {
+    let artifact: Value =
+        serde_json::from_str(DISTRIBUTOR_ARTIFACT).wrap_err("invalid Distributor artifact")?;
+
+    let abi = serde_json::from_value(artifact["abi"].clone())
+        .wrap_err("invalid ABI in the Distributor artifact")?;
+    let bytecode = artifact["bytecode"]
+        .as_str()
+        .ok_or_else(|| eyre!("no bytecode in the Distributor artifact"))?;
+    let compiler_version = artifact["compiler_version"]
+        .as_str()
+        .ok_or_else(|| eyre!("no compiler version in the Distributor artifact"))?;
+    let source_hash = artifact["source_hash"]
+        .as_str()
+        .ok_or_else(|| eyre!("no source hash in the Distributor artifact"))?;
+
+    Ok(DistributorArtifact {
+        abi,
+        bytecode: hex::decode(bytecode)?.into(),
+        compiler_version: compiler_version.to_string(),
+        source_hash: source_hash.parse()?,
+    })
+}
+
+/// Deploys the embedded `Distributor` contract.
+///
+/// # Arguments
+///
+/// * `signer` - The private key signer paying for the deployment.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+///
+/// # Returns
+///
+/// * `Result<(Address, JsonAbi)>` - The address and the JSON ABI to pass to
+///   [`distribute`](crate::distributor::distribute) on success.
+pub async fn deploy(
+    signer: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+) -> Result<(Address, JsonAbi)> {
+    let DistributorArtifact { abi, bytecode, .. } = distributor_artifact()?;
+
+    let address = executor::deploy(signer, rpc_http, bytecode).await?.address;
+
+    Ok((address, abi))
+}
@@ -0,0 +1,171 @@
+use crate::distributor::{RECEIPT_POLL_INTERVAL, RECEIPT_TIMEOUT};
+use crate::executor::{
+    submit_with_options, watch_pending_tx, ExecuteOptions, ProviderConfig, TxStatus,
+};
+use alloy::{
+    dyn_abi::{DynSolValue, JsonAbiExt},
+    json_abi::JsonAbi,
+    primitives::{Address, TxHash, U256},
+    providers::{Provider, ProviderBuilder},
+    signers::local::PrivateKeySigner,
+};
+use eyre::{ensure, eyre, Report, Result};
+
+/// Human-readable ABI of the ERC-721 functions and errors used by the distributor.
+const ERC721_ABI: [&str; 5] = [
+    "function safeTransferFrom(address from, address to, uint256 tokenId)",
+    "error ERC721NonexistentToken(uint256 tokenId)",
+    "error ERC721IncorrectOwner(address sender, uint256 tokenId, address owner)",
+    "error ERC721InsufficientApproval(address operator, uint256 tokenId)",
+    "error ERC721InvalidReceiver(address receiver)",
+];
+
+/// Represents the result of transferring one token of an ERC-721 distribution.
+///
+/// # Fields
+///
+/// * `to` - The address of the receiver.
+/// * `token_id` - The ID of the token.
+/// * `result` - The transaction hash on success, or an error report naming the decoded
+///   revert reason when the token does not exist or is not owned by the sender.
+#[derive(Debug)]
+pub struct Erc721Transfer {
+    pub to: Address,
+    pub token_id: U256,
+    pub result: Result<TxHash, Report>,
+}
+
+/// Distributes ERC-721 tokens, one `safeTransferFrom(sender, to, token_id)` per assignment.
+///
+/// Transfers are submitted one after another with nonces assigned by the function, then
+/// their receipts are awaited; an assignment that fails does not consume a nonce, so it
+/// never blocks the ones after it.
+///
+/// Batching the transfers through a multisend contract is not supported on purpose: the
+/// sender would have to approve that contract with `setApprovalForAll`, which lets anybody
+/// calling it move every token of the sender.
+///
+/// # Arguments
+///
+/// * `sender` - The private key signer of the account holding the tokens.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `nft` - The address of the ERC-721 contract.
+/// * `assignments` - The receiver and token ID of every transfer.
+///
+/// # Returns
+///
+/// * `Result<Vec<Erc721Transfer>>` - The result of every assignment, in the same order.
+pub async fn distribute_erc721(
+    sender: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    nft: Address,
+    assignments: Vec<(Address, U256)>,
+) -> Result<Vec<Erc721Transfer>> {
+    ensure!(!assignments.is_empty(), "no tokens to distribute");
+
+    let abi = JsonAbi::parse(ERC721_ABI)?;
+    distribute_sequential(sender, rpc_http.into(), &abi, nft, assignments).await
+}
+
+/// Submits one transfer per assignment with consecutive nonces, then awaits the receipts.
+async fn distribute_sequential(
+    sender: PrivateKeySigner,
+    config: ProviderConfig,
+    abi: &JsonAbi,
+    nft: Address,
+    assignments: Vec<(Address, U256)>,
+) -> Result<Vec<Erc721Transfer>> {
+    let from = sender.address();
+    let provider = ProviderBuilder::new().on_client(config.client()?);
+    let mut nonce = provider.get_transaction_count(from).pending().await?;
+
+    let mut submissions = Vec::with_capacity(assignments.len());
+    for (to, token_id) in &assignments {
+        let options = ExecuteOptions {
+            nonce: Some(nonce),
+            ..Default::default()
+        };
+        let args = [
+            DynSolValue::from(from),
+            DynSolValue::from(*to),
+            DynSolValue::from(*token_id),
+        ];
+        let submission = submit_with_options(
+            sender.clone(),
+            config.clone(),
+            abi.clone(),
+            nft,
+            "safeTransferFrom",
+            &args,
+            options,
+        )
+        .await;
+
+        // a rejected transfer is never sent, so its nonce goes to the next one
+        if submission.is_ok() {
+            nonce += 1;
+        }
+        submissions.push(
+            submission
+                .map(|execution| execution.tx_hash)
+                .map_err(|error| with_revert_reason(error, abi)),
+        );
+    }
+
+    let mut transfers = Vec::with_capacity(assignments.len());
+    for ((to, token_id), submission) in assignments.into_iter().zip(submissions) {
+        let result = match submission {
+            Ok(tx_hash) => confirm(config.clone(), tx_hash).await,
+            Err(error) => Err(error),
+        };
+        transfers.push(Erc721Transfer {
+            to,
+            token_id,
+            result,
+        });
+    }
+
+    Ok(transfers)
+}
+
+/// Waits for a transfer to be mined and fails if it reverted.
+async fn confirm(config: ProviderConfig, tx_hash: TxHash) -> Result<TxHash> {
+    match watch_pending_tx(config, tx_hash, RECEIPT_POLL_INTERVAL, RECEIPT_TIMEOUT).await? {
+        TxStatus::Confirmed(receipt) if receipt.status() => Ok(tx_hash),
+        TxStatus::Confirmed(_) => Err(eyre!("transaction {tx_hash} reverted")),
+        TxStatus::Dropped => Err(eyre!("transaction {tx_hash} was dropped")),
+        TxStatus::TimedOut => Err(eyre!("transaction {tx_hash} is still pending")),
+    }
+}
+
+/// Adds the decoded custom error of a reverted call to its error report, if it is in `abi`.
+fn with_revert_reason(error: Report, abi: &JsonAbi) -> Report {
+    match decode_revert(&error, abi) {
+        Some(reason) => error.wrap_err(format!("reverted with {reason}")),
+        None => error,
+    }
+}
+
+/// Decodes the revert data carried by an RPC error with the custom errors of `abi`.
+fn decode_revert(error: &Report, abi: &JsonAbi) -> Option<String> {
+    let data = match error.downcast_ref::<alloy::contract::Error>()? {
+        alloy::contract::Error::TransportError(error) => error.as_error_resp()?.as_revert_data()?,
+        _ => return None,
+    };
+
+    let selector = data.get(..4)?;
+    let custom_error = abi
+        .errors()
+        .find(|custom_error| custom_error.selector().as_slice() == selector)?;
+    let args = custom_error.abi_decode_input(&data[4..], true).ok()?;
+    let args: Vec<String> = args
+        .iter()
+        .map(|arg| match arg {
+            DynSolValue::Address(address) => address.to_string(),
+            DynSolValue::Uint(value, _) => value.to_string(),
+            arg => format!("{arg:?}"),
+        })
+        .collect();
+
+    Some(format!("{}({})", custom_error.name, args.join(", ")))
+}
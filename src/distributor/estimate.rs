@@ -0,0 +1,270 @@
+use crate::distributor::{
+    chunked::distribute_request, distribute::checked_total, DistributeParam, DEFAULT_MAX_RECIPIENTS,
+};
+use crate::executor::{get_block, ProviderConfig};
+use alloy::{
+    eips::BlockId,
+    json_abi::JsonAbi,
+    primitives::{keccak256, Address, U256},
+    providers::{Provider, ProviderBuilder},
+    rpc::types::{
+        state::{AccountOverride, StateOverride},
+        TransactionRequest,
+    },
+};
+use eyre::{ensure, Result};
+
+/// The number of synthetic recipients of the larger sample of [`estimate_marginal_gas`].
+const MARGINAL_GAS_SAMPLE: usize = 10;
+
+/// Represents the estimated cost of a distribution.
+///
+/// # Fields
+///
+/// * `total_value` - The sum of all amounts to distribute.
+/// * `gas` - The estimated gas of the distribution.
+/// * `max_fee` - The current EIP-1559 max fee per gas.
+/// * `total_cost` - The worst-case total spent by the sender, `total_value + gas * max_fee`.
+/// * `sender_balance` - The current Ether balance of the sender.
+/// * `sufficient` - Whether the sender balance covers `total_cost`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistributeEstimate {
+    pub total_value: U256,
+    pub gas: u64,
+    pub max_fee: u128,
+    pub total_cost: U256,
+    pub sender_balance: U256,
+    pub sufficient: bool,
+}
+
+/// Estimates the total cost of a distribution without broadcasting anything.
+///
+/// The sender balance is overridden during gas estimation, so the estimate also succeeds
+/// when the sender cannot afford the distribution.
+///
+/// # Arguments
+///
+/// * `sender` - The address of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `params` - The params of the distribution.
+///
+/// # Returns
+///
+/// * `Result<DistributeEstimate>` - The estimated cost on success.
+pub async fn estimate(
+    sender: Address,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: &JsonAbi,
+    contract_address: Address,
+    params: &[DistributeParam],
+) -> Result<DistributeEstimate> {
+    let config: ProviderConfig = rpc_http.into();
+    let tx = distribute_request(sender, abi, contract_address, params)?;
+    let total_value = checked_total(params)?;
+
+    let gas = estimate_funded_gas(config.clone(), sender, &tx).await?;
+
+    let provider = ProviderBuilder::new().on_client(config.client()?);
+
+    let max_fee = provider.estimate_eip1559_fees(None).await?.max_fee_per_gas;
+    let sender_balance = provider.get_balance(sender).await?;
+
+    let total_cost = total_value + U256::from(gas) * U256::from(max_fee);
+
+    Ok(DistributeEstimate {
+        total_value,
+        gas,
+        max_fee,
+        total_cost,
+        sender_balance,
+        sufficient: sender_balance >= total_cost,
+    })
+}
+
+/// Represents the gas cost of a distribution as a linear function of its recipient count.
+///
+/// # Fields
+///
+/// * `base` - The fixed gas of a distribution transaction, independent of its recipients.
+/// * `per_recipient` - The gas added by each recipient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MarginalGas {
+    pub base: u64,
+    pub per_recipient: u64,
+}
+
+impl MarginalGas {
+    /// Returns the estimated gas of a distribution to `recipients` receivers.
+    pub fn gas_for(&self, recipients: usize) -> u64 {
+        self.base
+            .saturating_add(self.per_recipient.saturating_mul(recipients as u64))
+    }
+
+    /// Returns how many recipients fit in a distribution using at most `gas_budget`.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The chunk size, at least 1 and at most [`DEFAULT_MAX_RECIPIENTS`].
+    pub fn chunk_size_within(&self, gas_budget: u64) -> usize {
+        let available = gas_budget.saturating_sub(self.base);
+
+        ((available / self.per_recipient.max(1)) as usize).clamp(1, DEFAULT_MAX_RECIPIENTS)
+    }
+
+    /// Fits the linear cost through the gas of a single-recipient and a `count`-recipient call.
+    fn fit(single: u64, multiple: u64, count: usize) -> Self {
+        let per_recipient = multiple.saturating_sub(single) / (count as u64 - 1);
+
+        Self {
+            base: single.saturating_sub(per_recipient),
+            per_recipient,
+        }
+    }
+}
+
+/// Estimates the base and per-recipient gas of the distributor contract.
+///
+/// The gas of a distribution to 1 and to 10 synthetic recipients of 1 wei each is estimated
+/// and fitted linearly. The recipients are fresh accounts, so `per_recipient` includes the
+/// cost of creating them, which is the worst case. As with [`estimate`], the sender balance
+/// is overridden, so `sample_sender` does not need to hold any Ether.
+///
+/// # Arguments
+///
+/// * `sample_sender` - The address the estimates are made from.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+///
+/// # Returns
+///
+/// * `Result<MarginalGas>` - The fitted gas cost on success.
+pub async fn estimate_marginal_gas(
+    sample_sender: Address,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: &JsonAbi,
+    contract_address: Address,
+) -> Result<MarginalGas> {
+    let config: ProviderConfig = rpc_http.into();
+    let params = synthetic_params(MARGINAL_GAS_SAMPLE);
+
+    let single = distribute_request(sample_sender, abi, contract_address, &params[..1])?;
+    let multiple = distribute_request(sample_sender, abi, contract_address, &params)?;
+
+    let single = estimate_funded_gas(config.clone(), sample_sender, &single).await?;
+    let multiple = estimate_funded_gas(config, sample_sender, &multiple).await?;
+
+    Ok(MarginalGas::fit(single, multiple, MARGINAL_GAS_SAMPLE))
+}
+
+/// Suggests how many recipients to put in each distribution transaction.
+///
+/// The chunk size is derived from [`estimate_marginal_gas`] so that a chunk uses at most
+/// `block_gas_limit_fraction` of the latest block gas limit.
+///
+/// # Arguments
+///
+/// * `sample_sender` - The address the estimates are made from.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `block_gas_limit_fraction` - The share of the block gas limit a chunk may use, in `(0, 1]`.
+///
+/// # Returns
+///
+/// * `Result<usize>` - The suggested chunk size, at least 1 and at most [`DEFAULT_MAX_RECIPIENTS`].
+pub async fn suggest_chunk_size(
+    sample_sender: Address,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: &JsonAbi,
+    contract_address: Address,
+    block_gas_limit_fraction: f64,
+) -> Result<usize> {
+    ensure!(
+        block_gas_limit_fraction > 0.0 && block_gas_limit_fraction <= 1.0,
+        "block_gas_limit_fraction must be in (0, 1], got {block_gas_limit_fraction}"
+    );
+
+    let config: ProviderConfig = rpc_http.into();
+    let marginal =
+        estimate_marginal_gas(sample_sender, config.clone(), abi, contract_address).await?;
+
+    let block = get_block(config, BlockId::latest()).await?;
+    let gas_budget = (block.gas_limit as f64 * block_gas_limit_fraction) as u64;
+
+    Ok(marginal.chunk_size_within(gas_budget))
+}
+
+/// Estimates the gas of a transaction with the sender balance overridden.
+async fn estimate_funded_gas(
+    config: ProviderConfig,
+    sender: Address,
+    tx: &TransactionRequest,
+) -> Result<u64> {
+    let provider = ProviderBuilder::new().on_client(config.client()?);
+    let overrides = StateOverride::from_iter([(
+        sender,
+        AccountOverride {
+            balance: Some(U256::MAX >> 1),
+            ..Default::default()
+        },
+    )]);
+
+    Ok(provider.estimate_gas(tx).overrides(&overrides).await?)
+}
+
+/// Builds params paying 1 wei to `count` distinct accounts that do not exist yet.
+fn synthetic_params(count: usize) -> Vec<DistributeParam> {
+    (0..count as u64)
+        .map(|index| DistributeParam {
+            receiver: Address::from_word(keccak256(index.to_be_bytes())),
+            amount: U256::from(1),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_marginal_gas_fit() {
+        let marginal = MarginalGas::fit(60_000, 375_000, 10);
+
+        assert_eq!(
+            marginal,
+            MarginalGas {
+                base: 25_000,
+                per_recipient: 35_000,
+            }
+        );
+        assert_eq!(marginal.gas_for(10), 375_000);
+    }
+
+    #[test]
+    fn test_marginal_gas_chunk_size() {
+        let marginal = MarginalGas {
+            base: 25_000,
+            per_recipient: 35_000,
+        };
+
+        assert_eq!(marginal.chunk_size_within(15_000_000), 427);
+        assert_eq!(marginal.chunk_size_within(10_000), 1);
+        assert_eq!(
+            marginal.chunk_size_within(1_000_000_000),
+            DEFAULT_MAX_RECIPIENTS
+        );
+    }
+
+    #[test]
+    fn test_synthetic_params() {
+        let params = synthetic_params(MARGINAL_GAS_SAMPLE);
+        let mut receivers: Vec<Address> = params.iter().map(|param| param.receiver).collect();
+        receivers.dedup();
+
+        assert_eq!(receivers.len(), MARGINAL_GAS_SAMPLE);
+        assert!(params.iter().all(|param| param.amount == U256::from(1)));
+    }
+}
@@ -0,0 +1,122 @@
+use crate::distributor::DistributeParam;
+use crate::executor::ProviderConfig;
+use alloy::{
+    contract::Interface,
+    dyn_abi::DynSolValue,
+    json_abi::JsonAbi,
+    network::TransactionBuilder,
+    primitives::{Address, U256},
+    providers::{Provider, ProviderBuilder},
+    rpc::types::{
+        state::{AccountOverride, StateOverride},
+        TransactionRequest,
+    },
+};
+use eyre::{ensure, Result};
+
+/// Calculates how much Ether one account needs to pay for its mints.
+///
+/// The gas of one mint is estimated from `from` (with its balance overridden, so it may be
+/// unfunded), priced at the current EIP-1559 max fee plus `buffer_percent`, and added to the
+/// mint `value`, all multiplied by `tx_count`.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `from` - A representative account to estimate the mint from.
+/// * `abi` - The JSON ABI of the mint contract.
+/// * `mint_contract` - The address of the mint contract.
+/// * `function_name` - The name of the mint function.
+/// * `args` - The arguments to pass to the function.
+/// * `value` - The amount of Ether sent with every mint.
+/// * `buffer_percent` - The safety margin added to the gas cost, e.g. `30` for 30%.
+/// * `tx_count` - The number of mints each account sends.
+///
+/// # Returns
+///
+/// * `Result<U256>` - The amount of Ether each account needs on success.
+#[allow(clippy::too_many_arguments)]
+pub async fn calc_funding(
+    rpc_http: impl Into<ProviderConfig>,
+    from: Address,
+    abi: &JsonAbi,
+    mint_contract: Address,
+    function_name: &str,
+    args: &[DynSolValue],
+    value: U256,
+    buffer_percent: u32,
+    tx_count: u32,
+) -> Result<U256> {
+    let provider = ProviderBuilder::new().on_client(rpc_http.into().client()?);
+
+    let input = Interface::new(abi.clone()).encode_input(function_name, args)?;
+    let tx = TransactionRequest::default()
+        .with_from(from)
+        .with_to(mint_contract)
+        .with_input(input)
+        .with_value(value);
+
+    let overrides = StateOverride::from_iter([(
+        from,
+        AccountOverride {
+            balance: Some(U256::MAX >> 1),
+            ..Default::default()
+        },
+    )]);
+    let gas = provider.estimate_gas(&tx).overrides(&overrides).await?;
+    let max_fee = provider.estimate_eip1559_fees(None).await?.max_fee_per_gas;
+
+    let gas_cost =
+        U256::from(gas) * U256::from(max_fee) * U256::from(100 + buffer_percent) / U256::from(100);
+
+    Ok((gas_cost + value) * U256::from(tx_count))
+}
+
+/// Calculates the funding of every receiver, see [`calc_funding`].
+///
+/// The first receiver is used as the representative account.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the mint contract.
+/// * `mint_contract` - The address of the mint contract.
+/// * `function_name` - The name of the mint function.
+/// * `args` - The arguments to pass to the function.
+/// * `value` - The amount of Ether sent with every mint.
+/// * `buffer_percent` - The safety margin added to the gas cost, e.g. `30` for 30%.
+/// * `tx_count` - The number of mints each account sends.
+/// * `receivers` - The accounts to fund.
+///
+/// # Returns
+///
+/// * `Result<Vec<DistributeParam>>` - One param per receiver, ready to distribute.
+#[allow(clippy::too_many_arguments)]
+pub async fn calc_funding_params(
+    rpc_http: impl Into<ProviderConfig>,
+    abi: &JsonAbi,
+    mint_contract: Address,
+    function_name: &str,
+    args: &[DynSolValue],
+    value: U256,
+    buffer_percent: u32,
+    tx_count: u32,
+    receivers: &[Address],
+) -> Result<Vec<DistributeParam>> {
+    ensure!(!receivers.is_empty(), "no receivers to fund");
+
+    let amount = calc_funding(
+        rpc_http,
+        receivers[0],
+        abi,
+        mint_contract,
+        function_name,
+        args,
+        value,
+        buffer_percent,
+        tx_count,
+    )
+    .await?;
+
+    DistributeParam::equal(receivers, amount)
+}
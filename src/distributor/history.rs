@@ -0,0 +1,87 @@
+use crate::executor::{query_events, ProviderConfig};
+use alloy::{
+    dyn_abi::DynSolValue,
+    json_abi::JsonAbi,
+    primitives::{Address, TxHash, U256},
+};
+use eyre::{eyre, Result};
+
+/// A past distribution decoded from a `Distribute` event.
+///
+/// # Fields
+///
+/// * `tx_hash` - The transaction hash of the distribution.
+/// * `sender` - The address that funded the distribution.
+/// * `recipients` - The addresses that received Ether.
+/// * `amounts` - The amounts received, in the same order as `recipients`.
+/// * `block_number` - The number of the block the distribution was included in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistributionEvent {
+    pub tx_hash: TxHash,
+    pub sender: Address,
+    pub recipients: Vec<Address>,
+    pub amounts: Vec<U256>,
+    pub block_number: u64,
+}
+
+/// Queries the `Distribute` events emitted by a distributor contract.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `from_block` - The first block to query.
+/// * `to_block` - The last block to query (optional, defaults to the latest block).
+///
+/// # Returns
+///
+/// * `Result<Vec<DistributionEvent>>` - The decoded distributions, in chain order.
+pub async fn query_distribution_events(
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    from_block: u64,
+    to_block: Option<u64>,
+) -> Result<Vec<DistributionEvent>> {
+    let events = query_events(
+        rpc_http,
+        &abi,
+        contract_address,
+        "Distribute",
+        from_block,
+        to_block,
+    )
+    .await?;
+
+    events
+        .into_iter()
+        .map(|event| {
+            let sender = match event.indexed.first() {
+                Some(DynSolValue::Address(sender)) => *sender,
+                _ => return Err(eyre!("missing `sender` in `Distribute` event")),
+            };
+            let (recipients, amounts) = match event.body.as_slice() {
+                [DynSolValue::Array(recipients), DynSolValue::Array(amounts)] => (
+                    recipients
+                        .iter()
+                        .filter_map(DynSolValue::as_address)
+                        .collect(),
+                    amounts
+                        .iter()
+                        .filter_map(|amount| amount.as_uint().map(|(amount, _)| amount))
+                        .collect(),
+                ),
+                _ => return Err(eyre!("malformed `Distribute` event body")),
+            };
+
+            Ok(DistributionEvent {
+                tx_hash: event.tx_hash,
+                sender,
+                recipients,
+                amounts,
+                block_number: event.block_number,
+            })
+        })
+        .collect()
+}
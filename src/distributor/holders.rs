@@ -0,0 +1,121 @@
+use crate::distributor::{distribute, DistributeParam};
+use crate::executor::{call, query_events, ProviderConfig};
+use alloy::{
+    dyn_abi::DynSolValue,
+    json_abi::JsonAbi,
+    primitives::{Address, TxHash, U256},
+    signers::local::PrivateKeySigner,
+};
+use eyre::{ensure, Result};
+use futures::future::try_join_all;
+use std::collections::BTreeSet;
+
+/// Distributes the same amount of Ether to every current holder of an ERC-20 token.
+///
+/// Holders are the receivers of the token's `Transfer` events in the block range that still
+/// hold a non-zero balance. The zero address and the sender itself are never funded.
+///
+/// # Arguments
+///
+/// * `sender` - The private key signer of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `distributor_abi` - The JSON ABI of the distributor contract.
+/// * `distributor_address` - The address of the distributor contract.
+/// * `token_abi` - The JSON ABI of the ERC-20 token.
+/// * `token_address` - The address of the ERC-20 token.
+/// * `amount_each` - The amount each holder gets.
+/// * `from_block` - The first block to scan for `Transfer` events.
+/// * `to_block` - The last block to scan (optional, defaults to the latest block).
+///
+/// # Returns
+///
+/// * `Result<TxHash>` - The transaction hash of the distribution on success.
+#[allow(clippy::too_many_arguments)]
+pub async fn distribute_to_token_holders(
+    sender: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    distributor_abi: JsonAbi,
+    distributor_address: Address,
+    token_abi: JsonAbi,
+    token_address: Address,
+    amount_each: U256,
+    from_block: u64,
+    to_block: Option<u64>,
+) -> Result<TxHash> {
+    let config: ProviderConfig = rpc_http.into();
+    let holders = token_holders(
+        config.clone(),
+        &token_abi,
+        token_address,
+        from_block,
+        to_block,
+    )
+    .await?;
+
+    let receivers: Vec<Address> = holders
+        .into_iter()
+        .filter(|holder| *holder != sender.address())
+        .collect();
+    ensure!(!receivers.is_empty(), "no token holders to distribute to");
+
+    let params = DistributeParam::equal(&receivers, amount_each)?;
+
+    distribute(sender, config, distributor_abi, distributor_address, params).await
+}
+
+/// Builds the set of current holders of an ERC-20 token from its `Transfer` events.
+///
+/// # Arguments
+///
+/// * `config` - The connection to the Ethereum RPC endpoint.
+/// * `token_abi` - The JSON ABI of the ERC-20 token.
+/// * `token_address` - The address of the ERC-20 token.
+/// * `from_block` - The first block to scan.
+/// * `to_block` - The last block to scan (optional, defaults to the latest block).
+///
+/// # Returns
+///
+/// * `Result<Vec<Address>>` - The holders with a non-zero balance, sorted by address.
+async fn token_holders(
+    config: ProviderConfig,
+    token_abi: &JsonAbi,
+    token_address: Address,
+    from_block: u64,
+    to_block: Option<u64>,
+) -> Result<Vec<Address>> {
+    let transfers = query_events(
+        config.clone(),
+        token_abi,
+        token_address,
+        "Transfer",
+        from_block,
+        to_block,
+    )
+    .await?;
+
+    // `Transfer(address indexed from, address indexed to, uint256 value)`
+    let candidates: BTreeSet<Address> = transfers
+        .iter()
+        .filter_map(|transfer| transfer.indexed.get(1).and_then(DynSolValue::as_address))
+        .filter(|holder| !holder.is_zero())
+        .collect();
+
+    let balances = try_join_all(candidates.iter().map(|holder| {
+        let (config, token_abi) = (config.clone(), token_abi.clone());
+        let args = [DynSolValue::from(*holder)];
+        async move { call(config, token_abi, token_address, "balanceOf", &args).await }
+    }))
+    .await?;
+
+    Ok(candidates
+        .into_iter()
+        .zip(balances)
+        .filter(|(_, balance)| {
+            balance
+                .first()
+                .and_then(DynSolValue::as_uint)
+                .is_some_and(|(balance, _)| !balance.is_zero())
+        })
+        .map(|(holder, _)| holder)
+        .collect())
+}
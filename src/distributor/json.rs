@@ -0,0 +1,226 @@
+use crate::distributor::DistributeParam;
+use eyre::{eyre, Result, WrapErr};
+use serde::Deserialize;
+use std::{borrow::Cow, fs};
+
+/// Reads distribution params from a JSON array of `{"receiver", "amount"}` entries.
+///
+/// Amounts may be given as a decimal string, a `0x`-prefixed hex string or a number.
+///
+/// # Arguments
+///
+/// * `path_or_str` - Either the JSON content itself (starting with `[`) or the path of a JSON file.
+///
+/// # Returns
+///
+/// * `Result<Vec<DistributeParam>>` - The params on success, or an error naming the invalid entry.
+pub fn params_from_json(path_or_str: &str) -> Result<Vec<DistributeParam>> {
+    let content = if path_or_str.trim_start().starts_with('[') {
+        Cow::Borrowed(path_or_str)
+    } else {
+        Cow::Owned(
+            fs::read_to_string(path_or_str)
+                .wrap_err_with(|| format!("failed to read {path_or_str}"))?,
+        )
+    };
+
+    let entries: Vec<serde_json::Value> =
+        serde_json::from_str(&content).wrap_err("expected a JSON array of distribution params")?;
+
+    entries
+        .into_iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            DistributeParam::deserialize(entry).map_err(|error| eyre!("entry {index}: {error}"))
+        })
+        .collect()
+}
+
+/// Writes distribution params as a JSON array readable by [`params_from_json`].
+///
+/// # Arguments
+///
+/// * `params` - The params to write.
+///
+/// # Returns
+///
+/// * `Result<String>` - The pretty-printed JSON on success.
+pub fn params_to_json(params: &[DistributeParam]) -> Result<String> {
+    Ok(serde_json::to_string_pretty(params)?)
+}
+
+/// (De)serializes amounts as decimal strings, accepting hex strings and numbers as well.
+pub(crate) mod amount {
+    use alloy::primitives::U256;
+    use serde::{de, Deserializer, Serializer};
+    use std::fmt;
+
+    pub(crate) fn serialize<S: Serializer>(
+        amount: &U256,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(amount)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<U256, D::Error> {
+        deserializer.deserialize_any(AmountVisitor)
+    }
+
+    struct AmountVisitor;
+
+    impl de::Visitor<'_> for AmountVisitor {
+        type Value = U256;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a decimal string, a hex string or a non-negative integer")
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<U256, E> {
+            Ok(U256::from(value))
+        }
+
+        fn visit_u128<E: de::Error>(self, value: u128) -> Result<U256, E> {
+            Ok(U256::from(value))
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<U256, E> {
+            let parsed = match value.strip_prefix("0x") {
+                Some(hex) => U256::from_str_radix(hex, 16),
+                None => U256::from_str_radix(value, 10),
+            };
+
+            parsed.map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+        }
+    }
+}
+
+/// (De)serializes integers as decimal strings, to avoid precision loss in JSON and TOML.
+pub(crate) mod decimal {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use std::{fmt::Display, str::FromStr};
+
+    pub(crate) fn serialize<T: Display, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distributor::DistributeResult;
+    use alloy::primitives::{Address, TxHash, U256};
+    use serde::Serialize;
+    use std::str::FromStr;
+
+    const ALICE: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+
+    #[test]
+    fn test_parse_amount_encodings() {
+        let content = format!(
+            r#"[
+                {{"receiver": "{ALICE}", "amount": "1000000000000000"}},
+                {{"receiver": "{ALICE}", "amount": "0x38d7ea4c68000"}},
+                {{"receiver": "{ALICE}", "amount": 1000000000000000}}
+            ]"#
+        );
+        let params = params_from_json(&content).unwrap();
+
+        assert_eq!(params.len(), 3);
+        for param in &params {
+            assert_eq!(param.receiver, Address::from_str(ALICE).unwrap());
+            assert_eq!(param.amount, U256::from(1_000_000_000_000_000u64));
+        }
+    }
+
+    #[test]
+    fn test_parse_malformed_address_names_entry() {
+        let content = format!(
+            r#"[
+                {{"receiver": "{ALICE}", "amount": "1"}},
+                {{"receiver": "0x1234", "amount": "1"}}
+            ]"#
+        );
+        let error = params_from_json(&content).unwrap_err();
+
+        assert!(error.to_string().starts_with("entry 1:"));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let params = vec![
+            DistributeParam {
+                receiver: Address::repeat_byte(1),
+                amount: U256::MAX,
+            },
+            DistributeParam {
+                receiver: Address::repeat_byte(2),
+                amount: U256::from(7),
+            },
+        ];
+
+        let json = params_to_json(&params).unwrap();
+        let decoded = params_from_json(&json).unwrap();
+
+        assert_eq!(decoded.len(), params.len());
+        for (decoded, param) in decoded.iter().zip(&params) {
+            assert_eq!(decoded.receiver, param.receiver);
+            assert_eq!(decoded.amount, param.amount);
+        }
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Plan {
+        params: Vec<DistributeParam>,
+        results: Vec<DistributeResult>,
+    }
+
+    fn plan() -> Plan {
+        Plan {
+            params: vec![DistributeParam {
+                receiver: Address::repeat_byte(1),
+                amount: U256::MAX,
+            }],
+            results: vec![DistributeResult {
+                tx_hash: TxHash::repeat_byte(2),
+                gas_used: 21_000,
+                effective_gas_price: u128::MAX,
+                block_number: 7,
+                status: true,
+                total_distributed: U256::MAX,
+                recipient_count: 1,
+                contract_recipients: Vec::new(),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_serde_json_round_trip() {
+        let json = serde_json::to_string(&plan()).unwrap();
+        assert!(json.contains(&format!("\"{}\"", U256::MAX)));
+
+        assert_eq!(serde_json::from_str::<Plan>(&json).unwrap(), plan());
+    }
+
+    #[test]
+    fn test_toml_round_trip() {
+        let toml = toml::to_string(&plan()).unwrap();
+
+        assert_eq!(toml::from_str::<Plan>(&toml).unwrap(), plan());
+    }
+}
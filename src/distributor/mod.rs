@@ -1,2 +1,92 @@
+use std::time::Duration;
+
+/// Delay between two polls for the receipt of a distribution transaction.
+pub(crate) const RECEIPT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Time after which a distribution transaction that is still pending is given up on.
+pub(crate) const RECEIPT_TIMEOUT: Duration = Duration::from_secs(300);
+
 mod distribute;
-pub use distribute::{distribute, DistributeParam};
+pub use distribute::{
+    distribute, distribute_equal, distribute_with_memo, distribute_with_options,
+    distribute_with_receipt, encode, encode_with, ContractRecipientPolicy, DistributeOptions,
+    DistributeParam, DistributeParamWithMemo, DistributeResult, DistributeSortOrder,
+    DistributorKind, EncodedDistribution,
+};
+
+mod chunked;
+pub use chunked::{
+    distribute_chunked, distribute_chunked_with_options, distribute_chunked_with_progress,
+    distribute_safe, estimate_chunk_size, estimate_distribute_gas, DistributeChunkError,
+    DistributeProgress,
+};
+
+mod token;
+pub use token::{distribute_token, distribute_token_with_approval, ApprovalPolicy};
+
+mod erc721;
+pub use erc721::{distribute_erc721, Erc721Transfer};
+
+mod sweep;
+pub use sweep::{
+    drain_accounts, sweep, sweep_token, DrainResult, InsufficientGasError, SweepResult,
+};
+
+mod history;
+pub use history::{query_distribution_events, DistributionEvent};
+
+mod top_up;
+pub use top_up::top_up;
+
+mod direct;
+pub use direct::{
+    distribute_direct, distribute_direct_with_progress, DirectDistribution, DirectTransfer,
+};
+
+mod csv;
+pub use csv::{
+    batch_distribute_from_csv, export_receipts_csv, params_from_csv, params_from_csv_str,
+    AmountUnit,
+};
+
+#[cfg(feature = "serde")]
+mod json;
+#[cfg(feature = "serde")]
+pub use json::{params_from_json, params_to_json};
+
+mod normalize;
+pub use normalize::{has_duplicate_receivers, normalize, NormalizedParams};
+
+mod validate;
+pub use validate::{
+    validate_all, validate_params, InvalidParamsError, ValidationRules, Violation,
+    ZeroAmountPolicy, DEFAULT_MAX_RECIPIENTS,
+};
+
+mod estimate;
+pub use estimate::{
+    estimate, estimate_marginal_gas, suggest_chunk_size, DistributeEstimate, MarginalGas,
+};
+
+mod verify;
+pub use verify::{verify, VerifyFailure, VerifyMode, VerifyReport};
+
+mod multi;
+pub use multi::{distribute_multi, MultiDistribution, SenderDistribution, SplitStrategy};
+
+mod holders;
+pub use holders::distribute_to_token_holders;
+
+mod funding;
+pub use funding::{calc_funding, calc_funding_params};
+
+mod multisend;
+pub use multisend::{encode_multisend, multisend, MultiCall, MultisendResult};
+
+mod resume;
+pub use resume::{resume, DistributionChunk, DistributionRun, DistributionRunError, ResumeOptions};
+
+#[cfg(feature = "embedded-contracts")]
+mod embedded;
+#[cfg(feature = "embedded-contracts")]
+pub use embedded::{deploy, distributor_artifact, DistributorArtifact};
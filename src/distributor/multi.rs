@@ -0,0 +1,212 @@
+use crate::distributor::{distribute_safe, DistributeParam, DistributeResult};
+use crate::executor::{get_block, ProviderConfig};
+use alloy::{
+    eips::BlockId,
+    json_abi::JsonAbi,
+    primitives::{Address, U256},
+    providers::{Provider, ProviderBuilder},
+    signers::local::PrivateKeySigner,
+};
+use eyre::{ensure, Report, Result};
+use futures::future::{join_all, try_join_all};
+use std::future::IntoFuture;
+
+/// How receivers are partitioned across funding wallets.
+///
+/// # Variants
+///
+/// * `RoundRobin` - Receivers are dealt to the senders in turn.
+/// * `BalanceProportional` - Each receiver goes to the sender with the most balance left after
+///   its previous assignments, so each sender funds a share proportional to its balance.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SplitStrategy {
+    #[default]
+    RoundRobin,
+    BalanceProportional,
+}
+
+/// Represents the distribution of one funding wallet.
+///
+/// # Fields
+///
+/// * `sender` - The address of the sender.
+/// * `receivers` - The receivers assigned to the sender.
+/// * `result` - The result of every transaction of the sender on success, or an error report on failure.
+#[derive(Debug)]
+pub struct SenderDistribution {
+    pub sender: Address,
+    pub receivers: Vec<Address>,
+    pub result: Result<Vec<DistributeResult>, Report>,
+}
+
+/// Represents a distribution from multiple funding wallets.
+///
+/// # Fields
+///
+/// * `senders` - The distribution of every sender, in the same order as the senders.
+#[derive(Debug)]
+pub struct MultiDistribution {
+    pub senders: Vec<SenderDistribution>,
+}
+
+impl MultiDistribution {
+    /// Returns the results of every successful transaction, across all senders.
+    pub fn results(&self) -> impl Iterator<Item = &DistributeResult> {
+        self.senders
+            .iter()
+            .filter_map(|sender| sender.result.as_ref().ok())
+            .flatten()
+    }
+
+    /// Returns the total amount distributed by the successful transactions.
+    pub fn total_distributed(&self) -> U256 {
+        self.results().map(|result| result.total_distributed).sum()
+    }
+
+    /// Returns the receivers whose sender failed.
+    pub fn failed_receivers(&self) -> Vec<Address> {
+        self.senders
+            .iter()
+            .filter(|sender| sender.result.is_err())
+            .flat_map(|sender| sender.receivers.iter().copied())
+            .collect()
+    }
+}
+
+/// Distributes Ether from multiple funding wallets in parallel.
+///
+/// The params are partitioned across `senders` according to `strategy`, and every partition
+/// is sent concurrently with [`distribute_safe`], in chunks within half of the block gas limit.
+/// A failing sender only fails its own partition.
+///
+/// # Arguments
+///
+/// * `senders` - The private key signers of the funding wallets.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `params` - A vector of `DistributeParam` containing receiver addresses and amounts.
+/// * `strategy` - How receivers are partitioned across senders.
+///
+/// # Returns
+///
+/// * `Result<MultiDistribution>` - The distribution of every sender.
+pub async fn distribute_multi(
+    senders: Vec<PrivateKeySigner>,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    params: Vec<DistributeParam>,
+    strategy: SplitStrategy,
+) -> Result<MultiDistribution> {
+    ensure!(!senders.is_empty(), "no senders to distribute from");
+
+    let config: ProviderConfig = rpc_http.into();
+    let provider = ProviderBuilder::new().on_client(config.client()?);
+
+    let partitions = match strategy {
+        SplitStrategy::RoundRobin => split_round_robin(params, senders.len()),
+        SplitStrategy::BalanceProportional => {
+            let balances = try_join_all(
+                senders
+                    .iter()
+                    .map(|sender| provider.get_balance(sender.address()).into_future()),
+            )
+            .await?;
+            split_proportional(params, &balances)
+        }
+    };
+
+    let block = get_block(config.clone(), BlockId::latest()).await?;
+    let gas_threshold = block.gas_limit / 2;
+
+    let distributions = join_all(senders.into_iter().zip(partitions).map(|(sender, params)| {
+        let (config, abi) = (config.clone(), abi.clone());
+        async move {
+            let address = sender.address();
+            let receivers: Vec<Address> = params.iter().map(|param| param.receiver).collect();
+
+            let result = if params.is_empty() {
+                Ok(Vec::new())
+            } else {
+                distribute_safe(sender, config, abi, contract_address, params, gas_threshold).await
+            };
+
+            SenderDistribution {
+                sender: address,
+                receivers,
+                result,
+            }
+        }
+    }))
+    .await;
+
+    Ok(MultiDistribution {
+        senders: distributions,
+    })
+}
+
+/// Deals params to `count` partitions in turn.
+fn split_round_robin(params: Vec<DistributeParam>, count: usize) -> Vec<Vec<DistributeParam>> {
+    let mut partitions: Vec<Vec<DistributeParam>> = (0..count).map(|_| Vec::new()).collect();
+    for (index, param) in params.into_iter().enumerate() {
+        partitions[index % count].push(param);
+    }
+
+    partitions
+}
+
+/// Assigns every param to the partition with the most balance left.
+fn split_proportional(
+    params: Vec<DistributeParam>,
+    balances: &[U256],
+) -> Vec<Vec<DistributeParam>> {
+    let mut partitions: Vec<Vec<DistributeParam>> = balances.iter().map(|_| Vec::new()).collect();
+    let mut remaining: Vec<U256> = balances.to_vec();
+
+    for param in params {
+        let (index, _) = remaining
+            .iter()
+            .enumerate()
+            .max_by_key(|(index, balance)| (**balance, std::cmp::Reverse(*index)))
+            .expect("at least one sender");
+        remaining[index] = remaining[index].saturating_sub(param.amount);
+        partitions[index].push(param);
+    }
+
+    partitions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(amounts: &[u64]) -> Vec<DistributeParam> {
+        amounts
+            .iter()
+            .enumerate()
+            .map(|(i, amount)| DistributeParam {
+                receiver: Address::repeat_byte(i as u8 + 1),
+                amount: U256::from(*amount),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_split_round_robin() {
+        let partitions = split_round_robin(params(&[1, 1, 1, 1, 1]), 2);
+
+        assert_eq!(partitions[0].len(), 3);
+        assert_eq!(partitions[1].len(), 2);
+        assert_eq!(partitions[1][0].receiver, Address::repeat_byte(2));
+    }
+
+    #[test]
+    fn test_split_proportional() {
+        let balances = [U256::from(30), U256::from(10)];
+        let partitions = split_proportional(params(&[10, 10, 10, 10]), &balances);
+
+        assert_eq!(partitions[0].len(), 3);
+        assert_eq!(partitions[1].len(), 1);
+    }
+}
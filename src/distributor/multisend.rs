@@ -0,0 +1,163 @@
+use crate::executor::{execute, ProviderConfig};
+use alloy::{
+    dyn_abi::DynSolValue,
+    json_abi::JsonAbi,
+    primitives::{Address, Bytes, TxHash, U256},
+    providers::{Provider, ProviderBuilder},
+    signers::local::PrivateKeySigner,
+};
+use eyre::{ensure, eyre, Result};
+
+/// Human-readable ABI of the MultiSendCallOnly entry point.
+const MULTISEND_ABI: [&str; 1] = ["function multiSend(bytes transactions) payable"];
+
+/// One call of a [`multisend`] batch.
+///
+/// # Fields
+///
+/// * `target` - The address to call.
+/// * `value` - The amount of Ether to send with the call.
+/// * `calldata` - The encoded function call, e.g. from [`build_calldata`](crate::executor::build_calldata).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiCall {
+    pub target: Address,
+    pub value: U256,
+    pub calldata: Bytes,
+}
+
+/// Represents the result of a multisend batch.
+///
+/// MultiSendCallOnly reverts the whole batch as soon as one call fails, so the calls
+/// either all took effect (`status` is `true`) or none did.
+///
+/// # Fields
+///
+/// * `tx_hash` - The transaction hash of the batch.
+/// * `gas_used` - The gas used by the transaction.
+/// * `status` - Whether the transaction, and so every call, succeeded.
+/// * `call_count` - The number of calls in the batch.
+/// * `total_value` - The sum of the values of the calls, sent as the value of the transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultisendResult {
+    pub tx_hash: TxHash,
+    pub gas_used: u64,
+    pub status: bool,
+    pub call_count: usize,
+    pub total_value: U256,
+}
+
+/// Sends several calls, each with its own target, value and calldata, in one transaction.
+///
+/// The calls are encoded for a contract compatible with Safe's `MultiSendCallOnly`, which
+/// runs them in order with itself as `msg.sender`. Since the gas of the batch is estimated
+/// before sending, a batch with a failing call is usually rejected with an error without
+/// anything being sent.
+///
+/// # Arguments
+///
+/// * `sender` - The private key signer of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `multisend_contract` - The address of the MultiSendCallOnly contract.
+/// * `calls` - The calls to batch, in execution order.
+///
+/// # Returns
+///
+/// * `Result<MultisendResult>` - The receipt details of the batch on success.
+pub async fn multisend(
+    sender: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    multisend_contract: Address,
+    calls: Vec<MultiCall>,
+) -> Result<MultisendResult> {
+    ensure!(!calls.is_empty(), "no calls to send");
+    let config: ProviderConfig = rpc_http.into();
+
+    let total_value = calls.iter().try_fold(U256::ZERO, |total, call| {
+        total
+            .checked_add(call.value)
+            .ok_or_else(|| eyre!("total value overflows"))
+    })?;
+
+    let abi = JsonAbi::parse(MULTISEND_ABI)?;
+    let transactions = encode_multisend(&calls);
+    let tx_hash = execute(
+        sender,
+        config.clone(),
+        abi,
+        multisend_contract,
+        "multiSend",
+        &[DynSolValue::Bytes(transactions.to_vec())],
+        Some(total_value),
+    )
+    .await?
+    .tx_hash;
+
+    let provider = ProviderBuilder::new().on_client(config.client()?);
+    let receipt = provider
+        .get_transaction_receipt(tx_hash)
+        .await?
+        .ok_or_else(|| eyre!("receipt not found for transaction {tx_hash}"))?;
+
+    Ok(MultisendResult {
+        tx_hash,
+        gas_used: receipt.gas_used,
+        status: receipt.status(),
+        call_count: calls.len(),
+        total_value,
+    })
+}
+
+/// Packs calls into the `transactions` argument of `multiSend`.
+///
+/// Every call is encoded as `operation (uint8, 0 for a call) | to (address) |
+/// value (uint256) | data length (uint256) | data`, without padding.
+///
+/// # Arguments
+///
+/// * `calls` - The calls to pack.
+///
+/// # Returns
+///
+/// * `Bytes` - The packed calls.
+pub fn encode_multisend(calls: &[MultiCall]) -> Bytes {
+    let mut encoded = Vec::new();
+    for call in calls {
+        encoded.push(0u8);
+        encoded.extend_from_slice(call.target.as_slice());
+        encoded.extend_from_slice(&call.value.to_be_bytes::<32>());
+        encoded.extend_from_slice(&U256::from(call.calldata.len()).to_be_bytes::<32>());
+        encoded.extend_from_slice(&call.calldata);
+    }
+
+    encoded.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_multisend() {
+        let calls = [
+            MultiCall {
+                target: Address::repeat_byte(1),
+                value: U256::from(2),
+                calldata: Bytes::from_static(&[0xab, 0xcd]),
+            },
+            MultiCall {
+                target: Address::repeat_byte(3),
+                value: U256::ZERO,
+                calldata: Bytes::new(),
+            },
+        ];
+        let encoded = encode_multisend(&calls);
+
+        assert_eq!(encoded.len(), (85 + 2) + 85);
+        assert_eq!(encoded[0], 0);
+        assert_eq!(&encoded[1..21], Address::repeat_byte(1).as_slice());
+        assert_eq!(U256::from_be_slice(&encoded[21..53]), U256::from(2));
+        assert_eq!(U256::from_be_slice(&encoded[53..85]), U256::from(2));
+        assert_eq!(&encoded[85..87], &[0xab, 0xcd]);
+        assert_eq!(&encoded[88..108], Address::repeat_byte(3).as_slice());
+    }
+}
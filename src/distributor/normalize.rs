@@ -0,0 +1,167 @@
+use crate::distributor::DistributeParam;
+use alloy::primitives::Address;
+use eyre::{eyre, Result};
+use std::collections::HashMap;
+
+/// Represents distribution params after duplicates were merged and empty entries dropped.
+///
+/// # Fields
+///
+/// * `params` - The normalized params, in the order each receiver first appeared.
+/// * `merged` - The receivers that appeared more than once, with the number of entries merged.
+/// * `dropped` - The receivers dropped because their total amount is zero.
+#[derive(Debug)]
+pub struct NormalizedParams {
+    pub params: Vec<DistributeParam>,
+    pub merged: Vec<(Address, usize)>,
+    pub dropped: Vec<Address>,
+}
+
+/// Merges duplicate receivers and drops zero-amount entries.
+///
+/// Amounts of duplicate receivers are summed.
+///
+/// # Arguments
+///
+/// * `params` - The params to normalize.
+///
+/// # Returns
+///
+/// * `Result<NormalizedParams>` - The normalized params, or an error if a sum overflows.
+pub fn normalize(params: Vec<DistributeParam>) -> Result<NormalizedParams> {
+    let mut merged_params: Vec<DistributeParam> = Vec::with_capacity(params.len());
+    let mut entries: HashMap<Address, (usize, usize)> = HashMap::new();
+
+    for param in params {
+        match entries.get_mut(&param.receiver) {
+            Some((index, count)) => {
+                let existing = &mut merged_params[*index];
+                existing.amount = existing
+                    .amount
+                    .checked_add(param.amount)
+                    .ok_or_else(|| eyre!("amount overflow merging receiver {}", param.receiver))?;
+                *count += 1;
+            }
+            None => {
+                entries.insert(param.receiver, (merged_params.len(), 1));
+                merged_params.push(param);
+            }
+        }
+    }
+
+    let merged = merged_params
+        .iter()
+        .filter_map(|param| match entries[&param.receiver] {
+            (_, count) if count > 1 => Some((param.receiver, count)),
+            _ => None,
+        })
+        .collect();
+
+    let (params, dropped): (Vec<_>, Vec<_>) = merged_params
+        .into_iter()
+        .partition(|param| !param.amount.is_zero());
+
+    Ok(NormalizedParams {
+        params,
+        merged,
+        dropped: dropped.into_iter().map(|param| param.receiver).collect(),
+    })
+}
+
+/// Finds the receivers that appear more than once.
+///
+/// # Arguments
+///
+/// * `params` - The params to check.
+///
+/// # Returns
+///
+/// * `Option<Vec<Address>>` - The duplicated receivers, in the order they first appeared,
+///   or `None` if every receiver is unique.
+pub fn has_duplicate_receivers(params: &[DistributeParam]) -> Option<Vec<Address>> {
+    let mut counts: HashMap<Address, usize> = HashMap::with_capacity(params.len());
+    let mut duplicates = Vec::new();
+
+    for param in params {
+        let count = counts.entry(param.receiver).or_default();
+        *count += 1;
+        if *count == 2 {
+            duplicates.push(param.receiver);
+        }
+    }
+
+    (!duplicates.is_empty()).then_some(duplicates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::U256;
+
+    fn param(receiver: u8, amount: U256) -> DistributeParam {
+        DistributeParam {
+            receiver: Address::repeat_byte(receiver),
+            amount,
+        }
+    }
+
+    #[test]
+    fn test_merge_duplicates() {
+        let params = vec![
+            param(1, U256::from(1)),
+            param(2, U256::from(2)),
+            param(1, U256::from(3)),
+        ];
+        let normalized = normalize(params).unwrap();
+
+        assert_eq!(normalized.params.len(), 2);
+        assert_eq!(normalized.params[0].receiver, Address::repeat_byte(1));
+        assert_eq!(normalized.params[0].amount, U256::from(4));
+        assert_eq!(normalized.params[1].amount, U256::from(2));
+        assert_eq!(normalized.merged, [(Address::repeat_byte(1), 2)]);
+        assert!(normalized.dropped.is_empty());
+    }
+
+    #[test]
+    fn test_drop_zero_amounts() {
+        let params = vec![
+            param(1, U256::ZERO),
+            param(2, U256::from(2)),
+            param(1, U256::ZERO),
+        ];
+        let normalized = normalize(params).unwrap();
+
+        assert_eq!(normalized.params.len(), 1);
+        assert_eq!(normalized.params[0].receiver, Address::repeat_byte(2));
+        assert_eq!(normalized.dropped, [Address::repeat_byte(1)]);
+    }
+
+    #[test]
+    fn test_merge_overflow() {
+        let params = vec![param(1, U256::MAX), param(1, U256::from(1))];
+
+        assert!(normalize(params).is_err());
+    }
+
+    #[test]
+    fn test_has_duplicate_receivers() {
+        let params = vec![
+            param(1, U256::from(1)),
+            param(2, U256::from(2)),
+            param(3, U256::from(3)),
+        ];
+        assert_eq!(has_duplicate_receivers(&params), None);
+
+        let params = vec![
+            param(2, U256::from(1)),
+            param(1, U256::from(2)),
+            param(2, U256::from(3)),
+            param(1, U256::from(4)),
+            param(2, U256::from(5)),
+        ];
+        assert_eq!(
+            has_duplicate_receivers(&params),
+            Some(vec![Address::repeat_byte(2), Address::repeat_byte(1)])
+        );
+    }
+}
@@ -0,0 +1,442 @@
+use crate::distributor::{
+    distribute::prepare_distribution, DistributeOptions, DistributeParam, DistributeResult,
+    RECEIPT_POLL_INTERVAL, RECEIPT_TIMEOUT,
+};
+use crate::executor::{
+    submit_with_options, watch_pending_tx, ExecuteOptions, MaybeSent, ProviderConfig, TxStatus,
+};
+use alloy::{
+    json_abi::JsonAbi,
+    primitives::{Address, TxHash},
+    providers::{Provider, ProviderBuilder},
+    signers::local::PrivateKeySigner,
+};
+use eyre::{ensure, eyre, Report, Result};
+use futures::future::try_join_all;
+use std::{fmt, future::IntoFuture, time::Duration};
+
+/// One chunk of a [`DistributionRun`].
+///
+/// # Fields
+///
+/// * `params` - The receivers and amounts of the chunk that still had to be funded when it was sent.
+/// * `result` - The result of the transaction that funded the chunk, `None` while it is pending.
+/// * `tx_hash` - The hash of the transaction sent for the chunk, `None` until it is submitted.
+///   A lost receipt is awaited again through it instead of sending the chunk twice.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DistributionChunk {
+    pub params: Vec<DistributeParam>,
+    pub result: Option<DistributeResult>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tx_hash: Option<TxHash>,
+}
+
+impl DistributionChunk {
+    /// Returns `true` if the chunk was sent or has nothing left to fund.
+    pub fn is_done(&self) -> bool {
+        self.result.is_some() || self.params.is_empty()
+    }
+}
+
+/// The state of a chunked distribution, recording which chunks succeeded.
+///
+/// A run is created with [`DistributionRun::new`] and driven by [`resume`], which sends only
+/// the pending chunks. With the `serde` feature it can be persisted with
+/// [`to_json`](Self::to_json) and restored with [`from_json`](Self::from_json).
+///
+/// # Fields
+///
+/// * `chunks` - The chunks of the distribution, in sending order.
+/// * `already_funded` - The params dropped by the balance check of [`resume`] because the
+///   receiver already held the amount.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DistributionRun {
+    pub chunks: Vec<DistributionChunk>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub already_funded: Vec<DistributeParam>,
+}
+
+impl DistributionRun {
+    /// Creates a run with every chunk pending.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - A vector of `DistributeParam` containing receiver addresses and amounts.
+    /// * `chunk_size` - The maximum number of receivers per transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self>` - The run, or an error if `params` is empty or `chunk_size` is 0.
+    pub fn new(params: Vec<DistributeParam>, chunk_size: usize) -> Result<Self> {
+        ensure!(chunk_size > 0, "chunk_size must be greater than 0");
+        ensure!(!params.is_empty(), "no receivers to distribute to");
+
+        let chunks = params
+            .chunks(chunk_size)
+            .map(|chunk| DistributionChunk {
+                params: chunk.to_vec(),
+                result: None,
+                tx_hash: None,
+            })
+            .collect();
+
+        Ok(Self {
+            chunks,
+            already_funded: Vec::new(),
+        })
+    }
+
+    /// Returns `true` if every chunk is done.
+    pub fn is_complete(&self) -> bool {
+        self.chunks.iter().all(DistributionChunk::is_done)
+    }
+
+    /// Returns the params of the chunks that are still pending.
+    pub fn pending(&self) -> Vec<&DistributeParam> {
+        self.chunks
+            .iter()
+            .filter(|chunk| !chunk.is_done())
+            .flat_map(|chunk| &chunk.params)
+            .collect()
+    }
+
+    /// Returns the number of receivers funded by the chunks that succeeded.
+    pub fn funded(&self) -> usize {
+        self.chunks
+            .iter()
+            .filter_map(|chunk| chunk.result.as_ref())
+            .map(|result| result.recipient_count)
+            .sum()
+    }
+
+    /// Returns the results of the chunks that succeeded, in order.
+    pub fn results(&self) -> Vec<&DistributeResult> {
+        self.chunks
+            .iter()
+            .filter_map(|chunk| chunk.result.as_ref())
+            .collect()
+    }
+
+    /// Serializes the run to pretty-printed JSON.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Restores a run from JSON produced by [`to_json`](Self::to_json).
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+/// Options of [`resume`].
+///
+/// # Fields
+///
+/// * `retries` - The number of times a failed chunk is retried before the run stops.
+/// * `retry_delay` - The delay before the first retry, doubled after every further retry.
+/// * `check_balance` - Whether to drop receivers that already hold their amount before
+///   sending a chunk, e.g. when a run is rebuilt from a list that was partly funded.
+#[derive(Debug, Clone)]
+pub struct ResumeOptions {
+    pub retries: u32,
+    pub retry_delay: Duration,
+    pub check_balance: bool,
+}
+
+impl Default for ResumeOptions {
+    fn default() -> Self {
+        Self {
+            retries: 2,
+            retry_delay: Duration::from_secs(1),
+            check_balance: false,
+        }
+    }
+}
+
+/// Error returned by [`resume`] when a chunk still fails after its retries.
+///
+/// # Fields
+///
+/// * `run` - The state of the run, to be resumed once the cause is fixed.
+/// * `source` - The error of the last attempt.
+#[derive(Debug)]
+pub struct DistributionRunError {
+    pub run: DistributionRun,
+    pub source: Report,
+}
+
+impl fmt::Display for DistributionRunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "distribution stopped with {} recipients pending ({} funded): {}",
+            self.run.pending().len(),
+            self.run.funded(),
+            self.source
+        )
+    }
+}
+
+impl std::error::Error for DistributionRunError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+/// Sends the pending chunks of a distribution run.
+///
+/// Chunks are sent sequentially and chunks that already succeeded are skipped, so a run
+/// that stopped halfway can be resumed without funding anyone twice. A failing chunk is
+/// retried according to `options`; if it still fails, the run stops with a
+/// [`DistributionRunError`] carrying the updated run.
+///
+/// Once the transaction of a chunk is submitted, retries and later resumes await that
+/// transaction instead of sending another one; the chunk is only sent again if its
+/// transaction was dropped or reverted. A submission that failed after it may have reached
+/// the node is not retried.
+///
+/// # Arguments
+///
+/// * `run` - The run to resume, see [`DistributionRun::new`].
+/// * `sender` - The private key signer of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `options` - The retry and balance check settings.
+///
+/// # Returns
+///
+/// * `Result<DistributionRun>` - The completed run on success.
+pub async fn resume(
+    mut run: DistributionRun,
+    sender: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    options: ResumeOptions,
+) -> Result<DistributionRun> {
+    let config: ProviderConfig = rpc_http.into();
+
+    for index in 0..run.chunks.len() {
+        if run.chunks[index].is_done() {
+            continue;
+        }
+
+        // a chunk already submitted is awaited as it was sent
+        if options.check_balance && run.chunks[index].tx_hash.is_none() {
+            let params = std::mem::take(&mut run.chunks[index].params);
+            let (funded, pending) = match split_funded(config.clone(), params.clone()).await {
+                Ok(split) => split,
+                Err(source) => {
+                    run.chunks[index].params = params;
+                    return Err(DistributionRunError { run, source }.into());
+                }
+            };
+            run.chunks[index].params = pending;
+            run.already_funded.extend(funded);
+
+            if run.chunks[index].is_done() {
+                continue;
+            }
+        }
+
+        let result = send_chunk(
+            sender.clone(),
+            config.clone(),
+            abi.clone(),
+            contract_address,
+            &mut run.chunks[index],
+            &options,
+        )
+        .await;
+
+        match result {
+            Ok(result) => run.chunks[index].result = Some(result),
+            Err(source) => return Err(DistributionRunError { run, source }.into()),
+        }
+    }
+
+    Ok(run)
+}
+
+/// Sends one chunk, retrying with a doubling delay.
+async fn send_chunk(
+    sender: PrivateKeySigner,
+    config: ProviderConfig,
+    abi: JsonAbi,
+    contract_address: Address,
+    chunk: &mut DistributionChunk,
+    options: &ResumeOptions,
+) -> Result<DistributeResult> {
+    let mut attempt = 0;
+    let mut delay = options.retry_delay;
+
+    loop {
+        let result = try_chunk(
+            sender.clone(),
+            config.clone(),
+            abi.clone(),
+            contract_address,
+            chunk,
+        )
+        .await;
+
+        match result {
+            Ok(result) => return Ok(result),
+            // resending could fund the chunk twice
+            Err(error) if error.is::<MaybeSent>() => return Err(error),
+            Err(_) if attempt < options.retries => {
+                attempt += 1;
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+            }
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+/// Sends the transaction of a chunk unless one is already submitted, and awaits its receipt.
+///
+/// The hash of a submitted transaction is kept in `chunk` until the transaction is mined,
+/// and cleared again if it was dropped or reverted, so the next attempt sends it anew.
+async fn try_chunk(
+    sender: PrivateKeySigner,
+    config: ProviderConfig,
+    abi: JsonAbi,
+    contract_address: Address,
+    chunk: &mut DistributionChunk,
+) -> Result<DistributeResult> {
+    let prepared = prepare_distribution(
+        sender.address(),
+        config.clone(),
+        contract_address,
+        chunk.params.clone(),
+        &DistributeOptions::default(),
+    )
+    .await?;
+
+    let tx_hash = match chunk.tx_hash {
+        Some(tx_hash) => tx_hash,
+        None => {
+            let execute_options = ExecuteOptions {
+                value: Some(prepared.value),
+                ..Default::default()
+            };
+            let execution = submit_with_options(
+                sender,
+                config.clone(),
+                abi,
+                contract_address,
+                &prepared.function_name,
+                &prepared.args,
+                execute_options,
+            )
+            .await?;
+            *chunk.tx_hash.insert(execution.tx_hash)
+        }
+    };
+
+    match watch_pending_tx(config, tx_hash, RECEIPT_POLL_INTERVAL, RECEIPT_TIMEOUT).await? {
+        TxStatus::Confirmed(receipt) if receipt.status() => Ok(prepared.into_result(&receipt)),
+        TxStatus::Confirmed(_) => {
+            chunk.tx_hash = None;
+            Err(eyre!("transaction {tx_hash} reverted"))
+        }
+        TxStatus::Dropped => {
+            chunk.tx_hash = None;
+            Err(eyre!("transaction {tx_hash} was dropped"))
+        }
+        TxStatus::TimedOut => Err(eyre!("transaction {tx_hash} is still pending")),
+    }
+}
+
+/// Splits params into those whose receiver already holds the amount and those still to fund.
+async fn split_funded(
+    config: ProviderConfig,
+    params: Vec<DistributeParam>,
+) -> Result<(Vec<DistributeParam>, Vec<DistributeParam>)> {
+    let provider = ProviderBuilder::new().on_client(config.client()?);
+    let balances = try_join_all(
+        params
+            .iter()
+            .map(|param| provider.get_balance(param.receiver).into_future()),
+    )
+    .await?;
+
+    let (funded, pending) = params
+        .into_iter()
+        .zip(balances)
+        .partition::<Vec<_>, _>(|(param, balance)| *balance >= param.amount);
+
+    Ok((
+        funded.into_iter().map(|(param, _)| param).collect(),
+        pending.into_iter().map(|(param, _)| param).collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::U256;
+
+    fn params(count: usize) -> Vec<DistributeParam> {
+        (0..count)
+            .map(|i| DistributeParam {
+                receiver: Address::with_last_byte(i as u8 + 1),
+                amount: U256::from(i + 1),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_new_run() {
+        let run = DistributionRun::new(params(5), 2).unwrap();
+
+        assert_eq!(run.chunks.len(), 3);
+        assert_eq!(run.chunks[2].params.len(), 1);
+        assert_eq!(run.pending().len(), 5);
+        assert_eq!(run.funded(), 0);
+        assert!(!run.is_complete());
+
+        assert!(DistributionRun::new(params(5), 0).is_err());
+        assert!(DistributionRun::new(Vec::new(), 2).is_err());
+    }
+
+    #[test]
+    fn test_pending_skips_done_chunks() {
+        let mut run = DistributionRun::new(params(4), 2).unwrap();
+        run.chunks[0].result = Some(DistributeResult {
+            tx_hash: Default::default(),
+            gas_used: 50_000,
+            effective_gas_price: 1,
+            block_number: 1,
+            status: true,
+            total_distributed: U256::from(3),
+            recipient_count: 2,
+            contract_recipients: Vec::new(),
+        });
+
+        let pending: Vec<Address> = run.pending().iter().map(|param| param.receiver).collect();
+        assert_eq!(
+            pending,
+            vec![Address::with_last_byte(3), Address::with_last_byte(4)]
+        );
+        assert_eq!(run.funded(), 2);
+
+        run.chunks[1].params.clear();
+        assert!(run.is_complete());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_round_trip() {
+        let run = DistributionRun::new(params(3), 2).unwrap();
+        let json = run.to_json().unwrap();
+
+        assert_eq!(DistributionRun::from_json(&json).unwrap(), run);
+    }
+}
@@ -0,0 +1,400 @@
+use crate::distributor::token::{erc20_abi, get_uint};
+use crate::executor::{execute, ProviderConfig};
+use alloy::{
+    contract::Interface,
+    dyn_abi::DynSolValue,
+    eips::eip1559::Eip1559Estimation,
+    network::{EthereumWallet, TransactionBuilder},
+    primitives::{Address, TxHash, U256},
+    providers::{Provider, ProviderBuilder},
+    rpc::types::TransactionRequest,
+    signers::local::PrivateKeySigner,
+    transports::http::{Client, Http},
+};
+use eyre::{ensure, eyre, Report, Result};
+use futures::future::join_all;
+use std::fmt;
+
+/// Gas used by a plain Ether transfer to an account without code.
+pub(crate) const TRANSFER_GAS: u64 = 21_000;
+
+/// Represents the result of a sweep from one account.
+///
+/// # Fields
+///
+/// * `account` - The address of the swept account.
+/// * `result` - The transaction hash on success, `None` if the account was skipped because
+///   its balance does not cover the transfer cost plus `leave_behind`, or an error report on failure.
+#[derive(Debug)]
+pub struct SweepResult {
+    pub account: Address,
+    pub result: Result<Option<TxHash>, Report>,
+}
+
+impl SweepResult {
+    /// Creates a new `SweepResult` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `account` - The address of the swept account.
+    /// * `result` - The outcome of the sweep.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - A new `SweepResult` instance.
+    fn new(account: Address, result: Result<Option<TxHash>, Report>) -> Self {
+        Self { account, result }
+    }
+}
+
+/// Error returned when an account cannot pay for the gas of its sweep transaction.
+///
+/// # Fields
+///
+/// * `account` - The address of the account.
+/// * `required` - The worst-case gas cost of the transaction.
+/// * `balance` - The Ether balance of the account.
+#[derive(Debug)]
+pub struct InsufficientGasError {
+    pub account: Address,
+    pub required: U256,
+    pub balance: U256,
+}
+
+impl fmt::Display for InsufficientGasError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "insufficient gas: account {} needs {} wei but holds {} wei",
+            self.account, self.required, self.balance
+        )
+    }
+}
+
+impl std::error::Error for InsufficientGasError {}
+
+/// Sweeps the Ether of multiple accounts back to a single address.
+///
+/// Each account concurrently sends `balance - gas_cost - leave_behind` to `to`, where
+/// `gas_cost` is the worst-case cost of a 21000-gas transfer at the current EIP-1559 fees.
+///
+/// # Arguments
+///
+/// * `accounts` - A vector of private key signers to sweep.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `to` - The address receiving the swept Ether.
+/// * `leave_behind` - The amount of Ether to keep in every account.
+///
+/// # Returns
+///
+/// * `Result<Vec<SweepResult>>` - A vector of `SweepResult`, in the same order as `accounts`.
+pub async fn sweep(
+    accounts: Vec<PrivateKeySigner>,
+    rpc_http: impl Into<ProviderConfig>,
+    to: Address,
+    leave_behind: U256,
+) -> Result<Vec<SweepResult>> {
+    let config: ProviderConfig = rpc_http.into();
+    let results = join_all(accounts.into_iter().map(|account| {
+        let config = config.clone();
+        async move {
+            let address = account.address();
+            let result = sweep_account(account, config, to, leave_behind).await;
+            SweepResult::new(address, result)
+        }
+    }))
+    .await;
+
+    Ok(results)
+}
+
+/// Sweeps the Ether of one account.
+///
+/// # Arguments
+///
+/// * `account` - The private key signer of the account to sweep.
+/// * `config` - The connection to the Ethereum RPC endpoint.
+/// * `to` - The address receiving the swept Ether.
+/// * `leave_behind` - The amount of Ether to keep in the account.
+///
+/// # Returns
+///
+/// * `Result<Option<TxHash>>` - The transaction hash on success, or `None` if the account was skipped.
+async fn sweep_account(
+    account: PrivateKeySigner,
+    config: ProviderConfig,
+    to: Address,
+    leave_behind: U256,
+) -> Result<Option<TxHash>> {
+    let sent = send_balance(account, config, to, |balance, gas_cost| {
+        let kept = gas_cost.checked_add(leave_behind).ok_or_else(|| {
+            eyre!("leave_behind of {leave_behind} wei plus the transfer cost overflows")
+        })?;
+        Ok(balance.checked_sub(kept).unwrap_or_default())
+    })
+    .await?;
+
+    Ok(sent.map(|(_, tx_hash)| tx_hash))
+}
+
+/// Represents the result of draining one account.
+///
+/// # Fields
+///
+/// * `source` - The address of the drained account.
+/// * `amount_sent` - The amount sent to the destination, zero if nothing was sent.
+/// * `result` - The transaction hash on success, or an error report if the account could not
+///   be drained.
+#[derive(Debug)]
+pub struct DrainResult {
+    pub source: Address,
+    pub amount_sent: U256,
+    pub result: Result<TxHash, Report>,
+}
+
+/// Collects the Ether of multiple accounts, e.g. mint accounts once a run is over.
+///
+/// Each account concurrently sends `balance - leave_for_gas` to `destination`, paying the
+/// gas of the transfer out of `leave_for_gas`. Unlike [`sweep`], which computes the gas
+/// cost, the amount kept is fixed by the caller; an account fails if `leave_for_gas` does
+/// not cover the worst-case cost of the transfer at the current EIP-1559 fees.
+///
+/// # Arguments
+///
+/// * `accounts` - A vector of private key signers to drain.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `destination` - The address receiving the Ether.
+/// * `leave_for_gas` - The amount of Ether left in every account to pay for its transfer.
+///
+/// # Returns
+///
+/// * `Result<Vec<DrainResult>>` - A vector of `DrainResult`, in the same order as `accounts`.
+pub async fn drain_accounts(
+    accounts: Vec<PrivateKeySigner>,
+    rpc_http: impl Into<ProviderConfig>,
+    destination: Address,
+    leave_for_gas: U256,
+) -> Result<Vec<DrainResult>> {
+    let config: ProviderConfig = rpc_http.into();
+    let results = join_all(accounts.into_iter().map(|account| {
+        let config = config.clone();
+        async move {
+            let source = account.address();
+            match drain_account(account, config, destination, leave_for_gas).await {
+                Ok((amount_sent, tx_hash)) => DrainResult {
+                    source,
+                    amount_sent,
+                    result: Ok(tx_hash),
+                },
+                Err(error) => DrainResult {
+                    source,
+                    amount_sent: U256::ZERO,
+                    result: Err(error),
+                },
+            }
+        }
+    }))
+    .await;
+
+    Ok(results)
+}
+
+/// Drains the Ether of one account.
+///
+/// # Arguments
+///
+/// * `account` - The private key signer of the account to drain.
+/// * `config` - The connection to the Ethereum RPC endpoint.
+/// * `destination` - The address receiving the Ether.
+/// * `leave_for_gas` - The amount of Ether left in the account to pay for the transfer.
+///
+/// # Returns
+///
+/// * `Result<(U256, TxHash)>` - The amount sent and the transaction hash on success.
+async fn drain_account(
+    account: PrivateKeySigner,
+    config: ProviderConfig,
+    destination: Address,
+    leave_for_gas: U256,
+) -> Result<(U256, TxHash)> {
+    let address = account.address();
+
+    send_balance(account, config, destination, |balance, gas_cost| {
+        ensure!(
+            leave_for_gas >= gas_cost,
+            "leave_for_gas of {leave_for_gas} wei does not cover the transfer cost of {gas_cost} wei"
+        );
+        Ok(balance.saturating_sub(leave_for_gas))
+    })
+    .await?
+    .ok_or_else(|| {
+        eyre!("nothing to drain from {address}: its balance does not exceed {leave_for_gas} wei")
+    })
+}
+
+/// Sends Ether from one account in a plain transfer, at the current EIP-1559 fees.
+///
+/// # Arguments
+///
+/// * `account` - The private key signer of the sending account.
+/// * `config` - The connection to the Ethereum RPC endpoint.
+/// * `to` - The address receiving the Ether.
+/// * `amount` - Computes the amount to send from the balance of the account and the
+///   worst-case gas cost of the transfer; nothing is sent if it is zero.
+///
+/// # Returns
+///
+/// * `Result<Option<(U256, TxHash)>>` - The amount sent and the transaction hash on success,
+///   or `None` if the amount was zero.
+async fn send_balance(
+    account: PrivateKeySigner,
+    config: ProviderConfig,
+    to: Address,
+    amount: impl FnOnce(U256, U256) -> Result<U256>,
+) -> Result<Option<(U256, TxHash)>> {
+    let address = account.address();
+    let wallet = EthereumWallet::new(account);
+    let provider = ProviderBuilder::new()
+        .with_recommended_fillers()
+        .wallet(wallet)
+        .on_client(config.client()?);
+
+    let balance = provider.get_balance(address).await?;
+    let (fees, gas_cost) = transfer_fees(&provider).await?;
+
+    let amount = amount(balance, gas_cost)?;
+    if amount.is_zero() {
+        return Ok(None);
+    }
+
+    let tx = transfer_request(to, amount, &fees);
+    let tx_hash = provider.send_transaction(tx).await?.watch().await?;
+
+    Ok(Some((amount, tx_hash)))
+}
+
+/// Estimates the EIP-1559 fees of a plain Ether transfer.
+///
+/// # Arguments
+///
+/// * `provider` - The provider of the sending account.
+///
+/// # Returns
+///
+/// * `Result<(Eip1559Estimation, U256)>` - The fees and the worst-case gas cost of the
+///   transfer at those fees.
+async fn transfer_fees(
+    provider: &impl Provider<Http<Client>>,
+) -> Result<(Eip1559Estimation, U256)> {
+    let fees = provider.estimate_eip1559_fees(None).await?;
+    let gas_cost = U256::from(TRANSFER_GAS) * U256::from(fees.max_fee_per_gas);
+
+    Ok((fees, gas_cost))
+}
+
+/// Builds a plain Ether transfer limited to [`TRANSFER_GAS`] at the given fees.
+fn transfer_request(to: Address, amount: U256, fees: &Eip1559Estimation) -> TransactionRequest {
+    TransactionRequest::default()
+        .with_to(to)
+        .with_value(amount)
+        .with_gas_limit(TRANSFER_GAS)
+        .with_max_fee_per_gas(fees.max_fee_per_gas)
+        .with_max_priority_fee_per_gas(fees.max_priority_fee_per_gas)
+}
+
+/// Sweeps the ERC-20 token balances of multiple accounts to a single address.
+///
+/// Each account concurrently transfers its whole token balance to `to`. Accounts without
+/// tokens are skipped, and accounts that cannot pay for the transfer fail with an
+/// [`InsufficientGasError`] so they can be topped up and retried.
+///
+/// # Arguments
+///
+/// * `accounts` - A vector of private key signers to sweep.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `token` - The address of the ERC-20 token.
+/// * `to` - The address receiving the swept tokens.
+///
+/// # Returns
+///
+/// * `Result<Vec<SweepResult>>` - A vector of `SweepResult`, in the same order as `accounts`.
+pub async fn sweep_token(
+    accounts: Vec<PrivateKeySigner>,
+    rpc_http: impl Into<ProviderConfig>,
+    token: Address,
+    to: Address,
+) -> Result<Vec<SweepResult>> {
+    let config: ProviderConfig = rpc_http.into();
+    let results = join_all(accounts.into_iter().map(|account| {
+        let config = config.clone();
+        async move {
+            let address = account.address();
+            let result = sweep_token_account(account, config, token, to).await;
+            SweepResult::new(address, result)
+        }
+    }))
+    .await;
+
+    Ok(results)
+}
+
+/// Sweeps the ERC-20 token balance of one account.
+///
+/// # Arguments
+///
+/// * `account` - The private key signer of the account to sweep.
+/// * `config` - The connection to the Ethereum RPC endpoint.
+/// * `token` - The address of the ERC-20 token.
+/// * `to` - The address receiving the swept tokens.
+///
+/// # Returns
+///
+/// * `Result<Option<TxHash>>` - The transaction hash on success, or `None` if the account holds no tokens.
+async fn sweep_token_account(
+    account: PrivateKeySigner,
+    config: ProviderConfig,
+    token: Address,
+    to: Address,
+) -> Result<Option<TxHash>> {
+    let address = account.address();
+
+    let balance = get_uint(
+        config.clone(),
+        token,
+        "balanceOf",
+        &[DynSolValue::from(address)],
+    )
+    .await?;
+    if balance.is_zero() {
+        return Ok(None);
+    }
+
+    let abi = erc20_abi()?;
+    let args = [DynSolValue::from(to), DynSolValue::from(balance)];
+
+    // make sure the account can pay for the transfer
+    let provider = ProviderBuilder::new().on_client(config.client()?);
+    let input = Interface::new(abi.clone()).encode_input("transfer", &args)?;
+    let tx = TransactionRequest::default()
+        .with_from(address)
+        .with_to(token)
+        .with_input(input);
+    let gas = provider.estimate_gas(&tx).await?;
+    let fees = provider.estimate_eip1559_fees(None).await?;
+    let required = U256::from(gas) * U256::from(fees.max_fee_per_gas);
+    let eth_balance = provider.get_balance(address).await?;
+    if eth_balance < required {
+        return Err(InsufficientGasError {
+            account: address,
+            required,
+            balance: eth_balance,
+        }
+        .into());
+    }
+
+    let tx_hash = execute(account, config, abi, token, "transfer", &args, None)
+        .await?
+        .tx_hash;
+
+    Ok(Some(tx_hash))
+}
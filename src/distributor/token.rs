@@ -0,0 +1,206 @@
+use crate::distributor::{distribute::checked_total, DistributeParam};
+use crate::executor::{call, execute, ProviderConfig};
+use alloy::{
+    dyn_abi::DynSolValue,
+    json_abi::JsonAbi,
+    primitives::{Address, TxHash, U256},
+    signers::local::PrivateKeySigner,
+};
+use eyre::{eyre, Result};
+
+/// Human-readable ABI of the ERC-20 functions used by the distributor.
+const ERC20_ABI: [&str; 4] = [
+    "function balanceOf(address account) view returns (uint256)",
+    "function allowance(address owner, address spender) view returns (uint256)",
+    "function approve(address spender, uint256 value) returns (bool)",
+    "function transfer(address to, uint256 value) returns (bool)",
+];
+
+/// Returns the JSON ABI of the ERC-20 functions used by the distributor.
+pub(crate) fn erc20_abi() -> Result<JsonAbi> {
+    Ok(JsonAbi::parse(ERC20_ABI)?)
+}
+
+/// How the distributor is approved to spend the tokens of the sender.
+///
+/// An approval is only sent when the current allowance does not cover the distribution.
+///
+/// # Variants
+///
+/// * `Skip` - Never approve; the sender manages the allowance.
+/// * `Exact` - Approve exactly the total of the distribution.
+/// * `Max` - Approve `U256::MAX`, so later distributions need no approval.
+/// * `ResetThenExact` - Approve zero first when an allowance remains, then the total, for
+///   tokens like USDT that reject changing a non-zero allowance.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ApprovalPolicy {
+    Skip,
+    #[default]
+    Exact,
+    Max,
+    ResetThenExact,
+}
+
+/// Distributes ERC-20 tokens to multiple receivers.
+///
+/// # Arguments
+///
+/// * `sender` - The private key signer of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the distributor contract.
+/// * `contract_address` - The address of the distributor contract.
+/// * `token` - The address of the ERC-20 token to distribute.
+/// * `params` - A vector of `DistributeParam` containing receiver addresses and amounts.
+/// * `approve` - Whether to approve the distributor first when the current allowance is insufficient.
+///
+/// # Returns
+///
+/// * `Result<TxHash>` - The transaction hash of the distribution on success.
+pub async fn distribute_token(
+    sender: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    token: Address,
+    params: Vec<DistributeParam>,
+    approve: bool,
+) -> Result<TxHash> {
+    let approval = if approve {
+        ApprovalPolicy::Exact
+    } else {
+        ApprovalPolicy::Skip
+    };
+
+    distribute_token_with_approval(
+        sender,
+        rpc_http,
+        abi,
+        contract_address,
+        token,
+        params,
+        approval,
+    )
+    .await
+}
+
+/// Distributes ERC-20 tokens to multiple receivers, approving the distributor as needed.
+///
+/// The allowance of the distributor is read first, and if it does not cover the total,
+/// the approval required by `approval` is sent and awaited before distributing.
+///
+/// # Arguments
+///
+/// * `sender` - The private key signer of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the distributor contract.
+/// * `contract_address` - The address of the distributor contract.
+/// * `token` - The address of the ERC-20 token to distribute.
+/// * `params` - A vector of `DistributeParam` containing receiver addresses and amounts.
+/// * `approval` - How the distributor is approved.
+///
+/// # Returns
+///
+/// * `Result<TxHash>` - The transaction hash of the distribution on success.
+pub async fn distribute_token_with_approval(
+    sender: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    token: Address,
+    params: Vec<DistributeParam>,
+    approval: ApprovalPolicy,
+) -> Result<TxHash> {
+    let config: ProviderConfig = rpc_http.into();
+    let total = checked_total(&params)?;
+
+    if approval != ApprovalPolicy::Skip {
+        let allowance = get_uint(
+            config.clone(),
+            token,
+            "allowance",
+            &[
+                DynSolValue::from(sender.address()),
+                DynSolValue::from(contract_address),
+            ],
+        )
+        .await?;
+
+        if allowance < total {
+            let amounts = match approval {
+                ApprovalPolicy::Max => vec![U256::MAX],
+                ApprovalPolicy::ResetThenExact if !allowance.is_zero() => vec![U256::ZERO, total],
+                _ => vec![total],
+            };
+
+            for amount in amounts {
+                execute(
+                    sender.clone(),
+                    config.clone(),
+                    erc20_abi()?,
+                    token,
+                    "approve",
+                    &[
+                        DynSolValue::from(contract_address),
+                        DynSolValue::from(amount),
+                    ],
+                    None,
+                )
+                .await?;
+            }
+        }
+    }
+
+    let txns = DynSolValue::Array(
+        params
+            .iter()
+            .map(|r| {
+                DynSolValue::Tuple(vec![
+                    DynSolValue::from(r.receiver),
+                    DynSolValue::from(r.amount),
+                ])
+            })
+            .collect(),
+    );
+
+    let args = &[DynSolValue::from(token), txns];
+
+    let tx_hash = execute(
+        sender,
+        config,
+        abi,
+        contract_address,
+        "distributeToken",
+        args,
+        None,
+    )
+    .await?
+    .tx_hash;
+
+    Ok(tx_hash)
+}
+
+/// Calls a `uint256`-returning view function on an ERC-20 token.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `token` - The address of the ERC-20 token.
+/// * `function_name` - The name of the view function to call.
+/// * `args` - The arguments to pass to the function.
+///
+/// # Returns
+///
+/// * `Result<U256>` - The returned value on success.
+pub(crate) async fn get_uint(
+    rpc_http: impl Into<ProviderConfig>,
+    token: Address,
+    function_name: &str,
+    args: &[DynSolValue],
+) -> Result<U256> {
+    let value = call(rpc_http, erc20_abi()?, token, function_name, args).await?;
+
+    match value.first() {
+        Some(DynSolValue::Uint(value, 256)) => Ok(*value),
+        _ => Err(eyre!("unexpected return value from `{function_name}`")),
+    }
+}
@@ -0,0 +1,69 @@
+use crate::distributor::{distribute, DistributeParam};
+use crate::executor::ProviderConfig;
+use alloy::{
+    json_abi::JsonAbi,
+    primitives::{Address, TxHash, U256},
+    providers::{Provider, ProviderBuilder},
+    signers::local::PrivateKeySigner,
+};
+use eyre::Result;
+use futures::future::try_join_all;
+use std::{collections::HashSet, future::IntoFuture};
+
+/// Tops up receivers whose balance is below a target.
+///
+/// Each receiver below `target` is sent `target - balance` in a single batched
+/// distribution; receivers already at or above `target` are skipped. A receiver listed more
+/// than once is topped up only once.
+///
+/// # Arguments
+///
+/// * `sender` - The private key signer of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `receivers` - The addresses to top up.
+/// * `target` - The balance every receiver should end up with.
+///
+/// # Returns
+///
+/// * `Result<Option<TxHash>>` - The transaction hash of the distribution, or `None` if no receiver needed funds.
+pub async fn top_up(
+    sender: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    receivers: &[Address],
+    target: U256,
+) -> Result<Option<TxHash>> {
+    let config: ProviderConfig = rpc_http.into();
+    let mut receivers = receivers.to_vec();
+    let mut seen = HashSet::with_capacity(receivers.len());
+    receivers.retain(|receiver| seen.insert(*receiver));
+
+    let provider = ProviderBuilder::new().on_client(config.client()?);
+    let balances = try_join_all(
+        receivers
+            .iter()
+            .map(|receiver| provider.get_balance(*receiver).into_future()),
+    )
+    .await?;
+
+    let params: Vec<DistributeParam> = receivers
+        .into_iter()
+        .zip(balances)
+        .filter(|(_, balance)| *balance < target)
+        .map(|(receiver, balance)| DistributeParam {
+            receiver,
+            amount: target - balance,
+        })
+        .collect();
+
+    if params.is_empty() {
+        return Ok(None);
+    }
+
+    let tx_hash = distribute(sender, config, abi, contract_address, params).await?;
+
+    Ok(Some(tx_hash))
+}
@@ -0,0 +1,493 @@
+use crate::distributor::DistributeParam;
+use alloy::primitives::{Address, U256};
+use eyre::{ensure, Result};
+use std::fmt;
+
+/// Default maximum number of receivers in one distribution.
+pub const DEFAULT_MAX_RECIPIENTS: usize = 1000;
+
+/// How params with a zero amount are handled by [`validate_params`] and [`encode_with`].
+///
+/// # Variants
+///
+/// * `Reject` - Zero amounts are reported as violations.
+/// * `Skip` - Zero-amount params are removed before sending.
+///
+/// [`encode_with`]: crate::distributor::encode_with
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ZeroAmountPolicy {
+    #[default]
+    Reject,
+    Skip,
+}
+
+/// Rules enforced on distribution params before sending.
+///
+/// # Fields
+///
+/// * `zero_amount` - How params with a zero amount are handled.
+/// * `max_recipients` - The maximum number of receivers in one distribution.
+/// * `max_per_recipient` - The maximum amount a single receiver may get (optional).
+/// * `max_total` - The maximum total amount of the distribution (optional).
+#[derive(Debug, Clone)]
+pub struct ValidationRules {
+    pub zero_amount: ZeroAmountPolicy,
+    pub max_recipients: usize,
+    pub max_per_recipient: Option<U256>,
+    pub max_total: Option<U256>,
+}
+
+impl Default for ValidationRules {
+    fn default() -> Self {
+        Self {
+            zero_amount: ZeroAmountPolicy::default(),
+            max_recipients: DEFAULT_MAX_RECIPIENTS,
+            max_per_recipient: None,
+            max_total: None,
+        }
+    }
+}
+
+/// A rule broken by distribution params.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    /// The receiver at `index` is the zero address.
+    ZeroAddress { index: usize },
+    /// The amount at `index` is zero.
+    ZeroAmount { index: usize },
+    /// The receiver at `index` is the sender itself.
+    SelfTransfer { index: usize },
+    /// There are more receivers than allowed.
+    TooManyRecipients { count: usize, max: usize },
+    /// The amount at `index` exceeds the per-recipient cap.
+    AmountAboveCap {
+        index: usize,
+        amount: U256,
+        max: U256,
+    },
+    /// The total amount exceeds the cap; `total` saturates at `U256::MAX`.
+    TotalAboveCap { total: U256, max: U256 },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroAddress { index } => write!(f, "param {index}: receiver is the zero address"),
+            Self::ZeroAmount { index } => write!(f, "param {index}: amount is zero"),
+            Self::SelfTransfer { index } => write!(f, "param {index}: receiver is the sender"),
+            Self::TooManyRecipients { count, max } => {
+                write!(f, "{count} receivers exceed the maximum of {max}")
+            }
+            Self::AmountAboveCap { index, amount, max } => {
+                write!(f, "param {index}: amount {amount} exceeds the cap of {max}")
+            }
+            Self::TotalAboveCap { total, max } => {
+                write!(f, "total amount {total} exceeds the cap of {max}")
+            }
+        }
+    }
+}
+
+/// Error returned by [`validate_params`], listing every violation found.
+///
+/// # Fields
+///
+/// * `violations` - The violations, in the order of the params.
+#[derive(Debug)]
+pub struct InvalidParamsError {
+    pub violations: Vec<Violation>,
+}
+
+impl fmt::Display for InvalidParamsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid distribution params")?;
+        for (i, violation) in self.violations.iter().enumerate() {
+            let separator = if i == 0 { ": " } else { "; " };
+            write!(f, "{separator}{violation}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for InvalidParamsError {}
+
+impl DistributeParam {
+    /// Checks that the param sends a non-zero amount to a non-zero address.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - An error naming the broken rule.
+    pub fn validate(&self) -> Result<()> {
+        self.validate_with(ZeroAmountPolicy::Reject)
+    }
+
+    /// Checks that the param sends to a non-zero address, allowing a zero amount if
+    /// `zero_amount` is [`ZeroAmountPolicy::Skip`], under which such a param is removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `zero_amount` - How a zero amount is handled.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - An error naming the broken rule.
+    pub fn validate_with(&self, zero_amount: ZeroAmountPolicy) -> Result<()> {
+        ensure!(!self.receiver.is_zero(), "receiver is the zero address");
+        ensure!(
+            !self.amount.is_zero() || zero_amount == ZeroAmountPolicy::Skip,
+            "amount sent to {} is zero",
+            self.receiver
+        );
+
+        Ok(())
+    }
+
+    /// Lists the zero-address and zero-amount violations of the param at `index`.
+    fn violations(&self, index: usize, zero_amount: ZeroAmountPolicy) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        if self.receiver.is_zero() {
+            violations.push(Violation::ZeroAddress { index });
+        }
+        if self.amount.is_zero() && zero_amount == ZeroAmountPolicy::Reject {
+            violations.push(Violation::ZeroAmount { index });
+        }
+
+        violations
+    }
+}
+
+/// Checks that every param sends a non-zero amount to a non-zero address.
+///
+/// Unlike [`DistributeParam::validate`], every broken rule is reported, together in an
+/// [`InvalidParamsError`].
+///
+/// # Arguments
+///
+/// * `params` - The params to validate.
+///
+/// # Returns
+///
+/// * `Result<()>` - An [`InvalidParamsError`] listing every violation.
+pub fn validate_all(params: &[DistributeParam]) -> Result<()> {
+    ensure_no_violations(params, ZeroAmountPolicy::Reject)
+}
+
+/// Checks params like [`validate_all`], removing zero amounts under [`ZeroAmountPolicy::Skip`].
+///
+/// # Arguments
+///
+/// * `params` - The params to check.
+/// * `zero_amount` - How params with a zero amount are handled.
+///
+/// # Returns
+///
+/// * `Result<Vec<DistributeParam>>` - The params to send, or an [`InvalidParamsError`] listing
+///   every violation.
+pub(crate) fn checked_params(
+    params: Vec<DistributeParam>,
+    zero_amount: ZeroAmountPolicy,
+) -> Result<Vec<DistributeParam>> {
+    ensure_no_violations(&params, zero_amount)?;
+
+    Ok(params
+        .into_iter()
+        .filter(|param| !param.amount.is_zero())
+        .collect())
+}
+
+/// Fails with an [`InvalidParamsError`] if any param sends to the zero address, or a zero
+/// amount under [`ZeroAmountPolicy::Reject`].
+fn ensure_no_violations(params: &[DistributeParam], zero_amount: ZeroAmountPolicy) -> Result<()> {
+    let violations: Vec<Violation> = params
+        .iter()
+        .enumerate()
+        .flat_map(|(index, param)| param.violations(index, zero_amount))
+        .collect();
+
+    if !violations.is_empty() {
+        return Err(InvalidParamsError { violations }.into());
+    }
+
+    Ok(())
+}
+
+/// Validates distribution params before they are sent.
+///
+/// Zero-address receivers, receivers equal to the sender, zero amounts (unless skipped),
+/// too many receivers and amounts above the caps are all reported together in an
+/// [`InvalidParamsError`].
+///
+/// # Arguments
+///
+/// * `sender` - The address of the sender.
+/// * `params` - The params to validate.
+/// * `rules` - The rules to enforce.
+///
+/// # Returns
+///
+/// * `Result<Vec<DistributeParam>>` - The params, without zero amounts if they are skipped.
+pub fn validate_params(
+    sender: Address,
+    params: Vec<DistributeParam>,
+    rules: &ValidationRules,
+) -> Result<Vec<DistributeParam>> {
+    let mut violations = Vec::new();
+
+    for (index, param) in params.iter().enumerate() {
+        violations.extend(param.violations(index, rules.zero_amount));
+        if param.receiver == sender {
+            violations.push(Violation::SelfTransfer { index });
+        }
+        if let Some(max) = rules.max_per_recipient {
+            if param.amount > max {
+                violations.push(Violation::AmountAboveCap {
+                    index,
+                    amount: param.amount,
+                    max,
+                });
+            }
+        }
+    }
+
+    let params: Vec<DistributeParam> = params
+        .into_iter()
+        .filter(|param| !param.amount.is_zero())
+        .collect();
+
+    if params.len() > rules.max_recipients {
+        violations.push(Violation::TooManyRecipients {
+            count: params.len(),
+            max: rules.max_recipients,
+        });
+    }
+
+    if let Some(max) = rules.max_total {
+        let total = params.iter().fold(U256::ZERO, |total, param| {
+            total.saturating_add(param.amount)
+        });
+        if total > max {
+            violations.push(Violation::TotalAboveCap { total, max });
+        }
+    }
+
+    if !violations.is_empty() {
+        return Err(InvalidParamsError { violations }.into());
+    }
+
+    Ok(params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SENDER: Address = Address::repeat_byte(0xff);
+
+    fn param(receiver: Address, amount: u64) -> DistributeParam {
+        DistributeParam {
+            receiver,
+            amount: U256::from(amount),
+        }
+    }
+
+    fn violations(params: Vec<DistributeParam>, rules: &ValidationRules) -> Vec<Violation> {
+        validate_params(SENDER, params, rules)
+            .unwrap_err()
+            .downcast::<InvalidParamsError>()
+            .unwrap()
+            .violations
+    }
+
+    #[test]
+    fn test_valid_params() {
+        let params = vec![param(Address::repeat_byte(1), 1)];
+
+        assert_eq!(
+            validate_params(SENDER, params, &ValidationRules::default())
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_reject_zero_address() {
+        let params = vec![param(Address::repeat_byte(1), 1), param(Address::ZERO, 1)];
+
+        assert_eq!(
+            violations(params, &ValidationRules::default()),
+            [Violation::ZeroAddress { index: 1 }]
+        );
+    }
+
+    #[test]
+    fn test_reject_self_transfer() {
+        let params = vec![param(SENDER, 1)];
+
+        assert_eq!(
+            violations(params, &ValidationRules::default()),
+            [Violation::SelfTransfer { index: 0 }]
+        );
+    }
+
+    #[test]
+    fn test_zero_amount_policy() {
+        let params = || {
+            vec![
+                param(Address::repeat_byte(1), 0),
+                param(Address::repeat_byte(2), 1),
+            ]
+        };
+
+        assert_eq!(
+            violations(params(), &ValidationRules::default()),
+            [Violation::ZeroAmount { index: 0 }]
+        );
+
+        let rules = ValidationRules {
+            zero_amount: ZeroAmountPolicy::Skip,
+            ..Default::default()
+        };
+        let params = validate_params(SENDER, params(), &rules).unwrap();
+        assert_eq!(params.len(), 1);
+        assert_eq!(params[0].receiver, Address::repeat_byte(2));
+    }
+
+    #[test]
+    fn test_param_validate() {
+        assert!(param(Address::repeat_byte(1), 1).validate().is_ok());
+
+        let error = param(Address::ZERO, 1).validate().unwrap_err();
+        assert_eq!(error.to_string(), "receiver is the zero address");
+
+        let zero_amount = param(Address::repeat_byte(1), 0);
+        assert!(zero_amount.validate().is_err());
+        assert!(zero_amount.validate_with(ZeroAmountPolicy::Skip).is_ok());
+        assert!(param(Address::ZERO, 0)
+            .validate_with(ZeroAmountPolicy::Skip)
+            .is_err());
+    }
+
+    #[test]
+    fn test_validate_all() {
+        assert!(validate_all(&[param(Address::repeat_byte(1), 1)]).is_ok());
+
+        let params = [
+            param(Address::ZERO, 1),
+            param(Address::repeat_byte(1), 1),
+            param(Address::repeat_byte(2), 0),
+        ];
+        let error = validate_all(&params).unwrap_err();
+        assert_eq!(
+            error
+                .downcast_ref::<InvalidParamsError>()
+                .unwrap()
+                .violations,
+            [
+                Violation::ZeroAddress { index: 0 },
+                Violation::ZeroAmount { index: 2 },
+            ]
+        );
+        assert_eq!(
+            error.to_string(),
+            "invalid distribution params: param 0: receiver is the zero address; \
+             param 2: amount is zero"
+        );
+    }
+
+    #[test]
+    fn test_reject_too_many_recipients() {
+        let params = (1..=3).map(|i| param(Address::repeat_byte(i), 1)).collect();
+        let rules = ValidationRules {
+            max_recipients: 2,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            violations(params, &rules),
+            [Violation::TooManyRecipients { count: 3, max: 2 }]
+        );
+    }
+
+    #[test]
+    fn test_report_every_violation() {
+        let params = vec![
+            param(Address::ZERO, 0),
+            param(Address::repeat_byte(1), 1),
+            param(SENDER, 1),
+            param(Address::ZERO, 1),
+        ];
+        let rules = ValidationRules {
+            max_recipients: 2,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            violations(params, &rules),
+            [
+                Violation::ZeroAddress { index: 0 },
+                Violation::ZeroAmount { index: 0 },
+                Violation::SelfTransfer { index: 2 },
+                Violation::ZeroAddress { index: 3 },
+                Violation::TooManyRecipients { count: 3, max: 2 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reject_amount_above_cap() {
+        let params = vec![
+            param(Address::repeat_byte(1), 100),
+            param(Address::repeat_byte(2), 101),
+        ];
+        let rules = ValidationRules {
+            max_per_recipient: Some(U256::from(100)),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            violations(params, &rules),
+            [Violation::AmountAboveCap {
+                index: 1,
+                amount: U256::from(101),
+                max: U256::from(100),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_reject_total_above_cap() {
+        let params = vec![
+            param(Address::repeat_byte(1), 100),
+            param(Address::repeat_byte(2), 100),
+        ];
+        let rules = ValidationRules {
+            max_total: Some(U256::from(199)),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            violations(params, &rules),
+            [Violation::TotalAboveCap {
+                total: U256::from(200),
+                max: U256::from(199),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_amounts_within_caps() {
+        let params = vec![
+            param(Address::repeat_byte(1), 99),
+            param(Address::repeat_byte(2), 100),
+        ];
+        let rules = ValidationRules {
+            max_per_recipient: Some(U256::from(100)),
+            max_total: Some(U256::from(200)),
+            ..Default::default()
+        };
+
+        assert_eq!(validate_params(SENDER, params, &rules).unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::distributor::DistributeParam;
+use crate::executor::ProviderConfig;
+use alloy::{
+    primitives::{Address, U256},
+    providers::{Provider, ProviderBuilder},
+};
+use eyre::Result;
+use futures::future::try_join_all;
+use std::future::IntoFuture;
+
+/// How receiver balances are compared against the distributed amounts.
+///
+/// # Variants
+///
+/// * `Exact` - The balance must equal the amount, for receivers that started empty.
+/// * `AtLeast` - The balance must be at least the amount, for receivers with pre-existing balances.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerifyMode {
+    Exact,
+    AtLeast,
+}
+
+/// A receiver whose balance does not match its distributed amount.
+///
+/// # Fields
+///
+/// * `receiver` - The address of the receiver.
+/// * `expected` - The distributed amount.
+/// * `actual` - The current balance of the receiver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifyFailure {
+    pub receiver: Address,
+    pub expected: U256,
+    pub actual: U256,
+}
+
+/// Represents the outcome of a balance verification.
+///
+/// # Fields
+///
+/// * `checked` - The number of receivers checked.
+/// * `failures` - The receivers whose balance does not match, in the order of the params.
+#[derive(Debug, Clone)]
+pub struct VerifyReport {
+    pub checked: usize,
+    pub failures: Vec<VerifyFailure>,
+}
+
+impl VerifyReport {
+    /// Returns `true` if every receiver holds its expected balance.
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Verifies that every receiver of a distribution holds its distributed amount.
+///
+/// Balances are queried concurrently. Each param is checked on its own, so duplicate
+/// receivers should be merged with [`normalize`](crate::distributor::normalize) first.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `params` - The params of the distribution.
+/// * `mode` - How balances are compared against the amounts.
+///
+/// # Returns
+///
+/// * `Result<VerifyReport>` - The report listing every mismatching receiver on success.
+pub async fn verify(
+    rpc_http: impl Into<ProviderConfig>,
+    params: &[DistributeParam],
+    mode: VerifyMode,
+) -> Result<VerifyReport> {
+    let provider = ProviderBuilder::new().on_client(rpc_http.into().client()?);
+    let balances = try_join_all(
+        params
+            .iter()
+            .map(|param| provider.get_balance(param.receiver).into_future()),
+    )
+    .await?;
+
+    let failures = params
+        .iter()
+        .zip(balances)
+        .filter(|(param, balance)| match mode {
+            VerifyMode::Exact => *balance != param.amount,
+            VerifyMode::AtLeast => *balance < param.amount,
+        })
+        .map(|(param, balance)| VerifyFailure {
+            receiver: param.receiver,
+            expected: param.amount,
+            actual: balance,
+        })
+        .collect();
+
+    Ok(VerifyReport {
+        checked: params.len(),
+        failures,
+    })
+}
@@ -0,0 +1,84 @@
+use crate::executor::ProviderConfig;
+use alloy::{
+    eips::eip7702::{Authorization, SignedAuthorization},
+    primitives::{Address, U256},
+    providers::{Provider, ProviderBuilder},
+    signers::{local::PrivateKeySigner, SignerSync},
+};
+use eyre::Result;
+
+/// Signs an EIP-7702 authorization delegating the signer's account to `delegate`.
+///
+/// # Arguments
+///
+/// * `signer` - The private key signer of the account being delegated.
+/// * `chain_id` - The chain ID the authorization is valid on (`0` for any chain).
+/// * `delegate` - The address of the contract whose code the account will use.
+/// * `nonce` - The current nonce of the account being delegated.
+///
+/// # Returns
+///
+/// * `Result<SignedAuthorization>` - The signed authorization on success.
+pub fn sign_authorization(
+    signer: &PrivateKeySigner,
+    chain_id: u64,
+    delegate: Address,
+    nonce: u64,
+) -> Result<SignedAuthorization> {
+    let authorization = Authorization {
+        chain_id: U256::from(chain_id),
+        address: delegate,
+        nonce,
+    };
+
+    let signature = signer.sign_hash_sync(&authorization.signature_hash())?;
+
+    Ok(authorization.into_signed(signature))
+}
+
+/// Signs EIP-7702 authorizations for multiple signers, fetching the chain ID and nonces from the node.
+///
+/// The sponsor sending the transaction must not be one of `signers`, since its nonce
+/// is incremented before the authorization list is processed.
+///
+/// # Arguments
+///
+/// * `signers` - The private key signers of the accounts being delegated.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `delegate` - The address of the contract whose code the accounts will use.
+///
+/// # Returns
+///
+/// * `Result<Vec<SignedAuthorization>>` - The signed authorizations, in the same order as `signers`.
+pub async fn sign_authorizations(
+    signers: &[PrivateKeySigner],
+    rpc_http: impl Into<ProviderConfig>,
+    delegate: Address,
+) -> Result<Vec<SignedAuthorization>> {
+    let provider = ProviderBuilder::new().on_client(rpc_http.into().client()?);
+    let chain_id = provider.get_chain_id().await?;
+
+    let mut authorizations = Vec::with_capacity(signers.len());
+    for signer in signers {
+        let nonce = provider.get_transaction_count(signer.address()).await?;
+        authorizations.push(sign_authorization(signer, chain_id, delegate, nonce)?);
+    }
+
+    Ok(authorizations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_authorization_recovers_signer() {
+        let signer = PrivateKeySigner::random();
+        let delegate = Address::repeat_byte(0x77);
+
+        let authorization = sign_authorization(&signer, 31337, delegate, 0).unwrap();
+
+        assert_eq!(authorization.address, delegate);
+        assert_eq!(authorization.recover_authority().unwrap(), signer.address());
+    }
+}
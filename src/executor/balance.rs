@@ -0,0 +1,94 @@
+use crate::executor::ProviderConfig;
+use alloy::{
+    primitives::{utils::format_ether, Address, U256},
+    providers::{Provider, ProviderBuilder},
+};
+use eyre::Result;
+use std::fmt;
+
+/// Error returned when an account holds less Ether than an operation needs.
+///
+/// # Fields
+///
+/// * `address` - The address of the account.
+/// * `required` - The amount the operation needs, in wei.
+/// * `available` - The balance of the account, in wei.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InsufficientBalanceError {
+    pub address: Address,
+    pub required: U256,
+    pub available: U256,
+}
+
+impl InsufficientBalanceError {
+    /// Returns how much Ether is missing, in wei.
+    pub fn deficit(&self) -> U256 {
+        self.required.saturating_sub(self.available)
+    }
+}
+
+impl fmt::Display for InsufficientBalanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "insufficient balance: {} holds {} ETH but needs {} ETH ({} ETH short)",
+            self.address,
+            format_ether(self.available),
+            format_ether(self.required),
+            format_ether(self.deficit())
+        )
+    }
+}
+
+impl std::error::Error for InsufficientBalanceError {}
+
+/// Fails if an account holds less Ether than required.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `address` - The address of the account.
+/// * `required` - The amount the account needs, in wei.
+///
+/// # Returns
+///
+/// * `Result<()>` - An [`InsufficientBalanceError`] if the balance is below `required`.
+pub async fn assert_enough_balance(
+    rpc_http: impl Into<ProviderConfig>,
+    address: Address,
+    required: U256,
+) -> Result<()> {
+    let provider = ProviderBuilder::new().on_client(rpc_http.into().client()?);
+    let available = provider.get_balance(address).await?;
+
+    if available < required {
+        return Err(InsufficientBalanceError {
+            address,
+            required,
+            available,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::utils::parse_ether;
+
+    #[test]
+    fn test_insufficient_balance_message() {
+        let error = InsufficientBalanceError {
+            address: Address::ZERO,
+            required: parse_ether("1.5").unwrap(),
+            available: parse_ether("0.25").unwrap(),
+        };
+
+        assert_eq!(error.deficit(), parse_ether("1.25").unwrap());
+        assert!(error
+            .to_string()
+            .contains("(1.250000000000000000 ETH short)"));
+    }
+}
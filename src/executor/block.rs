@@ -0,0 +1,50 @@
+use crate::executor::ProviderConfig;
+use alloy::{
+    eips::BlockId,
+    primitives::U256,
+    providers::{Provider, ProviderBuilder},
+};
+use eyre::{eyre, Result};
+
+/// The metadata of a block.
+///
+/// # Fields
+///
+/// * `number` - The number of the block.
+/// * `timestamp` - The timestamp of the block, in seconds since the Unix epoch.
+/// * `gas_limit` - The gas limit of the block.
+/// * `base_fee_per_gas` - The EIP-1559 base fee of the block, in wei; `None` before London.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockInfo {
+    pub number: u64,
+    pub timestamp: u64,
+    pub gas_limit: u64,
+    pub base_fee_per_gas: Option<U256>,
+}
+
+/// Gets the metadata of a block, without its transactions.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `block` - The block to get, e.g. `BlockId::latest()`.
+///
+/// # Returns
+///
+/// * `Result<BlockInfo>` - The metadata, or an error if the block does not exist.
+pub async fn get_block(rpc_http: impl Into<ProviderConfig>, block: BlockId) -> Result<BlockInfo> {
+    let provider = ProviderBuilder::new().on_client(rpc_http.into().client()?);
+
+    let header = provider
+        .get_block(block, false.into())
+        .await?
+        .ok_or_else(|| eyre!("block {block} not found"))?
+        .header;
+
+    Ok(BlockInfo {
+        number: header.number,
+        timestamp: header.timestamp,
+        gas_limit: header.gas_limit,
+        base_fee_per_gas: header.base_fee_per_gas.map(U256::from),
+    })
+}
@@ -0,0 +1,95 @@
+use alloy::{
+    dyn_abi::DynSolValue,
+    primitives::{Address, B256},
+    transports::http::reqwest::Url,
+};
+use dashmap::DashMap;
+
+/// Key of a cached call: the endpoint, the contract, the function and the hash of the calldata.
+type CallKey = (Url, Address, String, B256);
+
+/// A cache of read-only call results, shared between concurrent calls.
+///
+/// Results are keyed by endpoint URL, contract address, function name and calldata hash, so a
+/// cache shared between endpoints of different chains never mixes up their results. A cache
+/// pinned to a block with [`CallCache::at_block`] never goes stale; one created with
+/// [`CallCache::new`] reads the latest block and must be invalidated when the chain moves on.
+#[derive(Debug, Default)]
+pub struct CallCache {
+    block: Option<u64>,
+    entries: DashMap<CallKey, Vec<DynSolValue>>,
+}
+
+impl CallCache {
+    /// Creates an empty cache for calls at the latest block.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty cache for calls at a fixed block.
+    ///
+    /// # Arguments
+    ///
+    /// * `block` - The number of the block every call is made at.
+    pub fn at_block(block: u64) -> Self {
+        Self {
+            block: Some(block),
+            entries: DashMap::new(),
+        }
+    }
+
+    /// Returns the block calls are made at, or `None` for the latest block.
+    pub fn block(&self) -> Option<u64> {
+        self.block
+    }
+
+    /// Returns the number of cached results.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no result is cached.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes every cached result.
+    pub fn invalidate_all(&self) {
+        self.entries.clear();
+    }
+
+    pub(crate) fn get(
+        &self,
+        rpc_http: &Url,
+        contract_address: Address,
+        function_name: &str,
+        calldata_hash: B256,
+    ) -> Option<Vec<DynSolValue>> {
+        let key = (
+            rpc_http.clone(),
+            contract_address,
+            function_name.to_string(),
+            calldata_hash,
+        );
+
+        self.entries.get(&key).map(|entry| entry.value().clone())
+    }
+
+    pub(crate) fn insert(
+        &self,
+        rpc_http: &Url,
+        contract_address: Address,
+        function_name: &str,
+        calldata_hash: B256,
+        value: Vec<DynSolValue>,
+    ) {
+        let key = (
+            rpc_http.clone(),
+            contract_address,
+            function_name.to_string(),
+            calldata_hash,
+        );
+
+        self.entries.insert(key, value);
+    }
+}
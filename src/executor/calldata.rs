@@ -0,0 +1,137 @@
+use alloy::{
+    dyn_abi::{DynSolValue, JsonAbiExt},
+    json_abi::JsonAbi,
+    primitives::Bytes,
+};
+use eyre::{ensure, eyre, Result};
+
+/// Encodes a function call into calldata without touching the network.
+///
+/// When the function is overloaded, the first overload accepting `args` is used.
+///
+/// # Arguments
+///
+/// * `abi` - The JSON ABI of the contract.
+/// * `function_name` - The name of the function to encode.
+/// * `args` - The arguments to pass to the function.
+///
+/// # Returns
+///
+/// * `Result<Bytes>` - The selector followed by the ABI-encoded arguments on success.
+pub fn build_calldata(abi: &JsonAbi, function_name: &str, args: &[DynSolValue]) -> Result<Bytes> {
+    let functions = abi
+        .function(function_name)
+        .ok_or_else(|| eyre!("function `{function_name}` not found in the ABI"))?;
+
+    let mut last_error = None;
+    for function in functions {
+        match function.abi_encode_input(args) {
+            Ok(calldata) => return Ok(Bytes::from(calldata)),
+            Err(error) => last_error = Some(error),
+        }
+    }
+
+    Err(last_error.map_or_else(
+        || eyre!("function `{function_name}` not found in the ABI"),
+        Into::into,
+    ))
+}
+
+/// Decodes calldata back into the function name and its arguments.
+///
+/// # Arguments
+///
+/// * `abi` - The JSON ABI of the contract.
+/// * `calldata` - The selector followed by the ABI-encoded arguments.
+///
+/// # Returns
+///
+/// * `Result<(String, Vec<DynSolValue>)>` - The function name and the decoded arguments on success.
+pub fn decode_calldata(abi: &JsonAbi, calldata: &Bytes) -> Result<(String, Vec<DynSolValue>)> {
+    ensure!(calldata.len() >= 4, "calldata is shorter than a selector");
+
+    let (selector, data) = calldata.split_at(4);
+    let function = abi
+        .functions()
+        .find(|function| function.selector().as_slice() == selector)
+        .ok_or_else(|| {
+            eyre!(
+                "no function with selector 0x{} in the ABI",
+                alloy::hex::encode(selector)
+            )
+        })?;
+
+    let args = function.abi_decode_input(data, true)?;
+
+    Ok((function.name.clone(), args))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{Address, U256};
+
+    fn abi() -> JsonAbi {
+        JsonAbi::parse([
+            "function mint()",
+            "function transfer(address to, uint256 value) returns (bool)",
+            "function distributeEther((address recipient, uint256 amount)[] txns)",
+            "function distributeToken(address token, (address recipient, uint256 amount)[] txns)",
+        ])
+        .unwrap()
+    }
+
+    fn round_trip(function_name: &str, args: Vec<DynSolValue>) {
+        let abi = abi();
+        let calldata = build_calldata(&abi, function_name, &args).unwrap();
+        let (name, decoded) = decode_calldata(&abi, &calldata).unwrap();
+
+        assert_eq!(name, function_name);
+        assert_eq!(decoded, args);
+    }
+
+    #[test]
+    fn test_round_trip_without_args() {
+        round_trip("mint", vec![]);
+    }
+
+    #[test]
+    fn test_round_trip_with_args() {
+        round_trip(
+            "transfer",
+            vec![
+                DynSolValue::from(Address::repeat_byte(1)),
+                DynSolValue::from(U256::from(42)),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_round_trip_with_tuples() {
+        let txns = DynSolValue::Array(vec![
+            DynSolValue::Tuple(vec![
+                DynSolValue::from(Address::repeat_byte(1)),
+                DynSolValue::from(U256::from(1)),
+            ]),
+            DynSolValue::Tuple(vec![
+                DynSolValue::from(Address::repeat_byte(2)),
+                DynSolValue::from(U256::from(2)),
+            ]),
+        ]);
+
+        round_trip("distributeEther", vec![txns.clone()]);
+        round_trip(
+            "distributeToken",
+            vec![DynSolValue::from(Address::repeat_byte(3)), txns],
+        );
+    }
+
+    #[test]
+    fn test_unknown_function_and_selector() {
+        let abi = abi();
+
+        assert!(build_calldata(&abi, "burn", &[]).is_err());
+        assert!(decode_calldata(&abi, &Bytes::from(vec![0xde, 0xad, 0xbe, 0xef])).is_err());
+        assert!(decode_calldata(&abi, &Bytes::from(vec![0xde])).is_err());
+    }
+}
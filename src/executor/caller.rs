@@ -1,18 +1,21 @@
+use crate::executor::{CallCache, ProviderConfig};
 use alloy::{
     contract::{ContractInstance, Interface},
     dyn_abi::DynSolValue,
+    eips::BlockId,
     json_abi::JsonAbi,
-    primitives::Address,
+    primitives::{keccak256, Address},
     providers::ProviderBuilder,
-    transports::http::reqwest::Url,
 };
-use eyre::Result;
+use eyre::{ensure, eyre, Result, WrapErr};
+use futures::future::try_join_all;
+use std::sync::Arc;
 
 /// Calls a function on an Ethereum smart contract.
 ///
 /// # Arguments
 ///
-/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
 /// * `abi` - The JSON ABI of the contract.
 /// * `contract_address` - The address of the contract.
 /// * `function_name` - The name of the function to call.
@@ -22,19 +25,207 @@ use eyre::Result;
 ///
 /// * `Result<Vec<DynSolValue>>` - The result of the function call on success.
 pub async fn call(
-    rpc_http: Url,
+    rpc_http: impl Into<ProviderConfig>,
     abi: JsonAbi,
     contract_address: Address,
     function_name: &str,
     args: &[DynSolValue],
 ) -> Result<Vec<DynSolValue>> {
+    call_with_cache(rpc_http, abi, contract_address, function_name, args, None).await
+}
+
+/// Calls a function described by a minimal JSON ABI, without loading the full ABI of the contract.
+///
+/// Handy to query the same function across many contracts implementing a common
+/// interface, e.g. `decimals()` on a list of ERC-20 tokens.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `contract_address` - The address of the contract.
+/// * `abi_fragment` - A JSON ABI array holding at least the entry of the function, e.g.
+///   `[{"type":"function","name":"decimals","inputs":[],"outputs":[{"name":"","type":"uint8"}],"stateMutability":"view"}]`.
+/// * `function_name` - The name of the function to call.
+/// * `args` - The arguments to pass to the function.
+///
+/// # Returns
+///
+/// * `Result<Vec<DynSolValue>>` - The result of the function call on success, or an error if
+///   the fragment is not a valid JSON ABI or lacks the function.
+pub async fn call_with_fragment(
+    rpc_http: impl Into<ProviderConfig>,
+    contract_address: Address,
+    abi_fragment: &str,
+    function_name: &str,
+    args: &[DynSolValue],
+) -> Result<Vec<DynSolValue>> {
+    let abi = JsonAbi::from_json_str(abi_fragment).wrap_err("invalid ABI fragment")?;
+    ensure!(
+        abi.function(function_name).is_some(),
+        "no function `{function_name}` in the ABI fragment"
+    );
+
+    call(rpc_http, abi, contract_address, function_name, args).await
+}
+
+/// Calls a function on an Ethereum smart contract, reusing a cached result when available.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `function_name` - The name of the function to call.
+/// * `args` - The arguments to pass to the function.
+/// * `cache` - The cache to read from and write to (optional).
+///
+/// # Returns
+///
+/// * `Result<Vec<DynSolValue>>` - The result of the function call on success.
+pub async fn call_with_cache(
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    function_name: &str,
+    args: &[DynSolValue],
+    cache: Option<Arc<CallCache>>,
+) -> Result<Vec<DynSolValue>> {
+    let config: ProviderConfig = rpc_http.into();
+    let interface = Interface::new(abi);
+
+    let calldata_hash = match &cache {
+        Some(cache) => {
+            let calldata_hash = keccak256(interface.encode_input(function_name, args)?);
+            if let Some(value) = cache.get(
+                &config.rpc_http,
+                contract_address,
+                function_name,
+                calldata_hash,
+            ) {
+                return Ok(value);
+            }
+            Some(calldata_hash)
+        }
+        None => None,
+    };
+
     let provider = ProviderBuilder::new()
         .with_recommended_fillers()
-        .on_http(rpc_http);
+        .on_client(config.client()?);
+
+    let contract = ContractInstance::new(contract_address, provider.clone(), interface);
 
-    let contract = ContractInstance::new(contract_address, provider.clone(), Interface::new(abi));
+    let mut call = contract.function(function_name, args)?;
+    if let Some(block) = cache.as_ref().and_then(|cache| cache.block()) {
+        call = call.block(BlockId::number(block));
+    }
+    let value = call.call().await?;
 
-    let value = contract.function(function_name, args)?.call().await?;
+    if let (Some(cache), Some(calldata_hash)) = (cache, calldata_hash) {
+        cache.insert(
+            &config.rpc_http,
+            contract_address,
+            function_name,
+            calldata_hash,
+            value.clone(),
+        );
+    }
 
     Ok(value)
 }
+
+/// Calls multiple functions on an Ethereum smart contract concurrently.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `calls` - The name and arguments of every function to call.
+/// * `cache` - The cache to read from and write to (optional).
+///
+/// # Returns
+///
+/// * `Result<Vec<Vec<DynSolValue>>>` - The result of every call, in the same order as `calls`.
+pub async fn call_many(
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    calls: &[(&str, Vec<DynSolValue>)],
+    cache: Option<Arc<CallCache>>,
+) -> Result<Vec<Vec<DynSolValue>>> {
+    let config = rpc_http.into();
+    try_join_all(calls.iter().map(|(function_name, args)| {
+        call_with_cache(
+            config.clone(),
+            abi.clone(),
+            contract_address,
+            function_name,
+            args,
+            cache.clone(),
+        )
+    }))
+    .await
+}
+
+/// Checks whether an account has already minted, by calling the contract's `hasMinted(address)`.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `account` - The address of the account to check.
+///
+/// # Returns
+///
+/// * `Result<bool>` - `true` if the account has minted on success.
+pub async fn has_minted(
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    account: Address,
+) -> Result<bool> {
+    let value = call(
+        rpc_http,
+        abi,
+        contract_address,
+        "hasMinted",
+        &[DynSolValue::from(account)],
+    )
+    .await?;
+
+    match value.first() {
+        Some(DynSolValue::Bool(minted)) => Ok(*minted),
+        _ => Err(eyre!("unexpected return value from `hasMinted`")),
+    }
+}
+
+/// Checks concurrently whether multiple accounts have already minted.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `accounts` - The addresses of the accounts to check.
+///
+/// # Returns
+///
+/// * `Result<Vec<(Address, bool)>>` - Every account with its status, in the same order as `accounts`.
+pub async fn has_minted_batch(
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    accounts: &[Address],
+) -> Result<Vec<(Address, bool)>> {
+    let config = rpc_http.into();
+    let minted = try_join_all(
+        accounts
+            .iter()
+            .map(|account| has_minted(config.clone(), abi.clone(), contract_address, *account)),
+    )
+    .await?;
+
+    Ok(accounts.iter().copied().zip(minted).collect())
+}
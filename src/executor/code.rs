@@ -0,0 +1,71 @@
+use crate::executor::ProviderConfig;
+use alloy::{
+    eips::BlockId,
+    primitives::{Address, Bytes},
+    providers::{Provider, ProviderBuilder},
+};
+use eyre::{ensure, Result};
+
+/// Gets the bytecode deployed at an address.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `address` - The address to get the code of.
+/// * `block` - The block to get the code at (optional, defaults to the latest block).
+///
+/// # Returns
+///
+/// * `Result<Bytes>` - The bytecode on success, empty for externally owned accounts.
+pub async fn get_code(
+    rpc_http: impl Into<ProviderConfig>,
+    address: Address,
+    block: Option<BlockId>,
+) -> Result<Bytes> {
+    let provider = ProviderBuilder::new().on_client(rpc_http.into().client()?);
+
+    let code = provider
+        .get_code_at(address)
+        .block_id(block.unwrap_or_default())
+        .await?;
+
+    Ok(code)
+}
+
+/// Checks whether code is deployed at an address.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `address` - The address to check.
+///
+/// # Returns
+///
+/// * `Result<bool>` - `true` if the address holds code, `false` for externally owned accounts.
+pub async fn is_contract(rpc_http: impl Into<ProviderConfig>, address: Address) -> Result<bool> {
+    let code = get_code(rpc_http, address, None).await?;
+
+    Ok(!code.is_empty())
+}
+
+/// Fails if no code is deployed at an address.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `address` - The address expected to be a contract.
+///
+/// # Returns
+///
+/// * `Result<()>` - An error if the address holds no code.
+pub(crate) async fn ensure_contract(
+    rpc_http: impl Into<ProviderConfig>,
+    address: Address,
+) -> Result<()> {
+    ensure!(
+        is_contract(rpc_http, address).await?,
+        "no contract deployed at {address}"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use crate::executor::{get_block, ProviderConfig};
+use alloy::{
+    eips::BlockId,
+    primitives::{utils::format_ether, U256},
+    providers::{Provider, ProviderBuilder},
+};
+use eyre::{eyre, Result};
+
+/// Estimates the cost of an amount of gas at the current fees, in Ether.
+///
+/// The cost is `gas × (base_fee + priority_fee)`, using the base fee of the latest block.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `gas` - The amount of gas.
+///
+/// # Returns
+///
+/// * `Result<String>` - The cost formatted in Ether, e.g. `"0.000021000000000000"`.
+pub async fn estimate_gas_cost_eth(
+    rpc_http: impl Into<ProviderConfig>,
+    gas: u64,
+) -> Result<String> {
+    let cost = estimate_gas_cost(rpc_http.into(), gas).await?;
+
+    Ok(format_ether(cost))
+}
+
+/// Estimates the cost of an amount of gas at the current fees, in US dollars.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `gas` - The amount of gas.
+/// * `eth_price_usd` - The price of one Ether in US dollars.
+///
+/// # Returns
+///
+/// * `Result<String>` - The cost formatted in dollars with two decimals, e.g. `"1.25"`.
+pub async fn estimate_gas_cost_usd(
+    rpc_http: impl Into<ProviderConfig>,
+    gas: u64,
+    eth_price_usd: f64,
+) -> Result<String> {
+    let cost = estimate_gas_cost(rpc_http.into(), gas).await?;
+
+    format_cost_usd(cost, eth_price_usd)
+}
+
+/// Estimates the cost of an amount of gas at the current fees, in wei.
+async fn estimate_gas_cost(config: ProviderConfig, gas: u64) -> Result<U256> {
+    let base_fee = get_block(config.clone(), BlockId::latest())
+        .await?
+        .base_fee_per_gas
+        .ok_or_else(|| eyre!("the latest block has no base fee"))?;
+
+    let provider = ProviderBuilder::new().on_client(config.client()?);
+    let priority_fee = provider.get_max_priority_fee_per_gas().await?;
+
+    Ok(gas_cost(gas, base_fee.try_into()?, priority_fee))
+}
+
+/// Returns `gas × (base_fee + priority_fee)`, in wei.
+fn gas_cost(gas: u64, base_fee: u128, priority_fee: u128) -> U256 {
+    U256::from(gas) * (U256::from(base_fee) + U256::from(priority_fee))
+}
+
+/// Converts a cost in wei to US dollars with two decimals.
+fn format_cost_usd(cost: U256, eth_price_usd: f64) -> Result<String> {
+    let eth: f64 = format_ether(cost).parse()?;
+
+    Ok(format!("{:.2}", eth * eth_price_usd))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GWEI: u128 = 1_000_000_000;
+
+    #[test]
+    fn test_gas_cost() {
+        // 21000 gas at 30 + 2 gwei
+        let cost = gas_cost(21_000, 30 * GWEI, 2 * GWEI);
+
+        assert_eq!(cost, U256::from(672_000_000_000_000u64));
+        assert_eq!(format_ether(cost), "0.000672000000000000");
+    }
+
+    #[test]
+    fn test_format_cost_usd() {
+        let cost = gas_cost(21_000, 30 * GWEI, 2 * GWEI);
+
+        assert_eq!(format_cost_usd(cost, 2500.0).unwrap(), "1.68");
+        assert_eq!(format_cost_usd(U256::ZERO, 2500.0).unwrap(), "0.00");
+    }
+}
@@ -0,0 +1,161 @@
+use crate::executor::{is_contract, ProviderConfig};
+use alloy::{
+    network::{EthereumWallet, TransactionBuilder},
+    primitives::{address, Address, Bytes, TxHash},
+    providers::{Provider, ProviderBuilder},
+    rpc::types::TransactionRequest,
+    signers::local::PrivateKeySigner,
+};
+use eyre::{ensure, eyre, Result};
+
+/// The address of the deterministic deployment proxy, the standard `CREATE2` factory.
+///
+/// It is deployed at the same address on most chains and preinstalled on Anvil. The factory
+/// takes `salt ++ init_code` as calldata.
+pub const CREATE2_FACTORY: Address = address!("4e59b44847b379578588920ca78fbf26c0b4956c");
+
+/// Represents the result of a contract deployment.
+///
+/// # Fields
+///
+/// * `address` - The address of the deployed contract.
+/// * `tx_hash` - The transaction hash of the deployment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeployResult {
+    pub address: Address,
+    pub tx_hash: TxHash,
+}
+
+/// Deploys a contract and waits for the receipt.
+///
+/// # Arguments
+///
+/// * `deployer` - The private key signer paying for the deployment.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `init_code` - The creation bytecode, with any ABI-encoded constructor arguments appended.
+///
+/// # Returns
+///
+/// * `Result<DeployResult>` - The address of the contract and the transaction hash on success.
+pub async fn deploy(
+    deployer: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    init_code: Bytes,
+) -> Result<DeployResult> {
+    let wallet = EthereumWallet::new(deployer);
+    let provider = ProviderBuilder::new()
+        .with_recommended_fillers()
+        .wallet(wallet)
+        .on_client(rpc_http.into().client()?);
+
+    let tx = TransactionRequest::default().with_deploy_code(init_code);
+    let receipt = provider.send_transaction(tx).await?.get_receipt().await?;
+    ensure!(
+        receipt.status(),
+        "deployment {} reverted",
+        receipt.transaction_hash
+    );
+
+    let address = receipt.contract_address.ok_or_else(|| {
+        eyre!(
+            "no contract address in receipt {}",
+            receipt.transaction_hash
+        )
+    })?;
+
+    Ok(DeployResult {
+        address,
+        tx_hash: receipt.transaction_hash,
+    })
+}
+
+/// Deploys a contract to a deterministic address through a `CREATE2` factory.
+///
+/// # Arguments
+///
+/// * `deployer` - The private key signer paying for the deployment.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `factory_address` - The address of the factory, e.g. [`CREATE2_FACTORY`].
+/// * `salt` - The salt of the deployment.
+/// * `init_code` - The creation bytecode, with any ABI-encoded constructor arguments appended.
+///
+/// # Returns
+///
+/// * `Result<DeployResult>` - The address of the contract, as given by [`compute_create2_address`],
+///   and the transaction hash on success.
+pub async fn create2_deploy(
+    deployer: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    factory_address: Address,
+    salt: [u8; 32],
+    init_code: Bytes,
+) -> Result<DeployResult> {
+    let config: ProviderConfig = rpc_http.into();
+    let address = compute_create2_address(factory_address, salt, &init_code);
+
+    let wallet = EthereumWallet::new(deployer);
+    let provider = ProviderBuilder::new()
+        .with_recommended_fillers()
+        .wallet(wallet)
+        .on_client(config.client()?);
+
+    let input: Bytes = [salt.as_slice(), &init_code].concat().into();
+    let tx = TransactionRequest::default()
+        .with_to(factory_address)
+        .with_input(input);
+    let receipt = provider.send_transaction(tx).await?.get_receipt().await?;
+    ensure!(
+        receipt.status(),
+        "deployment {} reverted",
+        receipt.transaction_hash
+    );
+
+    // guard against factories that do not revert when the deployment fails
+    ensure!(
+        is_contract(config, address).await?,
+        "no contract deployed at {address}"
+    );
+
+    Ok(DeployResult {
+        address,
+        tx_hash: receipt.transaction_hash,
+    })
+}
+
+/// Computes the address a `CREATE2` deployment will have, as defined by EIP-1014.
+///
+/// # Arguments
+///
+/// * `factory` - The address of the factory performing the deployment.
+/// * `salt` - The salt of the deployment.
+/// * `init_code` - The creation bytecode, with any ABI-encoded constructor arguments appended.
+///
+/// # Returns
+///
+/// * `Address` - The address of the contract.
+pub fn compute_create2_address(factory: Address, salt: [u8; 32], init_code: &Bytes) -> Address {
+    factory.create2_from_code(salt, init_code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::hex;
+
+    #[test]
+    fn test_compute_create2_address() {
+        // examples from EIP-1014
+        assert_eq!(
+            compute_create2_address(Address::ZERO, [0; 32], &Bytes::from_static(&[0x00])),
+            address!("4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38")
+        );
+
+        let factory = address!("00000000000000000000000000000000deadbeef");
+        let salt = hex!("00000000000000000000000000000000000000000000000000000000cafebabe");
+        let init_code = Bytes::from_static(&hex!("deadbeef"));
+        assert_eq!(
+            compute_create2_address(factory, salt, &init_code),
+            address!("60f3f640a8508fC6a86d45DF051962668E1e8AC7")
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use crate::executor::ProviderConfig;
+use alloy::{
+    dyn_abi::{DynSolValue, EventExt},
+    eips::BlockId,
+    json_abi::JsonAbi,
+    primitives::{Address, TxHash, B256},
+    providers::{Provider, ProviderBuilder},
+    rpc::types::{Filter, Log},
+};
+use eyre::{bail, ensure, eyre, Result};
+
+/// An event log decoded with a contract ABI.
+///
+/// # Fields
+///
+/// * `tx_hash` - The hash of the transaction that emitted the event.
+/// * `block_number` - The number of the block the event was emitted in.
+/// * `indexed` - The decoded indexed parameters, in declaration order.
+/// * `body` - The decoded non-indexed parameters, in declaration order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedEvent {
+    pub tx_hash: TxHash,
+    pub block_number: u64,
+    pub indexed: Vec<DynSolValue>,
+    pub body: Vec<DynSolValue>,
+}
+
+/// Queries and decodes the events of a contract over a block range.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `event_name` - The name of the event to query.
+/// * `from_block` - The first block to query.
+/// * `to_block` - The last block to query (optional, defaults to the latest block).
+///
+/// # Returns
+///
+/// * `Result<Vec<DecodedEvent>>` - The decoded events, in chain order.
+pub async fn query_events(
+    rpc_http: impl Into<ProviderConfig>,
+    abi: &JsonAbi,
+    contract_address: Address,
+    event_name: &str,
+    from_block: u64,
+    to_block: Option<u64>,
+) -> Result<Vec<DecodedEvent>> {
+    let event = abi
+        .event(event_name)
+        .and_then(|events| events.first())
+        .ok_or_else(|| eyre!("`{event_name}` event not found in the ABI"))?;
+
+    let mut filter = Filter::new()
+        .address(contract_address)
+        .event_signature(event.selector())
+        .from_block(from_block);
+    if let Some(to_block) = to_block {
+        filter = filter.to_block(to_block);
+    }
+
+    let provider = ProviderBuilder::new().on_client(rpc_http.into().client()?);
+    let logs = provider.get_logs(&filter).await?;
+
+    logs.into_iter()
+        .map(|log| {
+            let decoded = event.decode_log(log.data(), true)?;
+
+            Ok(DecodedEvent {
+                tx_hash: log.transaction_hash.unwrap_or_default(),
+                block_number: log.block_number.unwrap_or_default(),
+                indexed: decoded.indexed,
+                body: decoded.body,
+            })
+        })
+        .collect()
+}
+
+/// A filter of raw event logs, see [`get_logs`].
+///
+/// # Fields
+///
+/// * `from_block` - The first block to fetch logs from.
+/// * `to_block` - The last block to fetch logs from; a block hash is only accepted if it is
+///   also `from_block`, which fetches the logs of that single block.
+/// * `addresses` - The contracts that emitted the logs; empty matches any contract.
+/// * `topics` - Up to four topics, matched by position; `None` matches any topic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFilter {
+    pub from_block: BlockId,
+    pub to_block: BlockId,
+    pub addresses: Vec<Address>,
+    pub topics: Vec<Option<B256>>,
+}
+
+impl LogFilter {
+    /// Converts the filter into an `eth_getLogs` filter.
+    fn into_filter(self) -> Result<Filter> {
+        ensure!(
+            self.topics.len() <= 4,
+            "a log has at most 4 topics, got {}",
+            self.topics.len()
+        );
+
+        let mut filter = match (self.from_block, self.to_block) {
+            (BlockId::Number(from), BlockId::Number(to)) => {
+                Filter::new().from_block(from).to_block(to)
+            }
+            (BlockId::Hash(from), BlockId::Hash(to)) if from.block_hash == to.block_hash => {
+                Filter::new().at_block_hash(from.block_hash)
+            }
+            _ => bail!("a block hash range must start and end at the same block"),
+        };
+
+        if !self.addresses.is_empty() {
+            filter = filter.address(self.addresses);
+        }
+        for (position, topic) in self.topics.into_iter().enumerate() {
+            let Some(topic) = topic else {
+                continue;
+            };
+            filter = match position {
+                0 => filter.event_signature(topic),
+                1 => filter.topic1(topic),
+                2 => filter.topic2(topic),
+                _ => filter.topic3(topic),
+            };
+        }
+
+        Ok(filter)
+    }
+}
+
+/// Fetches the raw event logs matching a filter.
+///
+/// Unlike [`query_events`], logs are not decoded, so a single query can cover several
+/// contracts and events; decode them with the `decode_log` of the matching ABI event.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `filter` - The block range, addresses and topics to match.
+///
+/// # Returns
+///
+/// * `Result<Vec<Log>>` - The matching logs, in chain order.
+pub async fn get_logs(rpc_http: impl Into<ProviderConfig>, filter: LogFilter) -> Result<Vec<Log>> {
+    let filter = filter.into_filter()?;
+
+    let provider = ProviderBuilder::new().on_client(rpc_http.into().client()?);
+    let logs = provider.get_logs(&filter).await?;
+
+    Ok(logs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::eips::BlockNumberOrTag;
+
+    fn filter(topics: Vec<Option<B256>>) -> LogFilter {
+        LogFilter {
+            from_block: BlockId::Number(BlockNumberOrTag::Earliest),
+            to_block: BlockId::Number(BlockNumberOrTag::Latest),
+            addresses: Vec::new(),
+            topics,
+        }
+    }
+
+    #[test]
+    fn test_log_filter_topics() {
+        let topic = B256::repeat_byte(1);
+        let converted = filter(vec![None, None, Some(topic)]).into_filter().unwrap();
+
+        assert!(converted.topics[0].is_empty());
+        assert!(converted.topics[1].is_empty());
+        assert!(converted.topics[2].matches(&topic));
+
+        assert!(filter(vec![None; 5]).into_filter().is_err());
+    }
+
+    #[test]
+    fn test_log_filter_block_hash() {
+        let hash = |byte: u8| BlockId::hash(B256::repeat_byte(byte));
+        let mut single_block = filter(Vec::new());
+        single_block.from_block = hash(1);
+        single_block.to_block = hash(1);
+        assert_eq!(
+            single_block.clone().into_filter().unwrap().get_block_hash(),
+            Some(B256::repeat_byte(1))
+        );
+
+        single_block.to_block = hash(2);
+        assert!(single_block.into_filter().is_err());
+    }
+}
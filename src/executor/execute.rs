@@ -1,14 +1,80 @@
+use crate::executor::{assert_enough_balance, ensure_contract, GasPolicy, ProviderConfig};
 use alloy::{
-    contract::{ContractInstance, Interface},
+    contract::{ContractInstance, Error as ContractError, Interface},
     dyn_abi::DynSolValue,
+    eips::eip7702::SignedAuthorization,
     json_abi::JsonAbi,
-    network::{Ethereum, EthereumWallet},
+    network::{Ethereum, EthereumWallet, TransactionBuilder, TransactionBuilder7702},
     primitives::{Address, TxHash, U256},
-    providers::ProviderBuilder,
+    providers::{Provider, ProviderBuilder},
+    rpc::types::TransactionRequest,
     signers::local::PrivateKeySigner,
-    transports::http::{reqwest::Url, Client, Http},
+    transports::{
+        http::{Client, Http},
+        RpcError,
+    },
 };
-use eyre::Result;
+use eyre::{eyre, Report, Result, WrapErr};
+use std::{fmt, sync::Arc};
+
+/// A check run on a transaction right before it is sent, see [`ExecuteOptions::pre_hook`].
+///
+/// The hook receives the unsigned transaction request and may inspect it, e.g. to log it
+/// or to enforce a compliance rule; returning an error aborts the execution.
+#[derive(Clone)]
+pub struct PreHook(Arc<dyn Fn(&TransactionRequest) -> Result<()> + Send + Sync>);
+
+impl PreHook {
+    /// Creates a hook from a closure.
+    pub fn new(hook: impl Fn(&TransactionRequest) -> Result<()> + Send + Sync + 'static) -> Self {
+        Self(Arc::new(hook))
+    }
+}
+
+impl fmt::Debug for PreHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PreHook")
+    }
+}
+
+/// Marks an error raised while a transaction was sent, after the request had left, so the
+/// node may have accepted the transaction even though no answer came back.
+#[derive(Debug)]
+pub(crate) struct MaybeSent;
+
+impl fmt::Display for MaybeSent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the transaction may have been sent before the request failed")
+    }
+}
+
+/// Options of a contract execution.
+///
+/// # Fields
+///
+/// * `value` - The amount of Ether to send with the transaction (optional).
+/// * `gas` - How the gas price of the transaction is determined.
+/// * `authorization_list` - The signed EIP-7702 authorizations to attach (optional).
+/// * `check_contract` - Whether to fail before sending if no code is deployed at the contract address.
+/// * `nonce` - The nonce of the transaction (optional, defaults to the next nonce of the account).
+/// * `check_balance` - Whether to fail before sending if the account cannot pay for the value
+///   and the worst-case gas cost of the transaction, see [`assert_enough_balance`].
+/// * `pre_hook` - A check run on the transaction right before it is sent (optional). The
+///   request carries the sender, target, calldata and value, plus the nonce and fees set by
+///   these options; the fields left unset are filled in when the transaction is sent.
+/// * `confirmations` - The number of blocks, the one including the transaction counted, to
+///   wait for before it is reported as confirmed (optional, defaults to 1).
+#[derive(Debug, Clone, Default)]
+pub struct ExecuteOptions {
+    pub value: Option<U256>,
+    pub gas: GasPolicy,
+    pub authorization_list: Option<Vec<SignedAuthorization>>,
+    pub check_contract: bool,
+    pub nonce: Option<u64>,
+    pub check_balance: bool,
+    pub pre_hook: Option<PreHook>,
+    pub confirmations: Option<u64>,
+}
 
 /// Represents the result of a contract execution.
 ///
@@ -43,7 +109,7 @@ impl Execution {
 /// # Arguments
 ///
 /// * `account` - The private key signer of the account executing the transaction.
-/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
 /// * `abi` - The JSON ABI of the contract.
 /// * `contract_address` - The address of the contract.
 /// * `function_name` - The name of the function to execute.
@@ -55,30 +121,234 @@ impl Execution {
 /// * `Result<Execution>` - The result of the contract execution, containing the caller's address and the transaction hash.
 pub async fn execute(
     account: PrivateKeySigner,
-    rpc_http: Url,
+    rpc_http: impl Into<ProviderConfig>,
     abi: JsonAbi,
     contract_address: Address,
     function_name: &str,
     args: &[DynSolValue],
     value: Option<U256>,
 ) -> Result<Execution> {
+    let options = ExecuteOptions {
+        value,
+        ..Default::default()
+    };
+
+    execute_with_options(
+        account,
+        rpc_http,
+        abi,
+        contract_address,
+        function_name,
+        args,
+        options,
+    )
+    .await
+}
+
+/// Executes a function on an Ethereum smart contract, attaching an EIP-7702 authorization list.
+///
+/// # Arguments
+///
+/// * `account` - The private key signer of the account sponsoring the transaction.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `function_name` - The name of the function to execute.
+/// * `args` - The arguments to pass to the function.
+/// * `authorization_list` - The signed authorizations delegating accounts to contract code.
+///
+/// # Returns
+///
+/// * `Result<Execution>` - The result of the contract execution, containing the caller's address and the transaction hash.
+pub async fn execute_with_authorization(
+    account: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    function_name: &str,
+    args: &[DynSolValue],
+    authorization_list: Vec<SignedAuthorization>,
+) -> Result<Execution> {
+    let options = ExecuteOptions {
+        authorization_list: Some(authorization_list),
+        ..Default::default()
+    };
+
+    execute_with_options(
+        account,
+        rpc_http,
+        abi,
+        contract_address,
+        function_name,
+        args,
+        options,
+    )
+    .await
+}
+
+/// Executes a function on an Ethereum smart contract with custom options.
+///
+/// # Arguments
+///
+/// * `account` - The private key signer of the account executing the transaction.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `function_name` - The name of the function to execute.
+/// * `args` - The arguments to pass to the function.
+/// * `options` - The value, gas policy, nonce, authorization list and pre-checks of the transaction.
+///
+/// # Returns
+///
+/// * `Result<Execution>` - The result of the contract execution, containing the caller's address and the transaction hash.
+pub async fn execute_with_options(
+    account: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    function_name: &str,
+    args: &[DynSolValue],
+    options: ExecuteOptions,
+) -> Result<Execution> {
+    send(
+        account,
+        rpc_http.into(),
+        abi,
+        contract_address,
+        function_name,
+        args,
+        options,
+        true,
+    )
+    .await
+}
+
+/// Sends a contract call like [`execute_with_options`], without waiting for it to be confirmed.
+///
+/// # Arguments
+///
+/// * `account` - The private key signer of the account executing the transaction.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `function_name` - The name of the function to execute.
+/// * `args` - The arguments to pass to the function.
+/// * `options` - The value, gas policy, nonce, authorization list and pre-checks of the transaction.
+///
+/// # Returns
+///
+/// * `Result<Execution>` - The caller's address and the hash of the submitted transaction.
+pub(crate) async fn submit_with_options(
+    account: PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    function_name: &str,
+    args: &[DynSolValue],
+    options: ExecuteOptions,
+) -> Result<Execution> {
+    send(
+        account,
+        rpc_http.into(),
+        abi,
+        contract_address,
+        function_name,
+        args,
+        options,
+        false,
+    )
+    .await
+}
+
+/// Sends a contract call, waiting for it to be confirmed if `wait` is set.
+#[allow(clippy::too_many_arguments)]
+async fn send(
+    account: PrivateKeySigner,
+    config: ProviderConfig,
+    abi: JsonAbi,
+    contract_address: Address,
+    function_name: &str,
+    args: &[DynSolValue],
+    options: ExecuteOptions,
+    wait: bool,
+) -> Result<Execution> {
+    let ExecuteOptions {
+        value,
+        gas,
+        authorization_list,
+        check_contract,
+        nonce,
+        check_balance,
+        pre_hook,
+        confirmations,
+    } = options;
+
+    if check_contract {
+        ensure_contract(config.clone(), contract_address).await?;
+    }
+
     let caller = account.address();
     let wallet = EthereumWallet::new(account);
     let provider = ProviderBuilder::new()
         .with_recommended_fillers()
         .wallet(wallet)
-        .on_http(rpc_http);
+        .on_client(config.client()?);
 
     let contract: ContractInstance<Http<Client>, _, Ethereum> =
         ContractInstance::new(contract_address, provider.clone(), Interface::new(abi));
 
-    let tx_hash = contract
+    let mut call = contract
         .function(function_name, args)?
         .value(value.unwrap_or_default())
-        .send()
-        .await?
-        .watch()
-        .await?;
+        .map(|tx| gas.apply(tx));
+    if let Some(authorization_list) = authorization_list {
+        call = call.map(|tx| tx.with_authorization_list(authorization_list));
+    }
+    if let Some(nonce) = nonce {
+        call = call.nonce(nonce);
+    }
+
+    if check_balance {
+        // a value above the balance makes the gas estimation revert, so it is checked first
+        let value = value.unwrap_or_default();
+        assert_enough_balance(config.clone(), caller, value).await?;
+
+        let gas_limit = call.estimate_gas().await?;
+        let fee_per_gas = match gas {
+            GasPolicy::Auto => provider.estimate_eip1559_fees(None).await?.max_fee_per_gas,
+            GasPolicy::Eip1559 {
+                max_fee_per_gas, ..
+            } => max_fee_per_gas,
+            GasPolicy::Legacy { gas_price } => gas_price,
+        };
+        let required = U256::from(gas_limit)
+            .checked_mul(U256::from(fee_per_gas))
+            .and_then(|gas_cost| gas_cost.checked_add(value))
+            .ok_or_else(|| eyre!("the worst-case cost of the transaction overflows"))?;
+        assert_enough_balance(config, caller, required).await?;
+    }
+
+    if let Some(PreHook(hook)) = pre_hook {
+        let tx = call.clone().into_transaction_request().with_from(caller);
+        hook(&tx).wrap_err("the pre-execution hook rejected the transaction")?;
+    }
+
+    let pending = match call.send().await {
+        Ok(pending) => pending,
+        // the node did not answer, so it may still have received the transaction
+        Err(error @ ContractError::TransportError(RpcError::Transport(_))) => {
+            return Err(Report::from(error).wrap_err(MaybeSent))
+        }
+        Err(error) => return Err(error.into()),
+    };
+    let tx_hash = if wait {
+        pending
+            .with_required_confirmations(confirmations.unwrap_or(1))
+            .watch()
+            .await?
+    } else {
+        *pending.tx_hash()
+    };
 
     Ok(Execution::new(caller, tx_hash))
 }
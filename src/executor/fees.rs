@@ -0,0 +1,104 @@
+use crate::executor::{get_block, ProviderConfig};
+use alloy::{
+    eips::{BlockId, BlockNumberOrTag},
+    primitives::U256,
+    providers::{Provider, ProviderBuilder},
+};
+use eyre::{ensure, Result};
+
+/// The fees paid in a range of recent blocks, as returned by `eth_feeHistory`.
+///
+/// # Fields
+///
+/// * `oldest_block` - The number of the first block of the range.
+/// * `base_fees` - The base fee of every block of the range, in wei, followed by the base fee
+///   of the block after the newest one, so there is one more than there are blocks.
+/// * `gas_used_ratios` - The share of the gas limit used by every block of the range, from 0 to 1.
+/// * `rewards` - The priority fees at the requested percentiles of every block of the range,
+///   in wei; empty when no percentiles were requested.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeeHistory {
+    pub oldest_block: u64,
+    pub base_fees: Vec<U256>,
+    pub gas_used_ratios: Vec<f64>,
+    pub rewards: Vec<Vec<U256>>,
+}
+
+/// Gets the current gas price of legacy transactions.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+///
+/// # Returns
+///
+/// * `Result<U256>` - The gas price in wei on success.
+pub async fn get_gas_price(rpc_http: impl Into<ProviderConfig>) -> Result<U256> {
+    let provider = ProviderBuilder::new().on_client(rpc_http.into().client()?);
+
+    let gas_price = provider.get_gas_price().await?;
+
+    Ok(U256::from(gas_price))
+}
+
+/// Gets the base fees, gas usage and priority fees of the blocks up to `newest_block`.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `block_count` - The number of blocks of the range; nodes may return fewer.
+/// * `newest_block` - The last block of the range, e.g. `BlockId::latest()`.
+/// * `reward_percentiles` - The percentiles of the priority fees to report for every block,
+///   in ascending order between 0 and 100.
+///
+/// # Returns
+///
+/// * `Result<FeeHistory>` - The fee history on success, or an error if the percentiles are
+///   out of order or the block does not exist.
+pub async fn get_fee_history(
+    rpc_http: impl Into<ProviderConfig>,
+    block_count: u64,
+    newest_block: BlockId,
+    reward_percentiles: &[f64],
+) -> Result<FeeHistory> {
+    ensure!(
+        reward_percentiles
+            .iter()
+            .all(|percentile| (0.0..=100.0).contains(percentile)),
+        "reward percentiles must be between 0 and 100"
+    );
+    ensure!(
+        reward_percentiles.windows(2).all(|pair| pair[0] <= pair[1]),
+        "reward percentiles must be in ascending order"
+    );
+
+    let config: ProviderConfig = rpc_http.into();
+    // `eth_feeHistory` takes no block hash, so a hash is resolved to its number first
+    let newest_block = match newest_block {
+        BlockId::Number(newest_block) => newest_block,
+        BlockId::Hash(_) => {
+            BlockNumberOrTag::Number(get_block(config.clone(), newest_block).await?.number)
+        }
+    };
+    let provider = ProviderBuilder::new().on_client(config.client()?);
+
+    let history = provider
+        .get_fee_history(block_count, newest_block, reward_percentiles)
+        .await?;
+
+    Ok(FeeHistory {
+        oldest_block: history.oldest_block,
+        base_fees: history
+            .base_fee_per_gas
+            .into_iter()
+            .map(U256::from)
+            .collect(),
+        gas_used_ratios: history.gas_used_ratio,
+        rewards: history
+            .reward
+            .unwrap_or_default()
+            .into_iter()
+            .map(|rewards| rewards.into_iter().map(U256::from).collect())
+            .collect(),
+    })
+}
@@ -0,0 +1,114 @@
+use alloy::{network::TransactionBuilder, rpc::types::TransactionRequest};
+use eyre::{ensure, Result};
+
+/// How the gas price of a transaction is determined.
+///
+/// # Variants
+///
+/// * `Auto` - Let the provider estimate EIP-1559 fees.
+/// * `Eip1559` - Use fixed EIP-1559 fees, denominated in wei.
+/// * `Legacy` - Use a fixed legacy gas price, denominated in wei.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GasPolicy {
+    #[default]
+    Auto,
+    Eip1559 {
+        max_fee_per_gas: u128,
+        max_priority_fee_per_gas: u128,
+    },
+    Legacy {
+        gas_price: u128,
+    },
+}
+
+impl GasPolicy {
+    /// Checks that the policy is consistent.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - An error if the priority fee exceeds the max fee.
+    pub fn validate(&self) -> Result<()> {
+        if let Self::Eip1559 {
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+        } = self
+        {
+            ensure!(
+                max_priority_fee_per_gas <= max_fee_per_gas,
+                "max_priority_fee_per_gas ({max_priority_fee_per_gas}) exceeds max_fee_per_gas ({max_fee_per_gas})"
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Raises the fees of the policy by `percent`, e.g. to replace an underpriced transaction.
+    ///
+    /// Both EIP-1559 fees are raised, since nodes only accept a replacement when the max fee
+    /// and the priority fee both increase. `Auto` is returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `percent` - The increase, in percent of the current fees.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The bumped policy.
+    pub fn bump(&self, percent: u32) -> Self {
+        let bump = |fee: u128| fee.saturating_add(fee * percent as u128 / 100);
+
+        match *self {
+            Self::Auto => Self::Auto,
+            Self::Eip1559 {
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+            } => Self::Eip1559 {
+                max_fee_per_gas: bump(max_fee_per_gas),
+                max_priority_fee_per_gas: bump(max_priority_fee_per_gas),
+            },
+            Self::Legacy { gas_price } => Self::Legacy {
+                gas_price: bump(gas_price),
+            },
+        }
+    }
+
+    /// Applies the policy to a transaction request.
+    pub(crate) fn apply(&self, tx: TransactionRequest) -> TransactionRequest {
+        match *self {
+            Self::Auto => tx,
+            Self::Eip1559 {
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+            } => tx
+                .with_max_fee_per_gas(max_fee_per_gas)
+                .with_max_priority_fee_per_gas(max_priority_fee_per_gas),
+            Self::Legacy { gas_price } => tx.with_gas_price(gas_price),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bump() {
+        let policy = GasPolicy::Eip1559 {
+            max_fee_per_gas: 100,
+            max_priority_fee_per_gas: 10,
+        };
+
+        assert_eq!(
+            policy.bump(10),
+            GasPolicy::Eip1559 {
+                max_fee_per_gas: 110,
+                max_priority_fee_per_gas: 11,
+            }
+        );
+        assert_eq!(
+            GasPolicy::Legacy { gas_price: 50 }.bump(20),
+            GasPolicy::Legacy { gas_price: 60 }
+        );
+        assert_eq!(GasPolicy::Auto.bump(10), GasPolicy::Auto);
+    }
+}
@@ -1,5 +1,73 @@
 mod execute;
-pub use execute::{execute, Execution};
+pub use execute::{
+    execute, execute_with_authorization, execute_with_options, ExecuteOptions, Execution, PreHook,
+};
+pub(crate) use execute::{submit_with_options, MaybeSent};
+
+mod gas;
+pub use gas::GasPolicy;
+
+mod provider;
+pub use provider::{HttpAuth, ProviderConfig};
 
 mod caller;
-pub use caller::call;
+pub use caller::{
+    call, call_many, call_with_cache, call_with_fragment, has_minted, has_minted_batch,
+};
+
+mod cache;
+pub use cache::CallCache;
+
+mod signature;
+pub use signature::{sign_message, sign_typed_data, verify_signature, TypedData};
+
+mod authorization;
+pub use authorization::{sign_authorization, sign_authorizations};
+
+#[cfg(feature = "simulation")]
+mod simulate;
+#[cfg(feature = "simulation")]
+pub use simulate::{simulate_on_fork, PlannedCall, SimulatedTx, SimulationPlan};
+
+mod probe;
+pub use probe::{probe_erc_interface, ErcInterface};
+
+mod watch;
+pub use watch::{transaction_status, watch_pending_tx, TransactionStatus, TxStatus};
+
+mod calldata;
+pub use calldata::{build_calldata, decode_calldata};
+
+mod balance;
+pub use balance::{assert_enough_balance, InsufficientBalanceError};
+
+mod code;
+pub(crate) use code::ensure_contract;
+pub use code::{get_code, is_contract};
+
+mod block;
+pub use block::{get_block, BlockInfo};
+
+mod fees;
+pub use fees::{get_fee_history, get_gas_price, FeeHistory};
+
+mod nonce;
+pub use nonce::{get_nonce, get_pending_nonce, nonce_gap};
+
+mod events;
+pub use events::{get_logs, query_events, DecodedEvent, LogFilter};
+
+mod cost;
+pub use cost::{estimate_gas_cost_eth, estimate_gas_cost_usd};
+
+mod deploy;
+pub use deploy::{compute_create2_address, create2_deploy, deploy, DeployResult, CREATE2_FACTORY};
+
+mod storage;
+pub use storage::{read_mapping_slot, read_storage};
+
+mod transfer;
+pub use transfer::simulate_eth_transfer;
+
+mod offline;
+pub use offline::{broadcast_signed, sign_transaction, GasParams};
@@ -0,0 +1,67 @@
+use crate::executor::ProviderConfig;
+use alloy::{
+    primitives::Address,
+    providers::{Provider, ProviderBuilder},
+};
+use eyre::Result;
+
+/// Gets the nonce of an account in the latest block, counting its confirmed transactions.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `address` - The address of the account.
+///
+/// # Returns
+///
+/// * `Result<u64>` - The confirmed nonce on success.
+pub async fn get_nonce(rpc_http: impl Into<ProviderConfig>, address: Address) -> Result<u64> {
+    let provider = ProviderBuilder::new().on_client(rpc_http.into().client()?);
+
+    let nonce = provider.get_transaction_count(address).latest().await?;
+
+    Ok(nonce)
+}
+
+/// Gets the nonce of an account in the pending block, counting its transactions in the mempool.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `address` - The address of the account.
+///
+/// # Returns
+///
+/// * `Result<u64>` - The pending nonce on success, the nonce of the next transaction to send.
+pub async fn get_pending_nonce(
+    rpc_http: impl Into<ProviderConfig>,
+    address: Address,
+) -> Result<u64> {
+    let provider = ProviderBuilder::new().on_client(rpc_http.into().client()?);
+
+    let nonce = provider.get_transaction_count(address).pending().await?;
+
+    Ok(nonce)
+}
+
+/// Counts the transactions of an account waiting in the mempool.
+///
+/// A gap that does not shrink over several blocks points at stuck transactions, for
+/// instance underpriced ones, which hold back every later transaction of the account.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `address` - The address of the account.
+///
+/// # Returns
+///
+/// * `Result<u64>` - The pending nonce minus the confirmed nonce.
+pub async fn nonce_gap(rpc_http: impl Into<ProviderConfig>, address: Address) -> Result<u64> {
+    let config: ProviderConfig = rpc_http.into();
+    let confirmed = get_nonce(config.clone(), address).await?;
+    let pending = get_pending_nonce(config, address).await?;
+
+    // a block mined between both queries can make the pending nonce look behind
+    Ok(pending.saturating_sub(confirmed))
+}
@@ -0,0 +1,112 @@
+use crate::executor::ProviderConfig;
+use alloy::{
+    contract::Interface,
+    dyn_abi::DynSolValue,
+    eips::eip2718::Encodable2718,
+    json_abi::JsonAbi,
+    network::{EthereumWallet, TransactionBuilder},
+    primitives::{Address, Bytes, TxHash, U256},
+    providers::{Provider, ProviderBuilder},
+    rpc::types::TransactionRequest,
+    signers::local::PrivateKeySigner,
+};
+use eyre::Result;
+
+/// The gas settings of an offline-signed transaction.
+///
+/// # Fields
+///
+/// * `gas_limit` - The gas limit of the transaction; if `None`, it is estimated through the RPC
+///   endpoint, so signing is no longer fully offline.
+/// * `max_fee_per_gas` - The EIP-1559 max fee per gas, denominated in wei.
+/// * `max_priority_fee_per_gas` - The EIP-1559 max priority fee per gas, denominated in wei.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GasParams {
+    pub gas_limit: Option<u64>,
+    pub max_fee_per_gas: u128,
+    pub max_priority_fee_per_gas: u128,
+}
+
+/// Builds and signs a contract call locally, without sending it.
+///
+/// The nonce, chain ID and fees are taken as given, so with a `gas_limit` set no request is
+/// made to the RPC endpoint and the transaction can be signed on an air-gapped machine.
+///
+/// # Arguments
+///
+/// * `account` - The private key signer of the account signing the transaction.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials;
+///   only used to estimate the gas limit.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `function_name` - The name of the function to call.
+/// * `args` - The arguments to pass to the function.
+/// * `value` - The amount of Ether to send with the transaction (optional).
+/// * `nonce` - The nonce of the transaction.
+/// * `chain_id` - The chain ID of the network the transaction is for.
+/// * `gas` - The gas limit and fees of the transaction.
+///
+/// # Returns
+///
+/// * `Result<Bytes>` - The EIP-2718 encoded signed transaction, see [`broadcast_signed`].
+#[allow(clippy::too_many_arguments)]
+pub async fn sign_transaction(
+    account: &PrivateKeySigner,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: &JsonAbi,
+    contract_address: Address,
+    function_name: &str,
+    args: &[DynSolValue],
+    value: Option<U256>,
+    nonce: u64,
+    chain_id: u64,
+    gas: GasParams,
+) -> Result<Bytes> {
+    let input = Interface::new(abi.clone()).encode_input(function_name, args)?;
+    let tx = TransactionRequest::default()
+        .with_from(account.address())
+        .with_to(contract_address)
+        .with_input(input)
+        .with_value(value.unwrap_or_default())
+        .with_nonce(nonce)
+        .with_chain_id(chain_id)
+        .with_max_fee_per_gas(gas.max_fee_per_gas)
+        .with_max_priority_fee_per_gas(gas.max_priority_fee_per_gas);
+
+    let gas_limit = match gas.gas_limit {
+        Some(gas_limit) => gas_limit,
+        None => {
+            let provider = ProviderBuilder::new().on_client(rpc_http.into().client()?);
+            provider.estimate_gas(&tx).await?
+        }
+    };
+
+    let wallet = EthereumWallet::new(account.clone());
+    let envelope = tx.with_gas_limit(gas_limit).build(&wallet).await?;
+
+    Ok(envelope.encoded_2718().into())
+}
+
+/// Sends a transaction signed by [`sign_transaction`] and waits for it to be confirmed.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `signed_tx` - The EIP-2718 encoded signed transaction.
+///
+/// # Returns
+///
+/// * `Result<TxHash>` - The transaction hash.
+pub async fn broadcast_signed(
+    rpc_http: impl Into<ProviderConfig>,
+    signed_tx: &Bytes,
+) -> Result<TxHash> {
+    let provider = ProviderBuilder::new().on_client(rpc_http.into().client()?);
+    let tx_hash = provider
+        .send_raw_transaction(signed_tx)
+        .await?
+        .watch()
+        .await?;
+
+    Ok(tx_hash)
+}
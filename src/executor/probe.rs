@@ -0,0 +1,180 @@
+use crate::executor::{call, ProviderConfig};
+use alloy::{
+    dyn_abi::DynSolValue,
+    json_abi::JsonAbi,
+    primitives::{Address, B256},
+    providers::{Provider, ProviderBuilder},
+};
+use eyre::Result;
+
+/// EIP-165 interface ID of ERC-721.
+const ERC721_INTERFACE_ID: [u8; 4] = [0x80, 0xac, 0x58, 0xcd];
+/// EIP-165 interface ID of ERC-1155.
+const ERC1155_INTERFACE_ID: [u8; 4] = [0xd9, 0xb6, 0x7a, 0x26];
+/// Selector of `mint()`.
+const MINT_SELECTOR: [u8; 4] = [0x12, 0x49, 0xc5, 0x8b];
+/// Opcode of `PUSH1`; `PUSH1` to `PUSH32` push the 1 to 32 bytes that follow them.
+const PUSH1: u8 = 0x60;
+/// Opcode of `PUSH4`, which pushes the selectors compared by the function dispatcher.
+const PUSH4: u8 = 0x63;
+/// Opcode of `PUSH32`.
+const PUSH32: u8 = 0x7f;
+
+/// Human-readable ABI of the functions used for probing.
+const PROBE_ABI: [&str; 3] = [
+    "function supportsInterface(bytes4 interfaceId) view returns (bool)",
+    "function totalSupply() view returns (uint256)",
+    "function balanceOf(address account) view returns (uint256)",
+];
+
+/// The ERC interfaces detected on a contract.
+///
+/// # Fields
+///
+/// * `is_erc20` - Whether the contract behaves like an ERC-20 token.
+/// * `is_erc721` - Whether the contract reports ERC-721 support through EIP-165.
+/// * `is_erc1155` - Whether the contract reports ERC-1155 support through EIP-165.
+/// * `supports_mint` - Whether the contract bytecode dispatches a parameterless `mint()`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ErcInterface {
+    pub is_erc20: bool,
+    pub is_erc721: bool,
+    pub is_erc1155: bool,
+    pub supports_mint: bool,
+}
+
+/// Probes a contract for common ERC interfaces.
+///
+/// ERC-721 and ERC-1155 are detected through EIP-165 `supportsInterface`. ERC-20 has
+/// no interface ID in practice, so it is detected by probing `totalSupply` and `balanceOf`;
+/// `decimals` is optional in ERC-20 and not probed.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `contract_address` - The address of the contract.
+///
+/// # Returns
+///
+/// * `Result<ErcInterface>` - The detected interfaces on success.
+pub async fn probe_erc_interface(
+    rpc_http: impl Into<ProviderConfig>,
+    contract_address: Address,
+) -> Result<ErcInterface> {
+    let config: ProviderConfig = rpc_http.into();
+    let abi = JsonAbi::parse(PROBE_ABI)?;
+
+    let is_erc721 = supports_interface(&config, &abi, contract_address, ERC721_INTERFACE_ID).await;
+    let is_erc1155 =
+        supports_interface(&config, &abi, contract_address, ERC1155_INTERFACE_ID).await;
+
+    let is_erc20 = !is_erc721
+        && !is_erc1155
+        && responds(&config, &abi, contract_address, "totalSupply", &[]).await
+        && responds(
+            &config,
+            &abi,
+            contract_address,
+            "balanceOf",
+            &[DynSolValue::Address(Address::ZERO)],
+        )
+        .await;
+
+    let provider = ProviderBuilder::new().on_client(config.client()?);
+    let code = provider.get_code_at(contract_address).await?;
+    let supports_mint = dispatches(&code, MINT_SELECTOR);
+
+    Ok(ErcInterface {
+        is_erc20,
+        is_erc721,
+        is_erc1155,
+        supports_mint,
+    })
+}
+
+/// Returns `true` if `supportsInterface(interface_id)` returns `true`; reverts count as `false`.
+async fn supports_interface(
+    config: &ProviderConfig,
+    abi: &JsonAbi,
+    contract_address: Address,
+    interface_id: [u8; 4],
+) -> bool {
+    let args = [DynSolValue::FixedBytes(
+        B256::right_padding_from(&interface_id),
+        interface_id.len(),
+    )];
+
+    matches!(
+        call(
+            config.clone(),
+            abi.clone(),
+            contract_address,
+            "supportsInterface",
+            &args
+        )
+        .await
+        .as_deref(),
+        Ok([DynSolValue::Bool(true)])
+    )
+}
+
+/// Returns `true` if calling the view function with `args` succeeds.
+async fn responds(
+    config: &ProviderConfig,
+    abi: &JsonAbi,
+    contract_address: Address,
+    function_name: &str,
+    args: &[DynSolValue],
+) -> bool {
+    call(
+        config.clone(),
+        abi.clone(),
+        contract_address,
+        function_name,
+        args,
+    )
+    .await
+    .is_ok()
+}
+
+/// Returns `true` if the bytecode pushes `selector` with a `PUSH4`, as the function dispatcher does.
+///
+/// The bytecode is walked opcode by opcode, skipping the data of every `PUSH`, so the
+/// selector bytes showing up inside the data of another push do not count.
+fn dispatches(code: &[u8], selector: [u8; 4]) -> bool {
+    let mut pc = 0;
+    while let Some(&opcode) = code.get(pc) {
+        if opcode == PUSH4 && code.get(pc + 1..pc + 5) == Some(selector.as_slice()) {
+            return true;
+        }
+        pc += match opcode {
+            PUSH1..=PUSH32 => usize::from(opcode - PUSH1) + 2,
+            _ => 1,
+        };
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dispatches() {
+        // DUP1 PUSH4 mint() EQ
+        assert!(dispatches(
+            &[0x80, 0x63, 0x12, 0x49, 0xc5, 0x8b, 0x14],
+            MINT_SELECTOR
+        ));
+        // the selector inside the data of a PUSH32
+        let mut code = vec![PUSH32];
+        code.extend([0; 27]);
+        code.extend([PUSH4, 0x12, 0x49, 0xc5, 0x8b]);
+        assert!(!dispatches(&code, MINT_SELECTOR));
+        // the selector bytes without a PUSH4
+        assert!(!dispatches(&[0x12, 0x49, 0xc5, 0x8b], MINT_SELECTOR));
+        // a truncated PUSH4
+        assert!(!dispatches(&[PUSH4, 0x12, 0x49], MINT_SELECTOR));
+    }
+}
@@ -0,0 +1,178 @@
+use alloy::{
+    rpc::client::RpcClient,
+    transports::http::{
+        reqwest::{
+            header::{HeaderMap, HeaderValue, AUTHORIZATION},
+            Url,
+        },
+        Client, Http,
+    },
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use eyre::{ensure, Result};
+
+/// Credentials sent with every request to an RPC endpoint.
+///
+/// # Fields
+///
+/// * `basic` - The username and password of HTTP Basic authentication (optional).
+/// * `bearer` - The token of Bearer authentication (optional).
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct HttpAuth {
+    pub basic: Option<(String, String)>,
+    pub bearer: Option<String>,
+}
+
+impl HttpAuth {
+    /// Creates credentials for HTTP Basic authentication.
+    pub fn basic(username: impl Into<String>, password: impl Into<String>) -> Self {
+        Self {
+            basic: Some((username.into(), password.into())),
+            bearer: None,
+        }
+    }
+
+    /// Creates credentials for Bearer authentication.
+    pub fn bearer(token: impl Into<String>) -> Self {
+        Self {
+            basic: None,
+            bearer: Some(token.into()),
+        }
+    }
+
+    /// Builds the `Authorization` header of the credentials.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<HeaderValue>>` - The header, `None` without credentials, or an error if
+    ///   both schemes are set or the credentials are not valid header characters.
+    fn header(&self) -> Result<Option<HeaderValue>> {
+        ensure!(
+            self.basic.is_none() || self.bearer.is_none(),
+            "only one of basic and bearer authentication can be set"
+        );
+
+        let value = match (&self.basic, &self.bearer) {
+            (Some((username, password)), _) => {
+                format!(
+                    "Basic {}",
+                    STANDARD.encode(format!("{username}:{password}"))
+                )
+            }
+            (_, Some(token)) => format!("Bearer {token}"),
+            (None, None) => return Ok(None),
+        };
+
+        let mut header = HeaderValue::from_str(&value)?;
+        header.set_sensitive(true);
+
+        Ok(Some(header))
+    }
+}
+
+impl std::fmt::Debug for HttpAuth {
+    // keeps the credentials out of logs
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HttpAuth")
+            .field("basic", &self.basic.as_ref().map(|(username, _)| username))
+            .field("bearer", &self.bearer.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+/// How to connect to an RPC endpoint.
+///
+/// Functions taking `impl Into<ProviderConfig>` accept a plain [`Url`] as well, so
+/// configuring a connection is only needed for endpoints requiring authentication.
+///
+/// # Fields
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+/// * `auth` - The credentials sent with every request (optional).
+#[derive(Debug, Clone)]
+pub struct ProviderConfig {
+    pub rpc_http: Url,
+    pub auth: Option<HttpAuth>,
+}
+
+impl ProviderConfig {
+    /// Creates a configuration connecting to `rpc_http` without authentication.
+    pub fn new(rpc_http: Url) -> Self {
+        Self {
+            rpc_http,
+            auth: None,
+        }
+    }
+
+    /// Sets the credentials sent with every request.
+    pub fn with_auth(mut self, auth: HttpAuth) -> Self {
+        self.auth = Some(auth);
+        self
+    }
+
+    /// Builds the RPC client of the configuration, to pass to `ProviderBuilder::on_client`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<RpcClient<Http<Client>>>` - The client, or an error if the credentials are invalid.
+    pub(crate) fn client(&self) -> Result<RpcClient<Http<Client>>> {
+        let mut headers = HeaderMap::new();
+        if let Some(header) = self
+            .auth
+            .as_ref()
+            .map(HttpAuth::header)
+            .transpose()?
+            .flatten()
+        {
+            headers.insert(AUTHORIZATION, header);
+        }
+
+        let client = Client::builder().default_headers(headers).build()?;
+        let transport = Http::with_client(client, self.rpc_http.clone());
+        let is_local = transport.guess_local();
+
+        Ok(RpcClient::new(transport, is_local))
+    }
+}
+
+impl From<Url> for ProviderConfig {
+    fn from(rpc_http: Url) -> Self {
+        Self::new(rpc_http)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auth_header() {
+        let basic = HttpAuth::basic("Aladdin", "open sesame").header().unwrap();
+        assert_eq!(basic.unwrap(), "Basic QWxhZGRpbjpvcGVuIHNlc2FtZQ==");
+
+        let bearer = HttpAuth::bearer("token").header().unwrap();
+        assert_eq!(bearer.unwrap(), "Bearer token");
+
+        assert!(HttpAuth::default().header().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_auth_rejects_both_schemes() {
+        let auth = HttpAuth {
+            basic: Some(("user".to_string(), "password".to_string())),
+            bearer: Some("token".to_string()),
+        };
+
+        assert!(auth.header().is_err());
+    }
+
+    #[test]
+    fn test_auth_debug_redacts_secrets() {
+        let debug = format!("{:?}", HttpAuth::basic("user", "hunter2"));
+        assert!(debug.contains("user"));
+        assert!(!debug.contains("hunter2"));
+
+        let debug = format!("{:?}", HttpAuth::bearer("secret-token"));
+        assert!(!debug.contains("secret-token"));
+    }
+}
@@ -0,0 +1,220 @@
+use alloy::{
+    primitives::{Address, Bytes, PrimitiveSignature, B256, U256},
+    signers::{local::PrivateKeySigner, SignerSync},
+    sol_types::{Eip712Domain, SolStruct},
+};
+use eyre::Result;
+use std::borrow::Cow;
+
+/// Builder for an EIP-712 signing domain.
+///
+/// # Fields
+///
+/// * `name` - The user readable name of the signing domain.
+/// * `version` - The current major version of the signing domain.
+/// * `chain_id` - The chain ID the signature is valid on.
+/// * `verifying_contract` - The address of the contract that will verify the signature.
+#[derive(Debug, Clone, Default)]
+pub struct TypedData {
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub chain_id: Option<u64>,
+    pub verifying_contract: Option<Address>,
+}
+
+impl TypedData {
+    /// Creates a new, empty `TypedData` builder.
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name of the signing domain.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the version of the signing domain.
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    /// Sets the chain ID of the signing domain.
+    pub fn chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    /// Sets the verifying contract of the signing domain.
+    pub fn verifying_contract(mut self, verifying_contract: Address) -> Self {
+        self.verifying_contract = Some(verifying_contract);
+        self
+    }
+
+    /// Builds the `Eip712Domain` from the configured fields.
+    pub fn domain(&self) -> Eip712Domain {
+        Eip712Domain::new(
+            self.name.clone().map(Cow::Owned),
+            self.version.clone().map(Cow::Owned),
+            self.chain_id.map(U256::from),
+            self.verifying_contract,
+            None,
+        )
+    }
+
+    /// Computes the EIP-712 domain separator.
+    pub fn separator(&self) -> B256 {
+        self.domain().separator()
+    }
+}
+
+/// Signs a message following EIP-191 (`personal_sign`).
+///
+/// # Arguments
+///
+/// * `signer` - The private key signer used to sign the message.
+/// * `message` - The message to sign.
+///
+/// # Returns
+///
+/// * `Result<Bytes>` - The 65-byte `r || s || v` signature on success.
+pub fn sign_message(signer: &PrivateKeySigner, message: &str) -> Result<Bytes> {
+    let signature = signer.sign_message_sync(message.as_bytes())?;
+
+    Ok(Bytes::from(signature.as_bytes()))
+}
+
+/// Verifies that an EIP-191 signature over a message was produced by the expected address.
+///
+/// # Arguments
+///
+/// * `message` - The message that was signed.
+/// * `signature` - The 65-byte `r || s || v` signature.
+/// * `expected_address` - The address expected to have signed the message.
+///
+/// # Returns
+///
+/// * `Result<bool>` - `true` if the recovered signer matches `expected_address`, `false` otherwise.
+pub fn verify_signature(
+    message: &str,
+    signature: &Bytes,
+    expected_address: Address,
+) -> Result<bool> {
+    let signature = PrimitiveSignature::try_from(signature.as_ref())?;
+    let recovered = signature.recover_address_from_msg(message)?;
+
+    Ok(recovered == expected_address)
+}
+
+/// Signs structured data following EIP-712.
+///
+/// # Arguments
+///
+/// * `signer` - The private key signer used to sign the data.
+/// * `domain` - The EIP-712 signing domain, see [`TypedData`].
+/// * `type_data` - The structured data to sign.
+///
+/// # Returns
+///
+/// * `Result<Bytes>` - The 65-byte `r || s || v` signature on success.
+pub fn sign_typed_data<T: SolStruct>(
+    signer: &PrivateKeySigner,
+    domain: Eip712Domain,
+    type_data: &T,
+) -> Result<Bytes> {
+    let signature = signer.sign_typed_data_sync(type_data, &domain)?;
+
+    Ok(Bytes::from(signature.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::sol;
+
+    sol! {
+        struct Permit {
+            address owner;
+            address spender;
+            uint256 value;
+            uint256 nonce;
+            uint256 deadline;
+        }
+    }
+
+    const MESSAGE: &str = "stormint proof of ownership";
+
+    #[test]
+    fn test_sign_and_verify_message() {
+        let signer = PrivateKeySigner::random();
+        let signature = sign_message(&signer, MESSAGE).unwrap();
+
+        assert_eq!(signature.len(), 65);
+        assert!(verify_signature(MESSAGE, &signature, signer.address()).unwrap());
+    }
+
+    #[test]
+    fn test_verify_tampered_message() {
+        let signer = PrivateKeySigner::random();
+        let signature = sign_message(&signer, MESSAGE).unwrap();
+
+        assert!(
+            !verify_signature("stormint proof of 0wnership", &signature, signer.address()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_verify_wrong_address() {
+        let (signer, other) = (PrivateKeySigner::random(), PrivateKeySigner::random());
+        let signature = sign_message(&signer, MESSAGE).unwrap();
+
+        assert!(!verify_signature(MESSAGE, &signature, other.address()).unwrap());
+    }
+
+    #[test]
+    fn test_verify_malformed_signature() {
+        let signer = PrivateKeySigner::random();
+        let signature = Bytes::from(vec![0u8; 10]);
+
+        assert!(verify_signature(MESSAGE, &signature, signer.address()).is_err());
+    }
+
+    #[test]
+    fn test_sign_typed_data_permit() {
+        let signer = PrivateKeySigner::random();
+        let domain = TypedData::builder()
+            .name("Daram")
+            .version("1")
+            .chain_id(1)
+            .verifying_contract(Address::repeat_byte(0x11))
+            .domain();
+        let permit = Permit {
+            owner: signer.address(),
+            spender: Address::repeat_byte(0x22),
+            value: U256::from(1000),
+            nonce: U256::ZERO,
+            deadline: U256::MAX,
+        };
+
+        let signature = sign_typed_data(&signer, domain.clone(), &permit).unwrap();
+
+        let signature = PrimitiveSignature::try_from(signature.as_ref()).unwrap();
+        let hash = permit.eip712_signing_hash(&domain);
+        assert_eq!(
+            signature.recover_address_from_prehash(&hash).unwrap(),
+            signer.address()
+        );
+    }
+
+    #[test]
+    fn test_typed_data_separator() {
+        let typed_data = TypedData::builder().name("Daram").version("1").chain_id(1);
+
+        assert_eq!(typed_data.separator(), typed_data.domain().separator());
+        assert_ne!(
+            typed_data.separator(),
+            typed_data.clone().chain_id(2).separator()
+        );
+    }
+}
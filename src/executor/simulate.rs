@@ -0,0 +1,125 @@
+use crate::executor::execute;
+use alloy::{
+    dyn_abi::DynSolValue,
+    json_abi::JsonAbi,
+    primitives::{Address, TxHash, U256},
+    providers::{Provider, ProviderBuilder},
+    signers::local::PrivateKeySigner,
+    transports::http::reqwest::Url,
+};
+use alloy_node_bindings::Anvil;
+use eyre::{Report, Result};
+
+/// A contract call planned for a simulated run.
+///
+/// # Fields
+///
+/// * `signer` - The private key signer that will send the transaction.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `function_name` - The name of the function to execute.
+/// * `args` - The arguments to pass to the function.
+/// * `value` - The amount of Ether to send with the transaction (optional).
+#[derive(Debug, Clone)]
+pub struct PlannedCall {
+    pub signer: PrivateKeySigner,
+    pub abi: JsonAbi,
+    pub contract_address: Address,
+    pub function_name: String,
+    pub args: Vec<DynSolValue>,
+    pub value: Option<U256>,
+}
+
+/// The transactions to replay against a forked chain.
+///
+/// # Fields
+///
+/// * `calls` - The planned calls, replayed in order.
+/// * `fund_amount` - The balance every caller is given on the fork before replaying.
+#[derive(Debug, Clone)]
+pub struct SimulationPlan {
+    pub calls: Vec<PlannedCall>,
+    pub fund_amount: U256,
+}
+
+/// Represents the outcome of a simulated transaction.
+///
+/// # Fields
+///
+/// * `caller` - The address of the caller.
+/// * `function_name` - The name of the function that was executed.
+/// * `result` - The transaction hash on the fork on success, or the revert/error report on failure.
+#[derive(Debug)]
+pub struct SimulatedTx {
+    pub caller: Address,
+    pub function_name: String,
+    pub result: Result<TxHash, Report>,
+}
+
+impl SimulatedTx {
+    /// Returns `true` if the transaction was included on the fork without reverting.
+    pub fn succeeded(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+/// Replays a planned run against a local Anvil fork of the target chain.
+///
+/// Every caller in the plan is funded with `anvil_setBalance`, so nothing is spent or
+/// broadcast on the real network. Requires the `anvil` binary to be installed.
+///
+/// # Arguments
+///
+/// * `fork_rpc` - The HTTP URL of the RPC endpoint to fork from.
+/// * `block` - The block number to fork at (optional, defaults to the latest block).
+/// * `plan` - The calls to replay and the balance to fund callers with.
+///
+/// # Returns
+///
+/// * `Result<Vec<SimulatedTx>>` - The outcome of every planned call, in order.
+pub async fn simulate_on_fork(
+    fork_rpc: Url,
+    block: Option<u64>,
+    plan: SimulationPlan,
+) -> Result<Vec<SimulatedTx>> {
+    let mut anvil = Anvil::new().fork(fork_rpc.to_string());
+    if let Some(block) = block {
+        anvil = anvil.fork_block_number(block);
+    }
+    let anvil = anvil.try_spawn()?;
+    let url = anvil.endpoint_url();
+
+    // fund every caller on the fork
+    let provider = ProviderBuilder::new().on_http(url.clone());
+    for call in &plan.calls {
+        provider
+            .raw_request::<_, ()>(
+                "anvil_setBalance".into(),
+                (call.signer.address(), plan.fund_amount),
+            )
+            .await?;
+    }
+
+    let mut results = Vec::with_capacity(plan.calls.len());
+    for call in plan.calls {
+        let result = execute(
+            call.signer.clone(),
+            url.clone(),
+            call.abi,
+            call.contract_address,
+            &call.function_name,
+            &call.args,
+            call.value,
+        )
+        .await
+        .map(|execution| execution.tx_hash);
+
+        results.push(SimulatedTx {
+            caller: call.signer.address(),
+            function_name: call.function_name,
+            result,
+        });
+    }
+
+    Ok(results)
+}
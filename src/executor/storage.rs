@@ -0,0 +1,88 @@
+use crate::executor::ProviderConfig;
+use alloy::{
+    eips::BlockId,
+    primitives::{keccak256, Address, B256, U256},
+    providers::{Provider, ProviderBuilder},
+};
+use eyre::Result;
+
+/// Reads a raw storage slot of a contract.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `contract_address` - The address of the contract.
+/// * `slot` - The storage slot to read.
+/// * `block` - The block to read the storage at (optional, defaults to the latest block).
+///
+/// # Returns
+///
+/// * `Result<B256>` - The 32-byte value of the slot on success.
+pub async fn read_storage(
+    rpc_http: impl Into<ProviderConfig>,
+    contract_address: Address,
+    slot: U256,
+    block: Option<BlockId>,
+) -> Result<B256> {
+    let provider = ProviderBuilder::new().on_client(rpc_http.into().client()?);
+
+    let value = provider
+        .get_storage_at(contract_address, slot)
+        .block_id(block.unwrap_or_default())
+        .await?;
+
+    Ok(B256::from(value))
+}
+
+/// Reads the value stored under `key` in a Solidity `mapping(address => ...)`.
+///
+/// The slot of the entry is `keccak256(abi.encode(key, mapping_slot))`, following the
+/// Solidity storage layout.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `contract_address` - The address of the contract.
+/// * `mapping_slot` - The slot the mapping is declared at.
+/// * `key` - The key of the entry.
+///
+/// # Returns
+///
+/// * `Result<B256>` - The 32-byte value of the entry on success.
+pub async fn read_mapping_slot(
+    rpc_http: impl Into<ProviderConfig>,
+    contract_address: Address,
+    mapping_slot: u64,
+    key: Address,
+) -> Result<B256> {
+    let slot = mapping_entry_slot(mapping_slot, key);
+
+    read_storage(rpc_http, contract_address, U256::from(slot), None).await
+}
+
+/// Computes the storage slot of `key` in a mapping declared at `mapping_slot`.
+fn mapping_entry_slot(mapping_slot: u64, key: Address) -> B256 {
+    let mut encoded = [0u8; 64];
+    encoded[12..32].copy_from_slice(key.as_slice());
+    encoded[32..].copy_from_slice(&U256::from(mapping_slot).to_be_bytes::<32>());
+
+    keccak256(encoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::{dyn_abi::DynSolValue, primitives::address};
+
+    #[test]
+    fn test_mapping_entry_slot_matches_abi_encode() {
+        let key = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let encoded = DynSolValue::Tuple(vec![
+            DynSolValue::from(key),
+            DynSolValue::from(U256::from(3)),
+        ])
+        .abi_encode_params();
+
+        assert_eq!(mapping_entry_slot(3, key), keccak256(encoded));
+    }
+}
@@ -0,0 +1,49 @@
+use crate::executor::ProviderConfig;
+use alloy::{
+    network::TransactionBuilder,
+    primitives::{Address, U256},
+    providers::{Provider, ProviderBuilder},
+    rpc::types::TransactionRequest,
+    transports::RpcError,
+};
+use eyre::Result;
+
+/// Checks whether a plain Ether transfer would succeed, without sending it.
+///
+/// The transfer is run with `eth_call` from `from` to `to`, without calldata, against the
+/// latest block. It fails when the sender cannot cover `value`, or when the receiver is a
+/// contract that rejects Ether, e.g. one without a `receive` or payable `fallback` function,
+/// so it vets an unknown receiver before a distribution. Unlike `eth_estimateGas` it does not
+/// search for a gas limit, and it is no guarantee that the transfer fits in 21,000 gas.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `from` - The address of the sender.
+/// * `to` - The address of the receiver.
+/// * `value` - The amount of Ether to transfer, in wei.
+///
+/// # Returns
+///
+/// * `Result<bool>` - Whether the transfer would succeed, or an error if the endpoint could
+///   not be queried.
+pub async fn simulate_eth_transfer(
+    rpc_http: impl Into<ProviderConfig>,
+    from: Address,
+    to: Address,
+    value: U256,
+) -> Result<bool> {
+    let provider = ProviderBuilder::new().on_client(rpc_http.into().client()?);
+
+    let tx = TransactionRequest::default()
+        .with_from(from)
+        .with_to(to)
+        .with_value(value);
+
+    match provider.call(&tx).await {
+        Ok(_) => Ok(true),
+        // the node answered, so the transfer itself failed
+        Err(RpcError::ErrorResp(_)) => Ok(false),
+        Err(error) => Err(error.into()),
+    }
+}
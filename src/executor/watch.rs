@@ -0,0 +1,107 @@
+use crate::executor::ProviderConfig;
+use alloy::{
+    primitives::TxHash,
+    providers::{Provider, ProviderBuilder},
+    rpc::types::TransactionReceipt,
+};
+use eyre::Result;
+use std::time::{Duration, Instant};
+
+/// The status of a submitted transaction.
+///
+/// # Variants
+///
+/// * `Confirmed` - The transaction was included in a block, with its receipt.
+/// * `Dropped` - The node knows neither a receipt nor a pending transaction for the hash.
+/// * `TimedOut` - The transaction was still pending when the timeout elapsed.
+#[derive(Debug)]
+pub enum TxStatus {
+    Confirmed(Box<TransactionReceipt>),
+    Dropped,
+    TimedOut,
+}
+
+/// Polls a transaction until it is confirmed, dropped, or the timeout elapses.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `tx_hash` - The hash of the transaction to watch.
+/// * `poll_interval` - The delay between two polls.
+/// * `timeout` - The maximum time to wait for the transaction.
+///
+/// # Returns
+///
+/// * `Result<TxStatus>` - The status of the transaction on success.
+pub async fn watch_pending_tx(
+    rpc_http: impl Into<ProviderConfig>,
+    tx_hash: TxHash,
+    poll_interval: Duration,
+    timeout: Duration,
+) -> Result<TxStatus> {
+    let provider = ProviderBuilder::new().on_client(rpc_http.into().client()?);
+    let deadline = Instant::now() + timeout;
+
+    loop {
+        if let Some(receipt) = provider.get_transaction_receipt(tx_hash).await? {
+            return Ok(TxStatus::Confirmed(Box::new(receipt)));
+        }
+
+        if provider.get_transaction_by_hash(tx_hash).await?.is_none() {
+            return Ok(TxStatus::Dropped);
+        }
+
+        if Instant::now() + poll_interval > deadline {
+            return Ok(TxStatus::TimedOut);
+        }
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+/// A snapshot of the status of a transaction.
+///
+/// # Fields
+///
+/// * `confirmed` - Whether the transaction was included in a block.
+/// * `block_number` - The number of the block the transaction was included in, if confirmed.
+/// * `success` - Whether the transaction succeeded, if confirmed.
+/// * `gas_used` - The gas used by the transaction, if confirmed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransactionStatus {
+    pub confirmed: bool,
+    pub block_number: Option<u64>,
+    pub success: Option<bool>,
+    pub gas_used: Option<u64>,
+}
+
+/// Checks the status of a transaction once, without waiting for it.
+///
+/// Unlike [`watch_pending_tx`], a missing receipt is not polled for; it is reported as
+/// `confirmed: false`, whether the transaction is pending or unknown to the node.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `tx_hash` - The hash of the transaction to check.
+///
+/// # Returns
+///
+/// * `Result<TransactionStatus>` - The status of the transaction on success.
+pub async fn transaction_status(
+    rpc_http: impl Into<ProviderConfig>,
+    tx_hash: TxHash,
+) -> Result<TransactionStatus> {
+    let provider = ProviderBuilder::new().on_client(rpc_http.into().client()?);
+
+    let status = match provider.get_transaction_receipt(tx_hash).await? {
+        Some(receipt) => TransactionStatus {
+            confirmed: true,
+            block_number: receipt.block_number,
+            success: Some(receipt.status()),
+            gas_used: Some(receipt.gas_used),
+        },
+        None => TransactionStatus::default(),
+    };
+
+    Ok(status)
+}
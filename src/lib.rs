@@ -5,3 +5,5 @@ pub mod executor;
 pub mod distributor;
 
 pub mod mint;
+
+pub mod progress;
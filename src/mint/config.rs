@@ -0,0 +1,343 @@
+use crate::executor::GasPolicy;
+use eyre::{ensure, Result};
+use std::{ops::Range, time::Duration};
+
+/// Configuration of a mint run.
+///
+/// # Fields
+///
+/// * `max_concurrent` - The maximum number of mints in flight at the same time.
+/// * `retries` - The number of times a mint failing with a transient error is retried.
+/// * `retry_delay` - The delay before the first retry, doubled after every further retry.
+/// * `gas` - How the gas price of the mint transactions is determined.
+/// * `max_gas_bumps` - The number of times an underpriced mint is replaced with bumped fees.
+/// * `gas_bump_percent` - How much the fees are raised, in percent, on every replacement.
+/// * `spawn_strategy` - How the concurrent mints of a batch are scheduled.
+/// * `wave_delay` - The pause between two batches of concurrent mints, which spreads the
+///   transactions over more blocks and lets the RPC endpoint recover from the burst.
+/// * `rate_limit` - The maximum number of transactions submitted per second (optional),
+///   regardless of how long their confirmations take.
+/// * `per_mint_timeout` - The time after which a mint still unconfirmed is reported as failed
+///   (optional), retries included. The last transaction may still be mined afterwards, so the
+///   account does not mint again in the run.
+/// * `mints_per_account` - The number of times every signer mints, one mint after another, for
+///   contracts allowing several mints per address.
+/// * `launch_delay` - The range of the random delay staggering the start of every signer
+///   (optional). Each signer starts one delay after the previous one, on top of the
+///   concurrency cap, so the delays alone add up to `signers × launch_delay.end` in the
+///   worst case, e.g. when minting one signer at a time.
+/// * `launch_seed` - The seed of the random launch delays (optional), to reproduce a run.
+/// * `confirmations` - The number of blocks, the one including the mint counted, to wait for
+///   before a mint is reported as confirmed; more are safer on chains with frequent re-orgs.
+/// * `fail_fast` - Whether to stop starting mints once any mint of the run reverted, e.g. to
+///   probe a new contract with a first small batch. The mints not started yet are reported
+///   with a [`MintSkipped`](crate::mint::MintSkipped) error; transient failures do not count.
+/// * `simulate_first` - Whether to run every mint as an `eth_call` from its signer before
+///   sending it, so a mint that would revert, e.g. for an account already minted or not
+///   allowlisted, fails with the revert reason without broadcasting anything. Simulations
+///   wait for the rate limit like transactions.
+#[derive(Debug, Clone)]
+pub struct MintConfig {
+    pub max_concurrent: usize,
+    pub retries: u32,
+    pub retry_delay: Duration,
+    pub gas: GasPolicy,
+    pub max_gas_bumps: u32,
+    pub gas_bump_percent: u32,
+    pub spawn_strategy: SpawnStrategy,
+    pub wave_delay: Duration,
+    pub rate_limit: Option<f64>,
+    pub per_mint_timeout: Option<Duration>,
+    pub mints_per_account: u32,
+    pub launch_delay: Option<Range<Duration>>,
+    pub launch_seed: Option<u64>,
+    pub confirmations: u32,
+    pub fail_fast: bool,
+    pub simulate_first: bool,
+}
+
+/// How the concurrent mints of a batch are scheduled on the Tokio runtime.
+///
+/// With `JoinAll` every mint is polled by the calling task, so a large batch is driven by a
+/// single worker thread; `SpawnTasks` lets the multi-threaded runtime spread the mints
+/// across its workers, which pays off for batches of several hundred accounts.
+///
+/// # Variants
+///
+/// * `JoinAll` - Poll all mints of a batch on the current task with `join_all`.
+/// * `SpawnTasks` - Spawn a Tokio task for every mint of a batch.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SpawnStrategy {
+    #[default]
+    JoinAll,
+    SpawnTasks,
+}
+
+impl Default for MintConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent: 50,
+            retries: 2,
+            retry_delay: Duration::from_secs(1),
+            gas: GasPolicy::Auto,
+            max_gas_bumps: 3,
+            gas_bump_percent: 10,
+            spawn_strategy: SpawnStrategy::JoinAll,
+            wave_delay: Duration::ZERO,
+            rate_limit: None,
+            per_mint_timeout: None,
+            mints_per_account: 1,
+            launch_delay: None,
+            launch_seed: None,
+            confirmations: 1,
+            fail_fast: false,
+            simulate_first: false,
+        }
+    }
+}
+
+impl MintConfig {
+    /// Creates a `MintConfigBuilder` starting from the default configuration.
+    pub fn builder() -> MintConfigBuilder {
+        MintConfigBuilder::default()
+    }
+
+    /// Checks that the configuration is consistent.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - An error describing the first conflicting setting.
+    pub fn validate(&self) -> Result<()> {
+        ensure!(
+            self.max_concurrent > 0,
+            "max_concurrent must be greater than 0"
+        );
+        if let Some(rate_limit) = self.rate_limit {
+            ensure!(
+                rate_limit.is_finite() && rate_limit > 0.0,
+                "rate_limit must be a positive number of transactions per second"
+            );
+            // the interval between two submissions must fit in a `Duration`
+            ensure!(
+                Duration::try_from_secs_f64(1.0 / rate_limit).is_ok(),
+                "rate_limit of {rate_limit} transactions per second is too low"
+            );
+        }
+        ensure!(
+            self.mints_per_account > 0,
+            "mints_per_account must be greater than 0"
+        );
+        ensure!(
+            self.confirmations > 0,
+            "confirmations must be greater than 0"
+        );
+        ensure!(
+            self.per_mint_timeout
+                .is_none_or(|timeout| !timeout.is_zero()),
+            "per_mint_timeout must be greater than 0"
+        );
+        ensure!(
+            self.launch_delay
+                .as_ref()
+                .is_none_or(|launch_delay| !launch_delay.is_empty()),
+            "launch_delay must not be an empty range"
+        );
+        self.gas.validate()
+    }
+}
+
+/// Builder for `MintConfig`.
+#[derive(Debug, Clone, Default)]
+pub struct MintConfigBuilder {
+    config: MintConfig,
+}
+
+impl MintConfigBuilder {
+    /// Sets the maximum number of mints in flight at the same time.
+    pub fn max_concurrent(mut self, max_concurrent: usize) -> Self {
+        self.config.max_concurrent = max_concurrent;
+        self
+    }
+
+    /// Sets the number of retries of a failed mint and the delay before the first retry.
+    pub fn retry(mut self, retries: u32, retry_delay: Duration) -> Self {
+        self.config.retries = retries;
+        self.config.retry_delay = retry_delay;
+        self
+    }
+
+    /// Sets how the gas price of the mint transactions is determined.
+    pub fn gas(mut self, gas: GasPolicy) -> Self {
+        self.config.gas = gas;
+        self
+    }
+
+    /// Sets how many times an underpriced mint is replaced and by how much its fees are raised.
+    pub fn gas_bump(mut self, max_gas_bumps: u32, gas_bump_percent: u32) -> Self {
+        self.config.max_gas_bumps = max_gas_bumps;
+        self.config.gas_bump_percent = gas_bump_percent;
+        self
+    }
+
+    /// Sets how the concurrent mints of a batch are scheduled.
+    pub fn spawn_strategy(mut self, spawn_strategy: SpawnStrategy) -> Self {
+        self.config.spawn_strategy = spawn_strategy;
+        self
+    }
+
+    /// Sets the pause between two batches of concurrent mints.
+    pub fn wave_delay(mut self, wave_delay: Duration) -> Self {
+        self.config.wave_delay = wave_delay;
+        self
+    }
+
+    /// Sets the maximum number of transactions submitted per second.
+    pub fn rate_limit(mut self, per_second: f64) -> Self {
+        self.config.rate_limit = Some(per_second);
+        self
+    }
+
+    /// Sets the time after which a mint still unconfirmed is reported as failed.
+    pub fn per_mint_timeout(mut self, timeout: Duration) -> Self {
+        self.config.per_mint_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the number of times every signer mints.
+    pub fn mints_per_account(mut self, mints_per_account: u32) -> Self {
+        self.config.mints_per_account = mints_per_account;
+        self
+    }
+
+    /// Sets the range of the random delay staggering the start of every signer.
+    pub fn launch_delay(mut self, launch_delay: Range<Duration>) -> Self {
+        self.config.launch_delay = Some(launch_delay);
+        self
+    }
+
+    /// Sets the seed of the random launch delays, to reproduce a run.
+    pub fn launch_seed(mut self, launch_seed: u64) -> Self {
+        self.config.launch_seed = Some(launch_seed);
+        self
+    }
+
+    /// Sets the number of blocks to wait for before a mint is reported as confirmed.
+    pub fn confirmations(mut self, confirmations: u32) -> Self {
+        self.config.confirmations = confirmations;
+        self
+    }
+
+    /// Sets whether to stop starting mints once any mint of the run reverted.
+    pub fn fail_fast(mut self, fail_fast: bool) -> Self {
+        self.config.fail_fast = fail_fast;
+        self
+    }
+
+    /// Sets whether to simulate every mint before sending it.
+    pub fn simulate_first(mut self, simulate_first: bool) -> Self {
+        self.config.simulate_first = simulate_first;
+        self
+    }
+
+    /// Validates and builds the `MintConfig`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<MintConfig>` - The configuration, or an error if settings conflict.
+    pub fn build(self) -> Result<MintConfig> {
+        self.config.validate()?;
+        Ok(self.config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_defaults() {
+        let config = MintConfig::builder().build().unwrap();
+
+        assert_eq!(config.max_concurrent, 50);
+        assert_eq!(config.retries, 2);
+        assert_eq!(config.gas, GasPolicy::Auto);
+        assert_eq!(config.max_gas_bumps, 3);
+        assert_eq!(config.gas_bump_percent, 10);
+        assert_eq!(config.spawn_strategy, SpawnStrategy::JoinAll);
+        assert_eq!(config.wave_delay, Duration::ZERO);
+        assert_eq!(config.rate_limit, None);
+        assert_eq!(config.per_mint_timeout, None);
+        assert_eq!(config.mints_per_account, 1);
+        assert_eq!(config.launch_delay, None);
+        assert_eq!(config.launch_seed, None);
+        assert_eq!(config.confirmations, 1);
+        assert!(!config.fail_fast);
+        assert!(!config.simulate_first);
+    }
+
+    #[test]
+    fn test_builder_chaining() {
+        let config = MintConfig::builder()
+            .max_concurrent(10)
+            .retry(3, Duration::from_millis(500))
+            .gas(GasPolicy::Legacy { gas_price: 1 })
+            .gas_bump(5, 25)
+            .spawn_strategy(SpawnStrategy::SpawnTasks)
+            .wave_delay(Duration::from_millis(200))
+            .rate_limit(10.0)
+            .per_mint_timeout(Duration::from_secs(60))
+            .mints_per_account(3)
+            .launch_delay(Duration::from_millis(10)..Duration::from_millis(50))
+            .launch_seed(42)
+            .confirmations(3)
+            .fail_fast(true)
+            .simulate_first(true)
+            .build()
+            .unwrap();
+
+        assert_eq!(config.max_concurrent, 10);
+        assert_eq!(config.retries, 3);
+        assert_eq!(config.retry_delay, Duration::from_millis(500));
+        assert_eq!(config.gas, GasPolicy::Legacy { gas_price: 1 });
+        assert_eq!(config.max_gas_bumps, 5);
+        assert_eq!(config.gas_bump_percent, 25);
+        assert_eq!(config.spawn_strategy, SpawnStrategy::SpawnTasks);
+        assert_eq!(config.wave_delay, Duration::from_millis(200));
+        assert_eq!(config.rate_limit, Some(10.0));
+        assert_eq!(config.per_mint_timeout, Some(Duration::from_secs(60)));
+        assert_eq!(config.mints_per_account, 3);
+        assert_eq!(
+            config.launch_delay,
+            Some(Duration::from_millis(10)..Duration::from_millis(50))
+        );
+        assert_eq!(config.launch_seed, Some(42));
+        assert_eq!(config.confirmations, 3);
+        assert!(config.fail_fast);
+        assert!(config.simulate_first);
+    }
+
+    #[test]
+    fn test_builder_rejects_conflicts() {
+        assert!(MintConfig::builder().max_concurrent(0).build().is_err());
+        assert!(MintConfig::builder().rate_limit(0.0).build().is_err());
+        assert!(MintConfig::builder().rate_limit(f64::NAN).build().is_err());
+        assert!(MintConfig::builder().rate_limit(1e-20).build().is_err());
+        assert!(MintConfig::builder().mints_per_account(0).build().is_err());
+        assert!(MintConfig::builder().confirmations(0).build().is_err());
+        assert!(MintConfig::builder()
+            .launch_delay(Duration::from_millis(10)..Duration::from_millis(10))
+            .build()
+            .is_err());
+        assert!(MintConfig::builder()
+            .per_mint_timeout(Duration::ZERO)
+            .build()
+            .is_err());
+        assert!(MintConfig::builder()
+            .gas(GasPolicy::Eip1559 {
+                max_fee_per_gas: 1,
+                max_priority_fee_per_gas: 2,
+            })
+            .build()
+            .is_err());
+    }
+}
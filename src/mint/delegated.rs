@@ -0,0 +1,120 @@
+use crate::executor::{
+    execute_with_authorization, get_block, read_storage, sign_authorizations, ProviderConfig,
+};
+use alloy::{
+    contract::Interface,
+    dyn_abi::DynSolValue,
+    eips::BlockId,
+    json_abi::JsonAbi,
+    primitives::{keccak256, Address, TxHash, U256},
+    providers::{Provider, ProviderBuilder},
+    signers::{local::PrivateKeySigner, SignerSync},
+    sol_types::SolValue,
+};
+use eyre::Result;
+
+/// Name hashed into the storage slot of the intent nonce of a delegated account.
+const NONCE_SLOT_NAME: &str = "stormint.BatchExecutor.nonce";
+
+/// Time during which the signed intents of a sponsored mint stay valid, in seconds.
+const INTENT_TTL: u64 = 600;
+
+/// Mints tokens for multiple signers in a single sponsored EIP-7702 transaction.
+///
+/// Every signer delegates its account to the `BatchExecutor` contract and signs an intent
+/// for this exact mint, and the sponsor calls `executeFor` so that each delegated account
+/// performs the mint as `msg.sender`. The intents consume a nonce stored in every account and
+/// expire after ten minutes, so the delegation cannot be used for any other call.
+///
+/// # Arguments
+///
+/// * `sponsor` - The private key signer paying for the transaction; must not be one of `signers`.
+/// * `signers` - A vector of private key signers on whose behalf the mint is performed.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `executor_address` - The address of the deployed `BatchExecutor` contract.
+/// * `abi` - The JSON ABI of the mint contract.
+/// * `contract_address` - The address of the mint contract.
+/// * `function_name` - The name of the function to execute (optional, defaults to "mint").
+/// * `args` - The arguments to pass to the function (optional).
+///
+/// # Returns
+///
+/// * `Result<TxHash>` - The transaction hash of the sponsored transaction on success.
+#[allow(clippy::too_many_arguments)]
+pub async fn mint_delegated(
+    sponsor: PrivateKeySigner,
+    signers: Vec<PrivateKeySigner>,
+    rpc_http: impl Into<ProviderConfig>,
+    executor_address: Address,
+    abi: JsonAbi,
+    contract_address: Address,
+    function_name: Option<&str>,
+    args: Option<&[DynSolValue]>,
+) -> Result<TxHash> {
+    let config: ProviderConfig = rpc_http.into();
+    let function_name = function_name.unwrap_or("mint");
+    let calldata = Interface::new(abi).encode_input(function_name, args.unwrap_or_default())?;
+
+    let authorization_list =
+        sign_authorizations(&signers, config.clone(), executor_address).await?;
+
+    let provider = ProviderBuilder::new().on_client(config.client()?);
+    let chain_id = provider.get_chain_id().await?;
+    let deadline = get_block(config.clone(), BlockId::latest())
+        .await?
+        .timestamp
+        + INTENT_TTL;
+    let nonce_slot = U256::from_be_bytes(keccak256(NONCE_SLOT_NAME).0);
+    let calldata_hash = keccak256(&calldata);
+
+    let mut signatures = Vec::with_capacity(signers.len());
+    for signer in &signers {
+        let account = signer.address();
+        let nonce = read_storage(config.clone(), account, nonce_slot, None).await?;
+        // matches `BatchExecutor.intentHash`, signed with the EIP-191 prefix
+        let intent_hash = keccak256(
+            (
+                U256::from(chain_id),
+                executor_address,
+                account,
+                contract_address,
+                calldata_hash,
+                U256::from_be_bytes(nonce.0),
+                U256::from(deadline),
+            )
+                .abi_encode_params(),
+        );
+        let signature = signer.sign_message_sync(intent_hash.as_slice())?;
+        signatures.push(DynSolValue::Bytes(signature.as_bytes().to_vec()));
+    }
+
+    let accounts = signers
+        .iter()
+        .map(|signer| DynSolValue::from(signer.address()))
+        .collect();
+    let args = &[
+        DynSolValue::Array(accounts),
+        DynSolValue::from(contract_address),
+        DynSolValue::Bytes(calldata),
+        DynSolValue::from(U256::from(deadline)),
+        DynSolValue::Array(signatures),
+    ];
+
+    let executor_abi = JsonAbi::parse([
+        "function executeFor(address[] accounts, address target, bytes data, uint256 deadline, bytes[] signatures)",
+    ])?;
+
+    let tx_hash = execute_with_authorization(
+        sponsor,
+        config,
+        executor_abi,
+        executor_address,
+        "executeFor",
+        args,
+        authorization_list,
+    )
+    .await?
+    .tx_hash;
+
+    Ok(tx_hash)
+}
@@ -1,22 +1,63 @@
-use crate::executor::execute;
+use crate::executor::{
+    build_calldata, submit_with_options, ExecuteOptions, GasPolicy, MaybeSent, ProviderConfig,
+};
+use crate::mint::{
+    rate::{LaunchScheduler, RateLimiter},
+    MintArgs, MintConfig, MintOptions, MintValue, ResultFn, SpawnStrategy,
+};
+use crate::progress::{MintProgressBar, Progress, ProgressUpdate};
 use alloy::{
     dyn_abi::DynSolValue,
     json_abi::JsonAbi,
-    primitives::{Address, TxHash, U256},
+    network::TransactionBuilder,
+    primitives::{Address, TxHash, B256, U256},
+    providers::{PendingTransactionBuilder, Provider, ProviderBuilder},
+    rpc::types::TransactionRequest,
     signers::local::PrivateKeySigner,
-    transports::http::reqwest::Url,
+    sol_types::decode_revert_reason,
+};
+use eyre::{eyre, Report, Result};
+use futures::{channel::mpsc, future::join_all, stream, Stream, StreamExt};
+use std::{
+    collections::HashMap,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
 };
-use eyre::{Report, Result};
 
 /// Represents the result of a mint operation.
 ///
 /// # Fields
 ///
 /// * `signer` - The address of the signer who performed the mint operation.
-/// * `result` - The result of the mint operation, containing either the transaction hash on success or an error report on failure.
+/// * `mint_index` - The position of the mint among those of its signer, 0 for the first,
+///   when a run mints several times per account.
+/// * `nonce` - The pending nonce of the signer before the last attempt, if it could be fetched.
+/// * `attempts` - The number of transactions sent, retries and fee bumps included; 0 if the
+///   mint was never attempted.
+/// * `result` - The result of the mint operation, containing either the transaction hash on success or the error of the last attempt on failure.
+///
+/// With the `serde` feature, `result` is written as `{"status": "ok", "tx_hash": "0x..."}` or
+/// `{"status": "error", "message": "..."}`; a deserialized error only keeps its message.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MintResult {
     pub signer: Address,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub mint_index: u32,
+    pub nonce: Option<u64>,
+    pub attempts: u32,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "outcome::serialize_result",
+            deserialize_with = "outcome::deserialize_result"
+        )
+    )]
     pub result: Result<TxHash, Report>,
 }
 
@@ -26,22 +67,292 @@ impl MintResult {
     /// # Arguments
     ///
     /// * `signer` - The address of the signer who performed the mint operation.
+    /// * `nonce` - The nonce used by the mint operation, if known.
+    /// * `attempts` - The number of transactions sent.
     /// * `tx` - The result of the mint operation, containing either the transaction hash on success or an error report on failure.
     ///
     /// # Returns
     ///
     /// * `Self` - A new `MintResult` instance.
-    fn new(signer: Address, tx: Result<TxHash, Report>) -> Self {
-        Self { signer, result: tx }
+    fn new(signer: Address, nonce: Option<u64>, attempts: u32, tx: Result<TxHash, Report>) -> Self {
+        Self {
+            signer,
+            mint_index: 0,
+            nonce,
+            attempts,
+            result: tx,
+        }
+    }
+
+    /// Whether the mint was skipped because the run was cancelled before it started.
+    pub fn is_cancelled(&self) -> bool {
+        matches!(&self.result, Err(error) if error.is::<MintCancelled>())
+    }
+
+    /// Whether the mint was skipped because an earlier mint of a fail-fast run reverted.
+    pub fn is_skipped(&self) -> bool {
+        matches!(&self.result, Err(error) if error.is::<MintSkipped>())
+    }
+
+    /// Whether the mint was still unconfirmed when [`MintConfig::per_mint_timeout`] elapsed.
+    pub fn is_timed_out(&self) -> bool {
+        matches!(&self.result, Err(error) if error.is::<MintTimedOut>())
+    }
+}
+
+/// Error of a mint skipped because its run was cancelled before the mint started.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MintCancelled;
+
+impl fmt::Display for MintCancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "mint cancelled before it started")
+    }
+}
+
+impl std::error::Error for MintCancelled {}
+
+/// Error of a mint skipped because an earlier mint of its run reverted, with
+/// [`MintConfig::fail_fast`] set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MintSkipped;
+
+impl fmt::Display for MintSkipped {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "mint skipped after an earlier mint reverted")
+    }
+}
+
+impl std::error::Error for MintSkipped {}
+
+/// Error of a mint still unconfirmed when [`MintConfig::per_mint_timeout`] elapsed. Its
+/// transaction may still be mined, so the account does not mint again in the run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MintTimedOut {
+    pub timeout: Duration,
+}
+
+impl fmt::Display for MintTimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "mint timed out after {:?}", self.timeout)
+    }
+}
+
+impl std::error::Error for MintTimedOut {}
+
+/// (De)serializes the result of a mint as an object tagged with its status.
+#[cfg(feature = "serde")]
+mod outcome {
+    use alloy::primitives::TxHash;
+    use eyre::{eyre, Report};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(tag = "status", rename_all = "lowercase")]
+    enum Outcome {
+        Ok { tx_hash: TxHash },
+        Error { message: String },
+    }
+
+    pub(super) fn serialize_result<S: Serializer>(
+        result: &Result<TxHash, Report>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let outcome = match result {
+            Ok(tx_hash) => Outcome::Ok { tx_hash: *tx_hash },
+            // the alternate format keeps the causes of the error
+            Err(error) => Outcome::Error {
+                message: format!("{error:#}"),
+            },
+        };
+
+        outcome.serialize(serializer)
+    }
+
+    pub(super) fn deserialize_result<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Result<TxHash, Report>, D::Error> {
+        Ok(match Outcome::deserialize(deserializer)? {
+            Outcome::Ok { tx_hash } => Ok(tx_hash),
+            Outcome::Error { message } => Err(eyre!(message)),
+        })
+    }
+}
+
+/// The progress of a mint run, reported after every completed mint.
+///
+/// # Fields
+///
+/// * `completed` - The number of mints completed so far, successful or not.
+/// * `total` - The total number of mints of the run.
+/// * `succeeded` - The number of mints that succeeded so far.
+/// * `failed` - The number of mints that failed so far.
+/// * `skipped` - The number of mints never sent because an earlier mint of their account
+///   failed for good, e.g. once its allowance was exhausted; they count as completed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MintProgress {
+    pub completed: usize,
+    pub total: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub skipped: usize,
+}
+
+impl MintProgress {
+    /// Counts a completed mint, `None` for a mint its account never sent.
+    pub(crate) fn record(&mut self, success: Option<bool>) {
+        self.completed += 1;
+        match success {
+            Some(true) => self.succeeded += 1,
+            Some(false) => self.failed += 1,
+            None => self.skipped += 1,
+        }
+    }
+}
+
+impl ProgressUpdate for MintProgress {
+    fn position(&self) -> u64 {
+        self.completed as u64
+    }
+
+    fn message(&self) -> String {
+        format!("✅ {} / ❌ {}", self.succeeded, self.failed)
+    }
+}
+
+/// Describes the mint call shared by every signer of a run.
+///
+/// # Fields
+///
+/// * `provider` - How to connect to the Ethereum RPC endpoint.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `function_name` - The name of the function to execute.
+/// * `args` - The arguments to pass to the function, shared or per signer.
+/// * `value` - The amount of Ether to send with the transaction, shared or per signer (optional).
+/// * `rate_limiter` - The limiter every submission waits for (optional).
+/// * `launcher` - The scheduler every signer waits for before its first mint (optional).
+/// * `on_result` - Called with every result as soon as its mint resolves (optional).
+/// * `cancel` - The flag cancelling the mints not started yet once set (optional).
+/// * `halted` - Set once a mint reverted in a fail-fast run, to skip the mints not started yet.
+/// * `sink` - Where every result is sent as soon as its mint completes, instead of being
+///   collected (optional).
+/// * `completions` - Where the outcome of every mint is sent as soon as it completes, or
+///   `None` for the mints an account gave up on, to report the progress of the run (optional).
+struct MintCall {
+    provider: ProviderConfig,
+    abi: JsonAbi,
+    contract_address: Address,
+    function_name: String,
+    args: MintArgs,
+    value: Option<MintValue>,
+    rate_limiter: Option<RateLimiter>,
+    launcher: Option<LaunchScheduler>,
+    on_result: Option<ResultFn>,
+    cancel: Option<Arc<AtomicBool>>,
+    halted: AtomicBool,
+    sink: Option<mpsc::UnboundedSender<MintResult>>,
+    completions: Option<mpsc::UnboundedSender<Option<bool>>>,
+}
+
+impl MintCall {
+    /// Creates a new `MintCall`, defaulting to a parameterless `mint` function.
+    fn new(
+        provider: ProviderConfig,
+        abi: JsonAbi,
+        contract_address: Address,
+        function_name: Option<&str>,
+        args: Option<&[DynSolValue]>,
+        value: Option<U256>,
+    ) -> Self {
+        Self {
+            provider,
+            abi,
+            contract_address,
+            function_name: function_name.unwrap_or("mint").to_string(),
+            args: MintArgs::Shared(args.unwrap_or_default().to_vec()),
+            value: value.map(MintValue::Shared),
+            rate_limiter: None,
+            launcher: None,
+            on_result: None,
+            cancel: None,
+            halted: AtomicBool::new(false),
+            sink: None,
+            completions: None,
+        }
+    }
+
+    /// Whether the run was cancelled, so no new mint should start.
+    fn is_cancelled(&self) -> bool {
+        self.cancel
+            .as_ref()
+            .is_some_and(|cancel| cancel.load(Ordering::Relaxed))
+    }
+
+    /// Whether no new mint should start, because the run was cancelled or halted.
+    fn is_stopped(&self) -> bool {
+        self.is_cancelled() || self.halted.load(Ordering::Relaxed)
+    }
+
+    /// Passes a result to the `on_result` callback of the run, if any, containing its panics.
+    fn notify(&self, result: &MintResult) {
+        let Some(on_result) = &self.on_result else {
+            return;
+        };
+
+        // the panic hook already reported the panic, the run just goes on
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| on_result(result)));
+    }
+
+    /// Counts a completed mint in the progress of the run, if it is reported.
+    fn report(&self, result: &MintResult) {
+        if let Some(completions) = &self.completions {
+            // fails only once the run returned, with no progress left to report
+            let _ = completions.unbounded_send(Some(result.result.is_ok()));
+        }
+    }
+
+    /// Counts the mints an account will never send in the progress of the run, if it is
+    /// reported, so that the progress still reaches its total.
+    fn report_skipped(&self, count: u32) {
+        if let Some(completions) = &self.completions {
+            for _ in 0..count {
+                // fails only once the run returned, with no progress left to report
+                let _ = completions.unbounded_send(None);
+            }
+        }
+    }
+
+    /// Sends a result to the sink of the run, or hands it back to be collected without one.
+    fn deliver(&self, result: MintResult) -> Option<MintResult> {
+        match &self.sink {
+            Some(sink) => {
+                // fails only once the stream was dropped, with nobody left to read the result
+                let _ = sink.unbounded_send(result);
+                None
+            }
+            None => Some(result),
+        }
+    }
+
+    /// Whether the results of the run are no longer read, so no new mint should start.
+    fn is_abandoned(&self) -> bool {
+        self.sink.as_ref().is_some_and(|sink| sink.is_closed())
     }
 }
 
 /// Mints tokens in a loop for multiple signers.
 ///
+/// Mints are sent one after another. A mint failing with a transient error, such as a
+/// dropped connection or a rate-limited request, is retried with the backoff of the default
+/// [`MintConfig`]; contract reverts are never retried.
+///
+/// No progress is drawn; use [`mint_loop_with_progress`] to follow the run.
+///
 /// # Arguments
 ///
 /// * `signers` - A vector of private key signers who will perform the mint operations.
-/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
 /// * `abi` - The JSON ABI of the contract.
 /// * `contract_address` - The address of the contract.
 /// * `function_name` - The name of the function to execute (optional, defaults to "mint").
@@ -51,72 +362,918 @@ impl MintResult {
 /// # Returns
 ///
 /// * `Result<Vec<MintResult>>` - A vector of `MintResult` containing the results of the mint operations.
+#[deprecated(note = "use `mint_loop_with` and `MintOptions` instead")]
 pub async fn mint_loop(
     signers: Vec<PrivateKeySigner>,
-    rpc_http: Url,
+    rpc_http: impl Into<ProviderConfig>,
     abi: JsonAbi,
     contract_address: Address,
     function_name: Option<&str>,
     args: Option<&[DynSolValue]>,
     value: Option<U256>,
 ) -> Result<Vec<MintResult>> {
+    let options = MintOptions {
+        function_name: function_name.unwrap_or("mint").to_string(),
+        args: MintArgs::Shared(args.unwrap_or_default().to_vec()),
+        value: value.map(MintValue::Shared),
+        config: MintConfig {
+            max_concurrent: 1,
+            ..Default::default()
+        },
+        progress: false,
+        on_result: None,
+        cancel: None,
+    };
+
+    mint_loop_with(signers, rpc_http, abi, contract_address, options).await
+}
+
+/// Mints tokens for multiple signers as described by `options`.
+///
+/// Signers are minted in batches of `options.config.max_concurrent`, every batch
+/// concurrently, with the retries, gas settings and timeout of `options.config`; see
+/// [`mint_loop_from_stream`] for how a run is scheduled. Every signer mints
+/// `options.config.mints_per_account` times in a row, and stops at its first mint failing
+/// for good, e.g. when its allowance is exhausted. Per-signer arguments must match
+/// `signers` one to one, and so must per-signer values, otherwise the run fails before any
+/// transaction is sent.
+///
+/// # Arguments
+///
+/// * `signers` - A vector of private key signers who will perform the mint operations.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `options` - The mint call and the settings of the run, see [`MintOptions::builder`].
+///
+/// # Returns
+///
+/// * `Result<Vec<MintResult>>` - A vector of `MintResult`, grouped by signer in the order of
+///   `signers`, with up to `options.config.mints_per_account` results per signer.
+pub async fn mint_loop_with(
+    signers: Vec<PrivateKeySigner>,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    options: MintOptions,
+) -> Result<Vec<MintResult>> {
+    mint_loop_with_progress(signers, rpc_http, abi, contract_address, options, None).await
+}
+
+/// Mints tokens for multiple signers as described by `options`, reporting every completed mint.
+///
+/// Works like [`mint_loop_with`]; `progress` additionally receives a [`MintProgress`] each
+/// time a mint completes, successful or not, so it is called exactly once per mint. With
+/// `options.progress`, the bars on stderr are drawn as well.
+///
+/// # Arguments
+///
+/// * `signers` - A vector of private key signers who will perform the mint operations.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `options` - The mint call and the settings of the run, see [`MintOptions::builder`].
+/// * `progress` - Receives a [`MintProgress`] after every completed mint (optional, `None` is
+///   silent).
+///
+/// # Returns
+///
+/// * `Result<Vec<MintResult>>` - A vector of `MintResult`, grouped by signer in the order of
+///   `signers`, with up to `options.config.mints_per_account` results per signer.
+pub async fn mint_loop_with_progress(
+    signers: Vec<PrivateKeySigner>,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    options: MintOptions,
+    progress: Option<&dyn Progress<MintProgress>>,
+) -> Result<Vec<MintResult>> {
+    options.config.validate()?;
+    options.args.validate(signers.len())?;
+    if let Some(value) = &options.value {
+        value.validate(signers.len())?;
+    }
+    let (completions, mut completed) = mpsc::unbounded();
+    let call = Arc::new(MintCall {
+        provider: rpc_http.into(),
+        abi,
+        contract_address,
+        function_name: options.function_name,
+        args: options.args,
+        value: options.value,
+        rate_limiter: options.config.rate_limit.map(RateLimiter::new),
+        launcher: launcher(&options.config),
+        on_result: options.on_result,
+        cancel: options.cancel,
+        halted: AtomicBool::new(false),
+        sink: None,
+        completions: Some(completions),
+    });
+
+    let mut state = MintProgress {
+        total: signers.len() * options.config.mints_per_account as usize,
+        ..Default::default()
+    };
+    let bars = if options.progress {
+        let bars = MintProgressBar::new(state.total as u64)?;
+        bars.update(&state);
+        Some(bars)
+    } else {
+        None
+    };
+    let reporters: Vec<&dyn Progress<MintProgress>> = bars
+        .iter()
+        .map(|bars| bars as &dyn Progress<MintProgress>)
+        .chain(progress)
+        .collect();
+
+    // the updates end when the run returns and drops the last clone of `call`
+    let config = options.config;
+    let run = async move { mint_waves(stream::iter(signers), &call, &config).await };
+    let report = async {
+        while let Some(success) = completed.next().await {
+            state.record(success);
+            for reporter in &reporters {
+                reporter.update(&state);
+            }
+        }
+    };
+    let (results, ()) = futures::join!(run, report);
+
+    for reporter in &reporters {
+        reporter.finish();
+    }
+
+    Ok(results)
+}
+
+/// Mints tokens for multiple signers as described by `options`, yielding every result as
+/// soon as its mint completes.
+///
+/// The run is scheduled like [`mint_loop_with`], but on a spawned Tokio task, so it must be
+/// called from within a Tokio runtime. Results come in the order mints complete, confirmed
+/// or failed, rather than grouped by signer, so a slow account does not hold back the
+/// outcome of the others. Dropping the stream stops the run once the mints in flight
+/// complete. `options.progress` is ignored, since the caller sees every result anyway.
+///
+/// # Arguments
+///
+/// * `signers` - A vector of private key signers who will perform the mint operations.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `options` - The mint call and the settings of the run, see [`MintOptions::builder`].
+///
+/// # Returns
+///
+/// * `Result<impl Stream<Item = MintResult>>` - The stream of results, ending with the run,
+///   or an error if the options are invalid, before any transaction is sent.
+pub fn mint_stream(
+    signers: Vec<PrivateKeySigner>,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    options: MintOptions,
+) -> Result<impl Stream<Item = MintResult>> {
+    options.config.validate()?;
+    options.args.validate(signers.len())?;
+    if let Some(value) = &options.value {
+        value.validate(signers.len())?;
+    }
+
+    let (sink, results) = mpsc::unbounded();
+    let call = Arc::new(MintCall {
+        provider: rpc_http.into(),
+        abi,
+        contract_address,
+        function_name: options.function_name,
+        args: options.args,
+        value: options.value,
+        rate_limiter: options.config.rate_limit.map(RateLimiter::new),
+        launcher: launcher(&options.config),
+        on_result: options.on_result,
+        cancel: options.cancel,
+        halted: AtomicBool::new(false),
+        sink: Some(sink),
+        completions: None,
+    });
+
+    // the stream ends when the last clone of `call`, and with it the sink, is dropped
+    tokio::spawn(async move {
+        mint_waves(stream::iter(signers), &call, &options.config).await;
+    });
+
+    Ok(results)
+}
+
+/// Mints tokens in a loop for whitelisted signers, passing each signer its Merkle proof.
+///
+/// The proof of every signer is passed as the only argument of the function, as a `bytes32[]`.
+/// Signers missing from `merkle_proofs` get a failed `MintResult` without sending a transaction.
+///
+/// # Arguments
+///
+/// * `signers` - A vector of private key signers who will perform the mint operations.
+/// * `merkle_proofs` - The Merkle proof of every whitelisted address.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `function_name` - The name of the function to execute (optional, defaults to "mint").
+///
+/// # Returns
+///
+/// * `Result<Vec<MintResult>>` - A vector of `MintResult`, in the same order as `signers`.
+pub async fn mint_loop_merkle(
+    signers: Vec<PrivateKeySigner>,
+    merkle_proofs: HashMap<Address, Vec<[u8; 32]>>,
+    rpc_http: impl Into<ProviderConfig>,
+    abi: JsonAbi,
+    contract_address: Address,
+    function_name: Option<&str>,
+) -> Result<Vec<MintResult>> {
+    let provider: ProviderConfig = rpc_http.into();
+    let config = MintConfig {
+        retries: 0,
+        ..Default::default()
+    };
+
     let mut results: Vec<MintResult> = Vec::with_capacity(signers.len());
-    for signer in &signers {
-        // Use &signers to avoid unnecessary cloning
-        let tx = execute_mint(
-            signer.clone(),
-            rpc_http.clone(),
+    for (index, signer) in signers.into_iter().enumerate() {
+        let address = signer.address();
+
+        let Some(proof) = merkle_proofs.get(&address) else {
+            let error = eyre!("no merkle proof for {address}");
+            results.push(MintResult::new(address, None, 0, Err(error)));
+            continue;
+        };
+
+        let args = [DynSolValue::Array(
+            proof
+                .iter()
+                .map(|node| DynSolValue::FixedBytes(B256::from(*node), 32))
+                .collect(),
+        )];
+        let call = MintCall::new(
+            provider.clone(),
             abi.clone(),
             contract_address,
             function_name,
-            args,
-            value,
-        )
-        .await;
+            Some(&args),
+            None,
+        );
 
-        results.push(MintResult::new(signer.address(), tx));
+        results.push(execute_mint(signer, index, &call, &config).await);
     }
 
     Ok(results)
 }
 
-/// Executes a mint operation on an Ethereum smart contract.
+/// Mints tokens for signers produced lazily by a stream.
+///
+/// Signers are pulled in batches of `config.max_concurrent`, and every batch is minted
+/// concurrently before the next one is pulled, so the whole account set never has to be
+/// held in memory at once. How a batch is scheduled follows `config.spawn_strategy`, the
+/// next batch is pulled `config.wave_delay` after the previous one completed, and failed
+/// mints are retried according to `config.retries`. With `config.rate_limit`, every
+/// attempt, retries included, also waits for its slot so the rate is never exceeded; with
+/// `config.simulate_first`, a send uses the slot of the simulation right before it.
 ///
 /// # Arguments
 ///
-/// * `signer` - The private key signer of the account executing the transaction.
-/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+/// * `signers` - A stream of private key signers who will perform the mint operations.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, or a [`ProviderConfig`] with credentials.
 /// * `abi` - The JSON ABI of the contract.
 /// * `contract_address` - The address of the contract.
 /// * `function_name` - The name of the function to execute (optional, defaults to "mint").
 /// * `args` - The arguments to pass to the function (optional).
 /// * `value` - The amount of Ether to send with the transaction (optional).
+/// * `config` - The configuration of the mint run.
 ///
 /// # Returns
 ///
-/// * `Result<TxHash>` - The transaction hash of the executed transaction on success.
-async fn execute_mint(
-    signer: PrivateKeySigner,
-    rpc_http: Url,
+/// * `Result<Vec<MintResult>>` - A vector of `MintResult`, in the order the signers were produced.
+#[allow(clippy::too_many_arguments)]
+pub async fn mint_loop_from_stream<S>(
+    signers: S,
+    rpc_http: impl Into<ProviderConfig>,
     abi: JsonAbi,
     contract_address: Address,
     function_name: Option<&str>,
     args: Option<&[DynSolValue]>,
     value: Option<U256>,
-) -> Result<TxHash> {
-    let function_name = function_name.unwrap_or("mint");
+    config: MintConfig,
+) -> Result<Vec<MintResult>>
+where
+    S: Stream<Item = PrivateKeySigner> + Send,
+{
+    config.validate()?;
+    let call = Arc::new(MintCall {
+        rate_limiter: config.rate_limit.map(RateLimiter::new),
+        launcher: launcher(&config),
+        ..MintCall::new(
+            rpc_http.into(),
+            abi,
+            contract_address,
+            function_name,
+            args,
+            value,
+        )
+    });
+
+    Ok(mint_waves(signers, &call, &config).await)
+}
+
+/// Mints for the signers of a stream in waves of `config.max_concurrent`.
+///
+/// # Arguments
+///
+/// * `signers` - A stream of private key signers who will perform the mint operations.
+/// * `call` - The mint call to execute.
+/// * `config` - The configuration of the mint run.
+///
+/// # Returns
+///
+/// * `Vec<MintResult>` - The result of every mint, in the order the signers were produced,
+///   empty when `call` sends its results to a sink.
+async fn mint_waves<S>(signers: S, call: &Arc<MintCall>, config: &MintConfig) -> Vec<MintResult>
+where
+    S: Stream<Item = PrivateKeySigner> + Send,
+{
+    let mut results: Vec<MintResult> = Vec::new();
+    // signers may mint several times, so results do not count them
+    let mut first_index = 0;
+    let mut batches = Box::pin(signers.chunks(config.max_concurrent));
+    while let Some(batch) = batches.next().await {
+        if call.is_abandoned() {
+            break;
+        }
+        // no pause before the first wave, nor once the remaining mints are skipped
+        if first_index > 0 && !config.wave_delay.is_zero() && !call.is_stopped() {
+            tokio::time::sleep(config.wave_delay).await;
+        }
 
-    let tx_hash = execute(
+        results.extend(mint_batch(&batch, first_index, call, config).await);
+        first_index += batch.len();
+    }
+
+    results
+}
+
+/// Mints for every signer of a batch concurrently, scheduled by `config.spawn_strategy`.
+///
+/// # Arguments
+///
+/// * `batch` - The signers of the batch.
+/// * `first_index` - The index in the run of the first signer of the batch.
+/// * `call` - The mint call to execute.
+/// * `config` - The configuration of the mint run.
+///
+/// # Returns
+///
+/// * `Vec<MintResult>` - The result of every mint, in the order of `batch`.
+async fn mint_batch(
+    batch: &[PrivateKeySigner],
+    first_index: usize,
+    call: &Arc<MintCall>,
+    config: &MintConfig,
+) -> Vec<MintResult> {
+    let results = match config.spawn_strategy {
+        SpawnStrategy::JoinAll => {
+            join_all(batch.iter().enumerate().map(|(offset, signer)| {
+                mint_account(signer.clone(), first_index + offset, call, config)
+            }))
+            .await
+        }
+        SpawnStrategy::SpawnTasks => {
+            let handles = batch.iter().enumerate().map(|(offset, signer)| {
+                let (signer, call, config) = (signer.clone(), call.clone(), config.clone());
+                let index = first_index + offset;
+                tokio::spawn(async move { mint_account(signer, index, &call, &config).await })
+            });
+
+            join_all(handles)
+                .await
+                .into_iter()
+                .zip(batch)
+                .map(|(joined, signer)| {
+                    joined.unwrap_or_else(|error| {
+                        let result = MintResult::new(signer.address(), None, 0, Err(error.into()));
+                        call.notify(&result);
+                        call.report(&result);
+                        call.deliver(result).into_iter().collect()
+                    })
+                })
+                .collect()
+        }
+    };
+
+    results.into_iter().flatten().collect()
+}
+
+/// Creates the launch scheduler of a run with `config.launch_delay`.
+fn launcher(config: &MintConfig) -> Option<LaunchScheduler> {
+    config
+        .launch_delay
+        .clone()
+        .map(|launch_delay| LaunchScheduler::new(launch_delay, config.launch_seed))
+}
+
+/// Mints `config.mints_per_account` times for one signer, one mint after another.
+///
+/// With `config.launch_delay`, the signer first waits for its turn to start. Once the run is
+/// cancelled, every mint left is reported as cancelled without being sent; with
+/// `config.fail_fast`, the same goes for skipped mints once any mint of the run reverted.
+/// The sequence stops at the first mint failing for good, such as a revert once the
+/// allowance of the account is exhausted; the mints left then have no result, and are only
+/// counted as skipped in the progress of the run. A mint that ran out of retries on a
+/// transient error does not stop the sequence.
+///
+/// # Arguments
+///
+/// * `signer` - The private key signer of the account executing the transactions.
+/// * `index` - The index of the signer in the run, which selects its arguments and value.
+/// * `call` - The mint call to execute.
+/// * `config` - The configuration of the mint run.
+///
+/// # Returns
+///
+/// * `Vec<MintResult>` - The result of every mint sent, in order.
+async fn mint_account(
+    signer: PrivateKeySigner,
+    index: usize,
+    call: &MintCall,
+    config: &MintConfig,
+) -> Vec<MintResult> {
+    if let Some(launcher) = call.launcher.as_ref().filter(|_| !call.is_stopped()) {
+        launcher.wait().await;
+    }
+
+    let mut results = Vec::with_capacity(config.mints_per_account as usize);
+    for mint_index in 0..config.mints_per_account {
+        let result = if call.is_cancelled() {
+            MintResult::new(signer.address(), None, 0, Err(MintCancelled.into()))
+        } else if call.halted.load(Ordering::Relaxed) {
+            MintResult::new(signer.address(), None, 0, Err(MintSkipped.into()))
+        } else {
+            execute_mint(signer.clone(), index, call, config).await
+        };
+        let result = MintResult {
+            mint_index,
+            ..result
+        };
+        if config.fail_fast && matches!(&result.result, Err(error) if is_revert(error)) {
+            call.halted.store(true, Ordering::Relaxed);
+        }
+        // cancelled and skipped mints do not stop the sequence, so each gets its own result
+        let terminal = result.is_timed_out()
+            || (!result.is_cancelled()
+                && !result.is_skipped()
+                && matches!(&result.result, Err(error) if !is_retryable(error)));
+
+        call.notify(&result);
+        call.report(&result);
+        results.extend(call.deliver(result));
+        if terminal {
+            call.report_skipped(config.mints_per_account - mint_index - 1);
+            break;
+        }
+    }
+
+    results
+}
+
+/// Executes a mint operation on an Ethereum smart contract.
+///
+/// A mint rejected as underpriced is resent with the same nonce and its fees raised by
+/// `config.gas_bump_percent`, up to `config.max_gas_bumps` times. Other transient failures
+/// are retried up to `config.retries` times, waiting `config.retry_delay` before the first
+/// retry and twice as long before every further one. Reverts are returned right away.
+/// With `config.per_mint_timeout`, the mint fails once the deadline passes, whatever step is
+/// running.
+///
+/// # Arguments
+///
+/// * `signer` - The private key signer of the account executing the transaction.
+/// * `index` - The index of the signer in the run, which selects its arguments and value.
+/// * `call` - The mint call to execute.
+/// * `config` - The retry, gas and timeout settings of the mint run.
+///
+/// # Returns
+///
+/// * `MintResult` - The nonce of the last attempt, the number of attempts, and the transaction
+///   hash of the executed transaction on success or the last error.
+async fn execute_mint(
+    signer: PrivateKeySigner,
+    index: usize,
+    call: &MintCall,
+    config: &MintConfig,
+) -> MintResult {
+    let address = signer.address();
+    let args = call.args.resolve(&address, index);
+    let value = call
+        .value
+        .as_ref()
+        .map(|value| value.resolve(&address, index));
+    let (mut nonce, mut attempts) = (None, 0);
+
+    let mint = send_mint(
         signer,
-        rpc_http,
-        abi,
-        contract_address,
-        function_name,
-        args.unwrap_or_default(),
+        call,
+        &args,
         value,
+        config,
+        &mut nonce,
+        &mut attempts,
+    );
+    let result = match config.per_mint_timeout {
+        Some(timeout) => tokio::time::timeout(timeout, mint)
+            .await
+            .unwrap_or_else(|_| Err(MintTimedOut { timeout }.into())),
+        None => mint.await,
+    };
+
+    MintResult::new(address, nonce, attempts, result)
+}
+
+/// Sends the mint transaction until it succeeds, fails for good, or runs out of retries.
+///
+/// With `config.simulate_first`, the mint is first run as an `eth_call`, and nothing is
+/// sent if it reverts; a simulation failing on a transient error is retried like a send.
+/// Once a transaction is sent it is only watched, and a retry after a send that may have
+/// reached the node reuses its nonce, so a mint is never sent twice.
+///
+/// # Arguments
+///
+/// * `signer` - The private key signer of the account executing the transaction.
+/// * `call` - The mint call to execute.
+/// * `args` - The arguments of the signer.
+/// * `value` - The amount of Ether sent by the signer (optional).
+/// * `config` - The retry and gas settings of the mint run.
+/// * `nonce` - Set to the pending nonce of the signer before an attempt, and fetched again
+///   only after a failure that happened before sending.
+/// * `attempts` - Incremented with every transaction sent.
+///
+/// # Returns
+///
+/// * `Result<TxHash>` - The transaction hash of the executed transaction, or the last error.
+async fn send_mint(
+    signer: PrivateKeySigner,
+    call: &MintCall,
+    args: &[DynSolValue],
+    value: Option<U256>,
+    config: &MintConfig,
+    nonce: &mut Option<u64>,
+    attempts: &mut u32,
+) -> Result<TxHash> {
+    let provider = ProviderBuilder::new().on_client(call.provider.client()?);
+
+    // pin the fees so an underpriced transaction can be replaced without re-estimating
+    let mut gas = match config.gas {
+        GasPolicy::Auto => provider
+            .estimate_eip1559_fees(None)
+            .await
+            .map(|fees| GasPolicy::Eip1559 {
+                max_fee_per_gas: fees.max_fee_per_gas,
+                max_priority_fee_per_gas: fees.max_priority_fee_per_gas,
+            })
+            .unwrap_or(GasPolicy::Auto),
+        gas => gas,
+    };
+
+    let (mut retries, mut bumps) = (0, 0);
+    let mut retry_delay = config.retry_delay;
+    // set once an attempt may have reached the node, from then on every retry reuses its
+    // nonce so it can only replace that attempt, never mint a second time
+    let mut maybe_sent = false;
+    let mut simulated = !config.simulate_first;
+    // set right after a successful simulation, whose slot the send that follows it reuses
+    let mut holds_slot = false;
+    loop {
+        if let Some(rate_limiter) = call.rate_limiter.as_ref().filter(|_| !holds_slot) {
+            rate_limiter.acquire().await;
+        }
+        holds_slot = false;
+
+        // a simulation failing on a transient error is retried like a send
+        let result = if simulated {
+            submit_mint(&signer, call, args, value, gas, nonce, attempts)
+                .await
+                .map(Some)
+        } else {
+            simulate_mint(signer.address(), call, args, value)
+                .await
+                .map(|()| None)
+        };
+
+        let error = match result {
+            Ok(Some(tx_hash)) => return confirm_mint(call, tx_hash, config).await,
+            Ok(None) => {
+                simulated = true;
+                holds_slot = true;
+                continue;
+            }
+            Err(error) => error,
+        };
+        if maybe_sent && is_already_sent(&error) {
+            return Err(error.wrap_err(format!(
+                "an earlier attempt with nonce {} reached the node",
+                nonce.unwrap_or_default()
+            )));
+        }
+        maybe_sent |= error.is::<MaybeSent>();
+
+        if is_underpriced(&error) && bumps < config.max_gas_bumps {
+            bumps += 1;
+            gas = gas.bump(config.gas_bump_percent);
+        } else if is_retryable(&error) && retries < config.retries {
+            retries += 1;
+            if !maybe_sent {
+                *nonce = None;
+            }
+            tokio::time::sleep(retry_delay).await;
+            retry_delay *= 2;
+        } else {
+            return Err(error);
+        }
+    }
+}
+
+/// Sends one attempt of a mint without waiting for it, fetching the pending nonce first
+/// unless an earlier attempt already holds one. The caller waits for the rate limit.
+///
+/// # Arguments
+///
+/// * `signer` - The private key signer of the account minting.
+/// * `call` - The mint call to send.
+/// * `args` - The arguments of the signer.
+/// * `value` - The amount of Ether sent by the signer (optional).
+/// * `gas` - The gas policy of the attempt.
+/// * `nonce` - The nonce of the attempt, set to the pending nonce when missing.
+/// * `attempts` - The number of attempts so far, incremented before this one starts.
+///
+/// # Returns
+///
+/// * `Result<TxHash>` - The hash of the sent transaction.
+async fn submit_mint(
+    signer: &PrivateKeySigner,
+    call: &MintCall,
+    args: &[DynSolValue],
+    value: Option<U256>,
+    gas: GasPolicy,
+    nonce: &mut Option<u64>,
+    attempts: &mut u32,
+) -> Result<TxHash> {
+    // an attempt failing before its transaction is sent, e.g. on the nonce, still counts
+    *attempts += 1;
+
+    // a replacement keeps the nonce of the transaction it replaces
+    let pending_nonce = match *nonce {
+        Some(pending_nonce) => pending_nonce,
+        None => {
+            ProviderBuilder::new()
+                .on_client(call.provider.client()?)
+                .get_transaction_count(signer.address())
+                .pending()
+                .await?
+        }
+    };
+    *nonce = Some(pending_nonce);
+
+    let options = ExecuteOptions {
+        value,
+        gas,
+        nonce: Some(pending_nonce),
+        ..Default::default()
+    };
+
+    let execution = submit_with_options(
+        signer.clone(),
+        call.provider.clone(),
+        call.abi.clone(),
+        call.contract_address,
+        &call.function_name,
+        args,
+        options,
     )
-    .await?
-    .tx_hash;
+    .await?;
+
+    Ok(execution.tx_hash)
+}
+
+/// Waits for a sent mint to be confirmed, watching it again after a transient failure.
+///
+/// The mint is never sent again from here: it already holds its nonce, and sending it
+/// under a fresh one would mint twice.
+///
+/// # Arguments
+///
+/// * `call` - The mint call that was sent.
+/// * `tx_hash` - The hash of the sent transaction.
+/// * `config` - The confirmations to wait for and the retry policy.
+///
+/// # Returns
+///
+/// * `Result<TxHash>` - The hash of the confirmed transaction.
+async fn confirm_mint(call: &MintCall, tx_hash: TxHash, config: &MintConfig) -> Result<TxHash> {
+    let provider = ProviderBuilder::new().on_client(call.provider.client()?);
+
+    let mut retries = 0;
+    let mut retry_delay = config.retry_delay;
+    loop {
+        let watched = PendingTransactionBuilder::new(provider.root().clone(), tx_hash)
+            .with_required_confirmations(config.confirmations.into())
+            .watch()
+            .await
+            .map_err(Report::from);
+
+        match watched {
+            Ok(tx_hash) => return Ok(tx_hash),
+            Err(error) if is_retryable(&error) && retries < config.retries => {
+                retries += 1;
+                tokio::time::sleep(retry_delay).await;
+                retry_delay *= 2;
+            }
+            Err(error) => {
+                return Err(error.wrap_err(format!("mint {tx_hash} was sent but not confirmed")))
+            }
+        }
+    }
+}
+
+/// Returns `true` if the node rejected a transaction because its nonce is already taken,
+/// either by the very same transaction or by one already included.
+fn is_already_sent(error: &Report) -> bool {
+    error.chain().any(|cause| {
+        let message = cause.to_string().to_lowercase();
+        message.contains("nonce too low") || message.contains("already known")
+    })
+}
+
+/// Returns `true` if a transaction was rejected because its fees were too low.
+///
+/// Matches both "transaction underpriced" and "replacement transaction underpriced".
+fn is_underpriced(error: &Report) -> bool {
+    error
+        .chain()
+        .any(|cause| cause.to_string().contains("underpriced"))
+}
+
+/// Messages of the transient failures worth retrying, matched case-insensitively.
+const RETRYABLE_ERRORS: [&str; 10] = [
+    "nonce too low",
+    "429",
+    "too many requests",
+    "rate limit",
+    "error sending request",
+    "connection",
+    "timed out",
+    "timeout",
+    "502",
+    "503",
+];
+
+/// Runs a mint as an `eth_call` from `from`, without sending any transaction.
+///
+/// # Arguments
+///
+/// * `from` - The address of the signer.
+/// * `call` - The mint call to simulate.
+/// * `args` - The arguments of the signer.
+/// * `value` - The amount of Ether sent by the signer (optional).
+///
+/// # Returns
+///
+/// * `Result<()>` - An error with the decoded revert reason if the mint would revert.
+async fn simulate_mint(
+    from: Address,
+    call: &MintCall,
+    args: &[DynSolValue],
+    value: Option<U256>,
+) -> Result<()> {
+    let provider = ProviderBuilder::new().on_client(call.provider.client()?);
+    let tx = TransactionRequest::default()
+        .with_from(from)
+        .with_to(call.contract_address)
+        .with_input(build_calldata(&call.abi, &call.function_name, args)?)
+        .with_value(value.unwrap_or_default());
+
+    let Err(error) = provider.call(&tx).await else {
+        return Ok(());
+    };
+    let reason = error
+        .as_error_resp()
+        .and_then(|payload| payload.as_revert_data())
+        .and_then(|data| decode_revert_reason(&data));
 
-    Ok(tx_hash)
+    Err(match reason {
+        Some(reason) => eyre!("simulation reverted: {reason}"),
+        None => Report::from(error).wrap_err("simulation failed"),
+    })
+}
+
+/// Returns `true` if a mint failed because the contract reverted.
+fn is_revert(error: &Report) -> bool {
+    error
+        .chain()
+        .any(|cause| cause.to_string().to_lowercase().contains("revert"))
+}
+
+/// Returns `true` if a failed mint may succeed when sent again.
+///
+/// Reverts are never retryable, since the contract would reject the mint again.
+fn is_retryable(error: &Report) -> bool {
+    let messages: Vec<String> = error
+        .chain()
+        .map(|cause| cause.to_string().to_lowercase())
+        .collect();
+
+    !is_revert(error)
+        && messages.iter().any(|message| {
+            RETRYABLE_ERRORS
+                .iter()
+                .any(|marker| message.contains(marker))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_result_json() {
+        let tx_hash = TxHash::repeat_byte(0xab);
+        let results = vec![
+            MintResult::new(Address::with_last_byte(1), Some(0), 1, Ok(tx_hash)),
+            MintResult::new(
+                Address::with_last_byte(2),
+                None,
+                3,
+                Err(eyre!("connection refused").wrap_err("error sending request")),
+            ),
+        ];
+
+        let json: serde_json::Value = serde_json::to_value(&results).unwrap();
+        assert_eq!(json[0]["result"]["status"], "ok");
+        assert_eq!(
+            json[0]["result"]["tx_hash"],
+            serde_json::to_value(tx_hash).unwrap()
+        );
+        assert_eq!(json[1]["result"]["status"], "error");
+        assert_eq!(
+            json[1]["result"]["message"],
+            "error sending request: connection refused"
+        );
+
+        let restored: Vec<MintResult> = serde_json::from_value(json).unwrap();
+        assert_eq!(restored[0].signer, results[0].signer);
+        assert_eq!(restored[0].nonce, Some(0));
+        assert_eq!(*restored[0].result.as_ref().unwrap(), tx_hash);
+        assert_eq!(restored[1].attempts, 3);
+        assert_eq!(
+            restored[1].result.as_ref().unwrap_err().to_string(),
+            "error sending request: connection refused"
+        );
+    }
+
+    #[test]
+    fn test_is_underpriced() {
+        assert!(is_underpriced(&eyre!("transaction underpriced")));
+        assert!(is_underpriced(
+            &eyre!("replacement transaction underpriced").wrap_err("server returned an error")
+        ));
+        assert!(!is_underpriced(&eyre!("nonce too low")));
+    }
+
+    #[test]
+    fn test_is_retryable() {
+        assert!(is_retryable(&eyre!("nonce too low")));
+        assert!(is_retryable(&eyre!(
+            "HTTP error 429 with body: Too Many Requests"
+        )));
+        assert!(is_retryable(
+            &eyre!("connection refused").wrap_err("error sending request for url")
+        ));
+
+        assert!(!is_retryable(&eyre!(
+            "server returned an error response: execution reverted: Address has already minted"
+        )));
+        assert!(!is_retryable(&eyre!(
+            "insufficient funds for gas * price + value"
+        )));
+    }
+
+    #[test]
+    fn test_is_already_sent() {
+        assert!(is_already_sent(&eyre!(
+            "nonce too low: next nonce 3, tx nonce 2"
+        )));
+        assert!(is_already_sent(
+            &eyre!("already known").wrap_err("server returned an error response")
+        ));
+        assert!(!is_already_sent(&eyre!(
+            "replacement transaction underpriced"
+        )));
+        assert!(!is_already_sent(&eyre!("error sending request")));
+    }
 }
@@ -1,2 +1,20 @@
+mod config;
+pub use config::{MintConfig, MintConfigBuilder, SpawnStrategy};
+
 mod miner;
-pub use miner::{mint_loop, MintResult};
+#[allow(deprecated)]
+pub use miner::mint_loop;
+pub use miner::{
+    mint_loop_from_stream, mint_loop_merkle, mint_loop_with, mint_loop_with_progress, mint_stream,
+    MintCancelled, MintProgress, MintResult, MintSkipped, MintTimedOut,
+};
+
+mod options;
+pub use options::{
+    ArgsFn, MintArgs, MintOptions, MintOptionsBuilder, MintValue, ResultFn, ValueFn,
+};
+
+mod rate;
+
+mod delegated;
+pub use delegated::mint_delegated;
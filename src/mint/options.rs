@@ -0,0 +1,463 @@
+use crate::executor::GasPolicy;
+use crate::mint::{MintConfig, MintResult};
+use alloy::{
+    dyn_abi::DynSolValue,
+    primitives::{Address, U256},
+};
+use eyre::{ensure, Result};
+use std::{
+    ops::Range,
+    sync::{atomic::AtomicBool, Arc},
+    time::Duration,
+};
+
+/// Computes the arguments of a signer from its address and its index in the run.
+pub type ArgsFn = Arc<dyn Fn(&Address, usize) -> Vec<DynSolValue> + Send + Sync>;
+
+/// Computes the value sent by a signer from its address and its index in the run.
+pub type ValueFn = Arc<dyn Fn(&Address, usize) -> U256 + Send + Sync>;
+
+/// Receives the result of every mint of a run as soon as the mint resolves.
+pub type ResultFn = Arc<dyn Fn(&MintResult) + Send + Sync>;
+
+/// Where the arguments of every mint come from.
+///
+/// # Variants
+///
+/// * `Shared` - The same arguments for every signer.
+/// * `PerSigner` - One argument list per signer, in the order of the signers.
+/// * `Computed` - A function called with the address and index of every signer, e.g. to
+///   pass each signer its own address.
+#[derive(Clone)]
+pub enum MintArgs {
+    Shared(Vec<DynSolValue>),
+    PerSigner(Vec<Vec<DynSolValue>>),
+    Computed(ArgsFn),
+}
+
+impl MintArgs {
+    /// Returns the arguments of the signer at `index` of the run.
+    pub(crate) fn resolve(&self, signer: &Address, index: usize) -> Vec<DynSolValue> {
+        match self {
+            Self::Shared(args) => args.clone(),
+            Self::PerSigner(args) => args.get(index).cloned().unwrap_or_default(),
+            Self::Computed(args) => args(signer, index),
+        }
+    }
+
+    /// Checks that there are arguments for each of `signer_count` signers.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - An error if per-signer arguments do not match the signers one to one.
+    pub(crate) fn validate(&self, signer_count: usize) -> Result<()> {
+        if let Self::PerSigner(args) = self {
+            ensure!(
+                args.len() == signer_count,
+                "got {} argument lists for {signer_count} signers",
+                args.len()
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for MintArgs {
+    fn default() -> Self {
+        Self::Shared(Vec::new())
+    }
+}
+
+impl std::fmt::Debug for MintArgs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Shared(args) => f.debug_tuple("Shared").field(args).finish(),
+            Self::PerSigner(args) => f.debug_tuple("PerSigner").field(args).finish(),
+            Self::Computed(_) => f.debug_tuple("Computed").finish_non_exhaustive(),
+        }
+    }
+}
+
+/// How much Ether every mint sends, e.g. to follow tiered pricing.
+///
+/// # Variants
+///
+/// * `Shared` - The same value for every signer.
+/// * `PerSigner` - One value per signer, in the order of the signers.
+/// * `Computed` - A function called with the address and index of every signer.
+#[derive(Clone)]
+pub enum MintValue {
+    Shared(U256),
+    PerSigner(Vec<U256>),
+    Computed(ValueFn),
+}
+
+impl MintValue {
+    /// Returns the value sent by the signer at `index` of the run.
+    pub(crate) fn resolve(&self, signer: &Address, index: usize) -> U256 {
+        match self {
+            Self::Shared(value) => *value,
+            Self::PerSigner(values) => values.get(index).copied().unwrap_or_default(),
+            Self::Computed(value) => value(signer, index),
+        }
+    }
+
+    /// Checks that there is a value for each of `signer_count` signers.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - An error if per-signer values do not match the signers one to one.
+    pub(crate) fn validate(&self, signer_count: usize) -> Result<()> {
+        if let Self::PerSigner(values) = self {
+            ensure!(
+                values.len() == signer_count,
+                "got {} values for {signer_count} signers",
+                values.len()
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for MintValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Shared(value) => f.debug_tuple("Shared").field(value).finish(),
+            Self::PerSigner(values) => f.debug_tuple("PerSigner").field(values).finish(),
+            Self::Computed(_) => f.debug_tuple("Computed").finish_non_exhaustive(),
+        }
+    }
+}
+
+/// Options of a mint run started with [`mint_loop_with`](crate::mint::mint_loop_with).
+///
+/// # Fields
+///
+/// * `function_name` - The name of the function to execute.
+/// * `args` - The arguments to pass to the function, shared or per signer.
+/// * `value` - The amount of Ether to send with every transaction, shared or per signer
+///   (optional).
+/// * `config` - The concurrency, retry, gas and timeout settings of the run.
+/// * `progress` - Whether the run is shown on stderr as a progress bar and a live count of the
+///   mints that succeeded and failed.
+/// * `on_result` - Called once per mint, right when it resolves, before its batch completes
+///   (optional). A panic in the callback is caught, after the panic hook reported it, so
+///   it never affects the mints of the run.
+/// * `cancel` - A flag cancelling the run once set (optional). Mints not started yet are
+///   skipped and reported with a [`MintCancelled`](crate::mint::MintCancelled) error, while
+///   transactions already sent are awaited and reported as usual.
+#[derive(Clone)]
+pub struct MintOptions {
+    pub function_name: String,
+    pub args: MintArgs,
+    pub value: Option<MintValue>,
+    pub config: MintConfig,
+    pub progress: bool,
+    pub on_result: Option<ResultFn>,
+    pub cancel: Option<Arc<AtomicBool>>,
+}
+
+impl std::fmt::Debug for MintOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MintOptions")
+            .field("function_name", &self.function_name)
+            .field("args", &self.args)
+            .field("value", &self.value)
+            .field("config", &self.config)
+            .field("progress", &self.progress)
+            .field("on_result", &self.on_result.as_ref().map(|_| "Fn"))
+            .field("cancel", &self.cancel)
+            .finish()
+    }
+}
+
+impl Default for MintOptions {
+    fn default() -> Self {
+        Self {
+            function_name: "mint".to_string(),
+            args: MintArgs::default(),
+            value: None,
+            config: MintConfig::default(),
+            progress: false,
+            on_result: None,
+            cancel: None,
+        }
+    }
+}
+
+impl MintOptions {
+    /// Creates a `MintOptionsBuilder` starting from the default options, which call a
+    /// parameterless `mint` function without value.
+    pub fn builder() -> MintOptionsBuilder {
+        MintOptionsBuilder::default()
+    }
+}
+
+/// Builder for `MintOptions`.
+#[derive(Debug, Clone, Default)]
+pub struct MintOptionsBuilder {
+    options: MintOptions,
+}
+
+impl MintOptionsBuilder {
+    /// Sets the name of the function to execute.
+    pub fn function_name(mut self, function_name: impl Into<String>) -> Self {
+        self.options.function_name = function_name.into();
+        self
+    }
+
+    /// Sets the arguments passed to the function by every signer.
+    pub fn args(mut self, args: Vec<DynSolValue>) -> Self {
+        self.options.args = MintArgs::Shared(args);
+        self
+    }
+
+    /// Sets one argument list per signer, in the order of the signers.
+    pub fn args_per_signer(mut self, args: Vec<Vec<DynSolValue>>) -> Self {
+        self.options.args = MintArgs::PerSigner(args);
+        self
+    }
+
+    /// Sets a function computing the arguments of every signer from its address and index.
+    pub fn args_with<F>(mut self, args: F) -> Self
+    where
+        F: Fn(&Address, usize) -> Vec<DynSolValue> + Send + Sync + 'static,
+    {
+        self.options.args = MintArgs::Computed(Arc::new(args));
+        self
+    }
+
+    /// Sets the amount of Ether sent by every signer.
+    pub fn value(mut self, value: U256) -> Self {
+        self.options.value = Some(MintValue::Shared(value));
+        self
+    }
+
+    /// Sets one amount of Ether per signer, in the order of the signers.
+    pub fn value_per_signer(mut self, values: Vec<U256>) -> Self {
+        self.options.value = Some(MintValue::PerSigner(values));
+        self
+    }
+
+    /// Sets a function computing the amount of Ether sent by every signer from its address
+    /// and index.
+    pub fn value_with<F>(mut self, value: F) -> Self
+    where
+        F: Fn(&Address, usize) -> U256 + Send + Sync + 'static,
+    {
+        self.options.value = Some(MintValue::Computed(Arc::new(value)));
+        self
+    }
+
+    /// Sets the maximum number of mints in flight at the same time.
+    pub fn max_concurrent(mut self, max_concurrent: usize) -> Self {
+        self.options.config.max_concurrent = max_concurrent;
+        self
+    }
+
+    /// Sets the number of retries of a failed mint and the delay before the first retry.
+    pub fn retry(mut self, retries: u32, retry_delay: Duration) -> Self {
+        self.options.config.retries = retries;
+        self.options.config.retry_delay = retry_delay;
+        self
+    }
+
+    /// Sets how the gas price of the mint transactions is determined.
+    pub fn gas(mut self, gas: GasPolicy) -> Self {
+        self.options.config.gas = gas;
+        self
+    }
+
+    /// Sets the time after which a mint still unconfirmed is reported as failed.
+    pub fn per_mint_timeout(mut self, timeout: Duration) -> Self {
+        self.options.config.per_mint_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the number of times every signer mints, one mint after another.
+    pub fn mints_per_account(mut self, mints_per_account: u32) -> Self {
+        self.options.config.mints_per_account = mints_per_account;
+        self
+    }
+
+    /// Sets the number of blocks to wait for before a mint is reported as confirmed.
+    pub fn confirmations(mut self, confirmations: u32) -> Self {
+        self.options.config.confirmations = confirmations;
+        self
+    }
+
+    /// Sets whether to stop starting mints once any mint of the run reverted.
+    pub fn fail_fast(mut self, fail_fast: bool) -> Self {
+        self.options.config.fail_fast = fail_fast;
+        self
+    }
+
+    /// Sets whether to simulate every mint before sending it.
+    pub fn simulate_first(mut self, simulate_first: bool) -> Self {
+        self.options.config.simulate_first = simulate_first;
+        self
+    }
+
+    /// Sets the range of the random delay staggering the start of every signer, and the
+    /// seed of the delays to reproduce a run (optional).
+    pub fn launch_delay(mut self, launch_delay: Range<Duration>, seed: Option<u64>) -> Self {
+        self.options.config.launch_delay = Some(launch_delay);
+        self.options.config.launch_seed = seed;
+        self
+    }
+
+    /// Sets whether the run is shown on stderr as progress bars.
+    pub fn progress(mut self, progress: bool) -> Self {
+        self.options.progress = progress;
+        self
+    }
+
+    /// Sets a callback receiving the result of every mint as soon as it resolves.
+    pub fn on_result<F>(mut self, on_result: F) -> Self
+    where
+        F: Fn(&MintResult) + Send + Sync + 'static,
+    {
+        self.options.on_result = Some(Arc::new(on_result));
+        self
+    }
+
+    /// Sets the flag cancelling the mints not started yet once it is set.
+    pub fn cancel(mut self, cancel: Arc<AtomicBool>) -> Self {
+        self.options.cancel = Some(cancel);
+        self
+    }
+
+    /// Replaces the whole run configuration, for the settings without a shortcut here.
+    pub fn config(mut self, config: MintConfig) -> Self {
+        self.options.config = config;
+        self
+    }
+
+    /// Validates and builds the `MintOptions`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<MintOptions>` - The options, or an error if settings conflict.
+    pub fn build(self) -> Result<MintOptions> {
+        self.options.config.validate()?;
+        Ok(self.options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_defaults() {
+        let options = MintOptions::builder().build().unwrap();
+
+        assert_eq!(options.function_name, "mint");
+        assert!(matches!(&options.args, MintArgs::Shared(args) if args.is_empty()));
+        assert!(options.value.is_none());
+        assert_eq!(options.config.max_concurrent, 50);
+        assert_eq!(options.config.retries, 2);
+        assert_eq!(options.config.gas, GasPolicy::Auto);
+        assert_eq!(options.config.per_mint_timeout, None);
+        assert!(!options.progress);
+        assert!(options.on_result.is_none());
+    }
+
+    #[test]
+    fn test_builder_chaining() {
+        let options = MintOptions::builder()
+            .function_name("mintTo")
+            .args(vec![DynSolValue::from(U256::from(2))])
+            .value(U256::from(100))
+            .max_concurrent(5)
+            .retry(0, Duration::ZERO)
+            .gas(GasPolicy::Legacy { gas_price: 1 })
+            .per_mint_timeout(Duration::from_secs(30))
+            .launch_delay(Duration::ZERO..Duration::from_millis(20), Some(7))
+            .progress(true)
+            .build()
+            .unwrap();
+
+        assert_eq!(options.function_name, "mintTo");
+        let expected = [DynSolValue::from(U256::from(2))];
+        assert!(matches!(&options.args, MintArgs::Shared(args) if args == &expected));
+        assert!(
+            matches!(options.value, Some(MintValue::Shared(value)) if value == U256::from(100))
+        );
+        assert_eq!(options.config.max_concurrent, 5);
+        assert_eq!(options.config.retries, 0);
+        assert_eq!(options.config.gas, GasPolicy::Legacy { gas_price: 1 });
+        assert_eq!(
+            options.config.per_mint_timeout,
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            options.config.launch_delay,
+            Some(Duration::ZERO..Duration::from_millis(20))
+        );
+        assert_eq!(options.config.launch_seed, Some(7));
+        assert!(options.progress);
+    }
+
+    #[test]
+    fn test_args_resolve() {
+        let signer = Address::repeat_byte(0x11);
+
+        let shared = MintArgs::Shared(vec![DynSolValue::from(U256::from(1))]);
+        assert_eq!(
+            shared.resolve(&signer, 3),
+            [DynSolValue::from(U256::from(1))]
+        );
+        assert!(shared.validate(5).is_ok());
+
+        let per_signer = MintArgs::PerSigner(vec![vec![], vec![DynSolValue::Bool(true)]]);
+        assert_eq!(per_signer.resolve(&signer, 1), [DynSolValue::Bool(true)]);
+        assert!(per_signer.validate(2).is_ok());
+        assert!(per_signer.validate(3).is_err());
+
+        let options = MintOptions::builder()
+            .args_with(|signer, index| {
+                vec![
+                    DynSolValue::from(*signer),
+                    DynSolValue::from(U256::from(index)),
+                ]
+            })
+            .build()
+            .unwrap();
+        assert_eq!(
+            options.args.resolve(&signer, 4),
+            [DynSolValue::from(signer), DynSolValue::from(U256::from(4))]
+        );
+    }
+
+    #[test]
+    fn test_value_resolve() {
+        let signer = Address::repeat_byte(0x11);
+
+        let per_signer = MintValue::PerSigner(vec![U256::from(1), U256::from(2)]);
+        assert_eq!(per_signer.resolve(&signer, 1), U256::from(2));
+        assert!(per_signer.validate(2).is_ok());
+        assert!(per_signer.validate(1).is_err());
+
+        // the first two signers pay less
+        let tiered = MintValue::Computed(Arc::new(|_, index| {
+            U256::from(if index < 2 { 10 } else { 20 })
+        }));
+        assert_eq!(tiered.resolve(&signer, 1), U256::from(10));
+        assert_eq!(tiered.resolve(&signer, 2), U256::from(20));
+        assert!(tiered.validate(100).is_ok());
+    }
+
+    #[test]
+    fn test_builder_rejects_conflicts() {
+        assert!(MintOptions::builder().max_concurrent(0).build().is_err());
+
+        let config = MintConfig {
+            rate_limit: Some(-1.0),
+            ..Default::default()
+        };
+        assert!(MintOptions::builder().config(config).build().is_err());
+    }
+}
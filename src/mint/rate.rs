@@ -0,0 +1,153 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{ops::Range, time::Duration};
+use tokio::{sync::Mutex, time::Instant};
+
+/// Spaces out transaction submissions to a fixed rate.
+///
+/// Every caller of [`acquire`](Self::acquire) reserves the next free slot, at least one
+/// interval after the previous one, so submissions never burst even when many mints start
+/// at once. A random delay of up to a tenth of the interval is added to every slot, so
+/// submissions do not align on exact ticks; the next slot is reserved after that delay, so
+/// it never brings two submissions closer than one interval.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    interval: Duration,
+    next_slot: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    /// Creates a limiter allowing `per_second` submissions per second.
+    pub fn new(per_second: f64) -> Self {
+        Self {
+            interval: Duration::from_secs_f64(1.0 / per_second),
+            next_slot: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Waits until the caller may submit its transaction.
+    pub async fn acquire(&self) {
+        let slot = {
+            let mut next_slot = self.next_slot.lock().await;
+            let jitter = self
+                .interval
+                .mul_f64(rand::thread_rng().gen_range(0.0..0.1));
+            let slot = (*next_slot).max(Instant::now()) + jitter;
+            *next_slot = slot + self.interval;
+            slot
+        };
+
+        tokio::time::sleep_until(slot).await;
+    }
+}
+
+/// Staggers the start of the accounts of a mint run by random delays.
+///
+/// Every caller of [`wait`](Self::wait) launches a random delay drawn from the range after
+/// the previous launch, or after now if that is later, so starts never bunch up even when
+/// a whole batch begins at once. Delays are drawn in the order the callers arrive, from an
+/// RNG seeded with the given seed when there is one, which makes a run reproducible.
+#[derive(Debug)]
+pub(crate) struct LaunchScheduler {
+    delay: Range<Duration>,
+    state: Mutex<(Instant, StdRng)>,
+}
+
+impl LaunchScheduler {
+    /// Creates a scheduler drawing delays from `delay`, with an RNG seeded by `seed` if given.
+    pub fn new(delay: Range<Duration>, seed: Option<u64>) -> Self {
+        let rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        Self {
+            delay,
+            state: Mutex::new((Instant::now(), rng)),
+        }
+    }
+
+    /// Waits until the caller may start.
+    pub async fn wait(&self) {
+        let launch = {
+            let mut state = self.state.lock().await;
+            let (last_launch, rng) = &mut *state;
+            let launch = (*last_launch).max(Instant::now()) + rng.gen_range(self.delay.clone());
+            *last_launch = launch;
+            launch
+        };
+
+        tokio::time::sleep_until(launch).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::future::join_all;
+    use std::sync::Arc;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rate_limiter_spacing() {
+        let limiter = Arc::new(RateLimiter::new(20.0));
+        let start = Instant::now();
+
+        // all acquired at once, but released one interval apart
+        let mut released = join_all((0..11).map(|_| {
+            let limiter = limiter.clone();
+            async move {
+                limiter.acquire().await;
+                start.elapsed()
+            }
+        }))
+        .await;
+
+        released.sort();
+        assert!(released[0] < Duration::from_millis(50));
+        assert!(released[10] >= Duration::from_millis(500));
+        // ten intervals plus at most a tenth of an interval of jitter per slot
+        assert!(released[10] <= Duration::from_millis(555));
+        for pair in released.windows(2) {
+            assert!(pair[1] - pair[0] >= Duration::from_millis(50));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_launch_scheduler_spreads_starts() {
+        let delay = Duration::from_millis(20)..Duration::from_millis(30);
+        let scheduler = Arc::new(LaunchScheduler::new(delay, Some(7)));
+        let start = Instant::now();
+
+        // all waiting at once, but started one after another
+        let mut started = join_all((0..5).map(|_| {
+            let scheduler = scheduler.clone();
+            async move {
+                scheduler.wait().await;
+                start.elapsed()
+            }
+        }))
+        .await;
+
+        started.sort();
+        assert!(started[0] >= Duration::from_millis(20));
+        for pair in started.windows(2) {
+            assert!(pair[1] - pair[0] >= Duration::from_millis(20));
+        }
+        // the worst case is five maximal delays in a row
+        assert!(started[4] < Duration::from_millis(5 * 30 + 100));
+    }
+
+    #[tokio::test]
+    async fn test_launch_scheduler_seed() {
+        let delay = Duration::from_millis(1)..Duration::from_millis(100);
+        let launches = |seed| {
+            let scheduler = LaunchScheduler::new(delay.clone(), Some(seed));
+            let mut rng = scheduler.state.into_inner().1;
+            (0..5)
+                .map(|_| rng.gen_range(delay.clone()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(launches(1), launches(1));
+        assert_ne!(launches(1), launches(2));
+    }
+}
@@ -0,0 +1,183 @@
+use eyre::Result;
+use indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget, ProgressStyle};
+
+/// Receives the progress updates of a long-running operation.
+///
+/// Any `Fn(&T)` closure is a progress callback, and [`ProgressBarReporter`] renders updates
+/// as a terminal progress bar. Functions taking an `Option<&dyn Progress<T>>` stay silent
+/// when given `None`.
+pub trait Progress<T>: Send + Sync {
+    /// Called with the latest state after each step of the operation.
+    fn update(&self, update: &T);
+
+    /// Called once the operation completes.
+    fn finish(&self) {}
+}
+
+impl<T, F> Progress<T> for F
+where
+    F: Fn(&T) + Send + Sync,
+{
+    fn update(&self, update: &T) {
+        self(update)
+    }
+}
+
+/// A progress update that can be rendered on a progress bar.
+pub trait ProgressUpdate {
+    /// The number of steps completed.
+    fn position(&self) -> u64;
+
+    /// A short status shown next to the bar.
+    fn message(&self) -> String {
+        String::new()
+    }
+}
+
+/// Renders progress as an indicatif bar on stderr.
+///
+/// Nothing is drawn when stderr is not a terminal, so the reporter is safe to use in
+/// scripts and CI logs.
+#[derive(Debug, Clone)]
+pub struct ProgressBarReporter {
+    bar: ProgressBar,
+}
+
+impl ProgressBarReporter {
+    /// Creates a progress bar.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - The total number of steps.
+    /// * `label` - What the steps are, e.g. "accounts generation".
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self>` - The reporter, or an error if the bar template is invalid.
+    pub fn new(len: u64, label: &str) -> Result<Self> {
+        let bar = ProgressBar::new(len);
+        bar.set_style(ProgressStyle::default_bar()
+            .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} {prefix} ({percent}%) ETA: {eta_precise} {msg}")?
+            .progress_chars("=>-"));
+        bar.set_prefix(label.to_string());
+
+        Ok(Self { bar })
+    }
+
+    /// Advances the bar by `delta` steps.
+    pub(crate) fn inc(&self, delta: u64) {
+        self.bar.inc(delta);
+    }
+
+    /// Completes the bar, leaving `message` next to it.
+    pub(crate) fn finish_with_message(&self, message: &'static str) {
+        self.bar.finish_with_message(message);
+    }
+}
+
+impl<T: ProgressUpdate> Progress<T> for ProgressBarReporter {
+    fn update(&self, update: &T) {
+        self.bar.set_position(update.position());
+        self.bar.set_message(update.message());
+    }
+
+    fn finish(&self) {
+        self.bar.finish();
+    }
+}
+
+/// Renders the progress of a mint run as two bars on stderr: the overall completion, and a
+/// live count of the mints that succeeded and failed, taken from the update message.
+///
+/// Like [`ProgressBarReporter`], nothing is drawn when stderr is not a terminal.
+#[derive(Debug)]
+pub(crate) struct MintProgressBar {
+    overall: ProgressBar,
+    tally: ProgressBar,
+}
+
+impl MintProgressBar {
+    /// Creates the bars of a run of `len` mints.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self>` - The bars, or an error if a bar template is invalid.
+    pub fn new(len: u64) -> Result<Self> {
+        Self::with_draw_target(len, ProgressDrawTarget::stderr())
+    }
+
+    /// Creates the bars of a run of `len` mints, drawn to `target`.
+    fn with_draw_target(len: u64, target: ProgressDrawTarget) -> Result<Self> {
+        let bars = MultiProgress::with_draw_target(target);
+
+        let overall = bars.add(ProgressBar::new(len));
+        overall.set_style(ProgressStyle::default_bar()
+            .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} mints ({percent}%) ETA: {eta_precise}")?
+            .progress_chars("=>-"));
+
+        let tally = bars.add(ProgressBar::new_spinner());
+        tally.set_style(ProgressStyle::default_spinner().template("{msg}")?);
+
+        Ok(Self { overall, tally })
+    }
+}
+
+impl<T: ProgressUpdate> Progress<T> for MintProgressBar {
+    fn update(&self, update: &T) {
+        self.overall.set_position(update.position());
+        self.tally.set_message(update.message());
+    }
+
+    // leaves the final tally on screen
+    fn finish(&self) {
+        self.overall.finish();
+        self.tally.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mint::MintProgress;
+    use std::sync::Mutex;
+
+    fn run(steps: u64, progress: Option<&dyn Progress<u64>>) {
+        for step in 1..=steps {
+            if let Some(progress) = progress {
+                progress.update(&step);
+            }
+        }
+    }
+
+    #[test]
+    fn test_closure_progress() {
+        let updates = Mutex::new(Vec::new());
+        let collect = |step: &u64| updates.lock().unwrap().push(*step);
+
+        run(3, Some(&collect));
+        run(3, None);
+
+        assert_eq!(*updates.lock().unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_mint_progress_tally() {
+        let bars = MintProgressBar::with_draw_target(3, ProgressDrawTarget::hidden()).unwrap();
+
+        let mut state = MintProgress {
+            total: 3,
+            ..Default::default()
+        };
+        bars.update(&state);
+        assert_eq!(bars.tally.message(), "✅ 0 / ❌ 0");
+
+        for success in [Some(true), Some(false), Some(true)] {
+            state.record(success);
+            bars.update(&state);
+        }
+        Progress::<MintProgress>::finish(&bars);
+
+        assert_eq!(bars.overall.position(), 3);
+        assert_eq!(bars.tally.message(), "✅ 2 / ❌ 1");
+    }
+}
@@ -38,7 +38,17 @@ impl TestEnvironment {
     /// - The Anvil instance cannot be spawned.
     /// - The provider cannot be built.
     pub fn new(accounts_len: Option<usize>) -> Result<TestEnvironment> {
-        let anvil = Anvil::default().try_spawn()?;
+        Self::with_anvil(Anvil::default(), accounts_len)
+    }
+
+    /// Generates a `TestEnvironment` from a custom-configured Anvil builder.
+    ///
+    /// # Arguments
+    ///
+    /// * `anvil` - The Anvil builder, e.g. with a specific hardfork enabled.
+    /// * `accounts_len` - An optional number of accounts to generate.
+    pub fn with_anvil(anvil: Anvil, accounts_len: Option<usize>) -> Result<TestEnvironment> {
+        let anvil = anvil.try_spawn()?;
         let private_keys = anvil.keys();
 
         let signers: Vec<PrivateKeySigner> = private_keys
@@ -5,7 +5,7 @@ use alloy::providers::Provider;
 use eyre::Result;
 use stormint::account::generate_accounts;
 use stormint::distributor::{distribute, DistributeParam};
-use stormint::mint::mint_loop;
+use stormint::mint::{mint_loop_with, MintOptions};
 
 const MNEMONIC: &str = "test test test test test test test test test test test junk";
 const START_INDEX: u32 = 100;
@@ -50,14 +50,12 @@ async fn test_workflow() -> Result<()> {
     let mint_address = deploy_contract(provider.clone(), bytecode).await?;
 
     // mint tokens to receiver accounts
-    let results = mint_loop(
+    let results = mint_loop_with(
         receivers,
         url.clone(),
         abi.clone(),
         mint_address,
-        None,
-        None,
-        None,
+        MintOptions::default(),
     )
     .await?;
 
@@ -0,0 +1,74 @@
+use crate::common::{deploy_contract, parse_artifact, TestEnvironment};
+use alloy::network::TransactionBuilder;
+use alloy::primitives::utils::parse_ether;
+use alloy::providers::Provider;
+use alloy::rpc::types::TransactionRequest;
+use alloy::signers::local::PrivateKeySigner;
+use eyre::Result;
+use stormint::executor::{
+    assert_enough_balance, execute_with_options, ExecuteOptions, InsufficientBalanceError,
+};
+
+const FREE_MINT_ARTIFACT_PATH: &str = "contracts/out/FreeMint.sol/FreeMint.json";
+
+#[tokio::test]
+async fn test_assert_enough_balance() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let url = test_env.url;
+    let funded = test_env.signers[0].address();
+    let empty = PrivateKeySigner::random().address();
+
+    assert_enough_balance(url.clone(), funded, parse_ether("1")?).await?;
+
+    let error = assert_enough_balance(url, empty, parse_ether("1.5")?)
+        .await
+        .unwrap_err();
+    let insufficient = error.downcast_ref::<InsufficientBalanceError>().unwrap();
+    assert_eq!(insufficient.address, empty);
+    assert!(insufficient.available.is_zero());
+    assert!(error
+        .to_string()
+        .contains("(1.500000000000000000 ETH short)"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_check_balance() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+
+    let (abi, bytecode) = parse_artifact(FREE_MINT_ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // the account holds some Ether, but less than the value it sends
+    let account = PrivateKeySigner::random();
+    let tx = TransactionRequest::default()
+        .with_from(test_env.signers[0].address())
+        .with_to(account.address())
+        .with_value(parse_ether("0.01")?);
+    provider.send_transaction(tx).await?.get_receipt().await?;
+
+    let options = ExecuteOptions {
+        value: Some(parse_ether("1")?),
+        check_balance: true,
+        ..Default::default()
+    };
+    let error = execute_with_options(
+        account.clone(),
+        url,
+        abi,
+        contract_address,
+        "mint",
+        &[],
+        options,
+    )
+    .await
+    .unwrap_err();
+
+    let insufficient = error.downcast_ref::<InsufficientBalanceError>().unwrap();
+    assert_eq!(insufficient.required, parse_ether("1")?);
+    assert_eq!(provider.get_transaction_count(account.address()).await?, 0);
+
+    Ok(())
+}
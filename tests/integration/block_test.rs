@@ -0,0 +1,31 @@
+use crate::common::TestEnvironment;
+use alloy::eips::BlockId;
+use alloy::providers::Provider;
+use eyre::Result;
+use stormint::executor::{estimate_gas_cost_eth, get_block};
+
+#[tokio::test]
+async fn test_get_block() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+
+    let latest = get_block(url.clone(), BlockId::latest()).await?;
+    assert_eq!(latest.number, provider.get_block_number().await?);
+    assert!(latest.timestamp > 0);
+    assert!(latest.gas_limit > 0);
+    assert!(latest.base_fee_per_gas.is_some());
+
+    let genesis = get_block(url.clone(), BlockId::number(0)).await?;
+    assert_eq!(genesis.number, 0);
+    assert!(genesis.timestamp <= latest.timestamp);
+
+    assert!(get_block(url.clone(), BlockId::number(latest.number + 100))
+        .await
+        .is_err());
+
+    // the gas cost is priced with the base fee of the latest block
+    let cost: f64 = estimate_gas_cost_eth(url, 21_000).await?.parse()?;
+    assert!(cost > 0.0);
+
+    Ok(())
+}
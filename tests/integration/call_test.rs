@@ -0,0 +1,199 @@
+use crate::common::{deploy_contract, parse_artifact, TestEnvironment};
+use alloy::dyn_abi::DynSolValue;
+use alloy::transports::http::reqwest::Url;
+use eyre::Result;
+use std::sync::Arc;
+use stormint::executor::{
+    call, call_many, call_with_cache, call_with_fragment, execute, CallCache, HttpAuth,
+    ProviderConfig,
+};
+
+const ARTIFACT_PATH: &str = "contracts/out/FreeMint.sol/FreeMint.json";
+const TOKEN_ARTIFACT_PATH: &str = "contracts/out/MockToken.sol/MockToken.json";
+const DECIMALS_FRAGMENT: &str = r#"[{"type":"function","name":"decimals","inputs":[],"outputs":[{"name":"","type":"uint8"}],"stateMutability":"view"}]"#;
+/// An endpoint nothing listens on, so any request to it fails.
+const UNREACHABLE_URL: &str = "http://127.0.0.1:1";
+
+#[tokio::test]
+async fn test_call_cache() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let cache = Arc::new(CallCache::new());
+    let value = call_with_cache(
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        "totalSupply",
+        &[],
+        Some(cache.clone()),
+    )
+    .await?;
+    assert_eq!(cache.len(), 1);
+
+    execute(
+        signers[0].clone(),
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        "mint",
+        &[],
+        None,
+    )
+    .await?;
+
+    // the second call is served from the cache, so it misses the mint
+    let cached = call_with_cache(
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        "totalSupply",
+        &[],
+        Some(cache.clone()),
+    )
+    .await?;
+    assert_eq!(cached, value);
+
+    // results cached for one endpoint are never served for another
+    let unreachable: Url = UNREACHABLE_URL.parse()?;
+    let result = call_with_cache(
+        unreachable,
+        abi.clone(),
+        contract_address,
+        "totalSupply",
+        &[],
+        Some(cache.clone()),
+    )
+    .await;
+    assert!(result.is_err());
+    assert_eq!(cache.len(), 1);
+
+    cache.invalidate_all();
+    assert!(cache.is_empty());
+    let fresh =
+        call_with_cache(url, abi, contract_address, "totalSupply", &[], Some(cache)).await?;
+    assert_ne!(fresh, value);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_call_many() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let cache = Arc::new(CallCache::new());
+    let calls = [
+        ("totalSupply", vec![]),
+        ("balanceOf", vec![DynSolValue::from(signers[0].address())]),
+        ("totalSupply", vec![]),
+    ];
+    let values = call_many(url, abi, contract_address, &calls, Some(cache.clone())).await?;
+
+    assert_eq!(values.len(), calls.len());
+    assert_eq!(values[0], values[2]);
+    assert_eq!(cache.len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_call_with_fragment() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+
+    // the same query on two unrelated ERC-20 contracts
+    let mut contracts = Vec::new();
+    for path in [ARTIFACT_PATH, TOKEN_ARTIFACT_PATH] {
+        let (_, bytecode) = parse_artifact(path)?;
+        contracts.push(deploy_contract(provider.clone(), bytecode).await?);
+    }
+    for contract_address in contracts.iter().copied() {
+        let decimals = call_with_fragment(
+            url.clone(),
+            contract_address,
+            DECIMALS_FRAGMENT,
+            "decimals",
+            &[],
+        )
+        .await?;
+        assert_eq!(decimals, [DynSolValue::from(18u8)]);
+    }
+
+    let error = call_with_fragment(url.clone(), contracts[0], DECIMALS_FRAGMENT, "symbol", &[])
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("no function `symbol`"));
+
+    let error = call_with_fragment(url, contracts[0], "decimals()", "decimals", &[])
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("invalid ABI fragment"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_call_with_auth() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(2))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // anvil ignores the header, but every request carries it
+    let expected = call(
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        "MINT_AMOUNT",
+        &[],
+    )
+    .await?;
+    let auths = [
+        HttpAuth::basic("user", "password"),
+        HttpAuth::bearer("token"),
+    ];
+    // every account mints once
+    for (auth, signer) in auths.into_iter().zip(signers) {
+        let config = ProviderConfig::new(url.clone()).with_auth(auth);
+
+        let value = call(
+            config.clone(),
+            abi.clone(),
+            contract_address,
+            "MINT_AMOUNT",
+            &[],
+        )
+        .await?;
+        assert_eq!(value, expected);
+
+        execute(
+            signer,
+            config,
+            abi.clone(),
+            contract_address,
+            "mint",
+            &[],
+            None,
+        )
+        .await?;
+    }
+
+    let conflicting = HttpAuth {
+        basic: Some(("user".to_string(), "password".to_string())),
+        bearer: Some("token".to_string()),
+    };
+    let config = ProviderConfig::new(url).with_auth(conflicting);
+    assert!(call(config, abi, contract_address, "MINT_AMOUNT", &[])
+        .await
+        .is_err());
+
+    Ok(())
+}
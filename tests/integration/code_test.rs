@@ -0,0 +1,71 @@
+use crate::common::{deploy_contract, parse_artifact, TestEnvironment};
+use alloy::eips::BlockId;
+use alloy::primitives::utils::parse_ether;
+use eyre::Result;
+use stormint::distributor::{distribute_with_options, DistributeOptions, DistributeParam};
+use stormint::executor::{execute_with_options, get_code, is_contract, ExecuteOptions};
+
+const FREE_MINT_ARTIFACT_PATH: &str = "contracts/out/FreeMint.sol/FreeMint.json";
+const DISTRIBUTOR_ARTIFACT_PATH: &str = "contracts/out/Distributor.sol/Distributor.json";
+
+#[tokio::test]
+async fn test_get_code() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (_, bytecode) = parse_artifact(FREE_MINT_ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let code = get_code(url.clone(), contract_address, None).await?;
+    assert!(!code.is_empty());
+
+    // nothing was deployed at genesis
+    let code = get_code(url.clone(), contract_address, Some(BlockId::number(0))).await?;
+    assert!(code.is_empty());
+
+    assert!(is_contract(url.clone(), contract_address).await?);
+    assert!(!is_contract(url, signers[0].address()).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_check_contract() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(3))?;
+    let (url, signers) = (test_env.url, test_env.signers);
+    let eoa = signers[1].address();
+
+    let (abi, _) = parse_artifact(FREE_MINT_ARTIFACT_PATH)?;
+    let options = ExecuteOptions {
+        check_contract: true,
+        ..Default::default()
+    };
+    let error = execute_with_options(
+        signers[0].clone(),
+        url.clone(),
+        abi,
+        eoa,
+        "mint",
+        &[],
+        options,
+    )
+    .await
+    .unwrap_err();
+    assert!(error.to_string().contains("no contract deployed"));
+
+    let (abi, _) = parse_artifact(DISTRIBUTOR_ARTIFACT_PATH)?;
+    let params = vec![DistributeParam {
+        receiver: signers[2].address(),
+        amount: parse_ether("0.001")?,
+    }];
+    let options = DistributeOptions {
+        check_contract: true,
+        ..Default::default()
+    };
+    let error = distribute_with_options(signers[0].clone(), url, abi, eoa, params, options)
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("no contract deployed"));
+
+    Ok(())
+}
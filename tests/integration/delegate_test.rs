@@ -0,0 +1,172 @@
+use crate::common::{deploy_contract, get_token_balance, parse_artifact, TestEnvironment};
+use alloy::dyn_abi::DynSolValue;
+use alloy::primitives::utils::parse_ether;
+use alloy::primitives::{keccak256, U256};
+use alloy::providers::Provider;
+use alloy::signers::SignerSync;
+use alloy_node_bindings::Anvil;
+use eyre::Result;
+use stormint::distributor::{distribute, distribute_direct, DistributeParam};
+use stormint::executor::{build_calldata, execute};
+use stormint::mint::mint_delegated;
+
+const EXECUTOR_ARTIFACT_PATH: &str = "contracts/out/BatchExecutor.sol/BatchExecutor.json";
+const TOKEN_ARTIFACT_PATH: &str = "contracts/out/MockToken.sol/MockToken.json";
+const DISTRIBUTOR_ARTIFACT_PATH: &str = "contracts/out/Distributor.sol/Distributor.json";
+
+#[tokio::test]
+async fn test_mint_delegated() -> Result<()> {
+    let anvil = Anvil::default().args(["--hardfork", "prague"]);
+    let test_env = TestEnvironment::with_anvil(anvil, Some(4))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (_, bytecode) = parse_artifact(EXECUTOR_ARTIFACT_PATH)?;
+    let executor_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let (abi, bytecode) = parse_artifact(TOKEN_ARTIFACT_PATH)?;
+    let token_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let sponsor = signers[0].clone();
+    let accounts = signers[1..].to_vec();
+    let tx_hash = mint_delegated(
+        sponsor,
+        accounts.clone(),
+        url.clone(),
+        executor_address,
+        abi.clone(),
+        token_address,
+        None,
+        None,
+    )
+    .await?;
+
+    let receipt = provider.get_transaction_receipt(tx_hash).await?.unwrap();
+    assert!(receipt.status());
+
+    // every delegated account minted, without sending a transaction itself
+    for account in accounts {
+        let balance =
+            get_token_balance(url.clone(), abi.clone(), token_address, account.address()).await?;
+        assert!(balance > U256::ZERO);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delegated_accounts_need_intents() -> Result<()> {
+    let anvil = Anvil::default().args(["--hardfork", "prague"]);
+    let test_env = TestEnvironment::with_anvil(anvil, Some(3))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (executor_abi, bytecode) = parse_artifact(EXECUTOR_ARTIFACT_PATH)?;
+    let executor_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let (abi, bytecode) = parse_artifact(TOKEN_ARTIFACT_PATH)?;
+    let token_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // the account is delegated by a legitimate sponsored mint
+    let (sponsor, account, stranger) = (signers[0].clone(), signers[1].clone(), signers[2].clone());
+    mint_delegated(
+        sponsor,
+        vec![account.clone()],
+        url.clone(),
+        executor_address,
+        abi.clone(),
+        token_address,
+        None,
+        None,
+    )
+    .await?;
+    let balance =
+        get_token_balance(url.clone(), abi.clone(), token_address, account.address()).await?;
+
+    // a stranger signing the intent in place of the account cannot make it call anything
+    let calldata = build_calldata(&abi, "mint", &[])?;
+    let forged = stranger.sign_message_sync(keccak256(&calldata).as_slice())?;
+    let args = [
+        DynSolValue::Array(vec![DynSolValue::from(account.address())]),
+        DynSolValue::from(token_address),
+        DynSolValue::Bytes(calldata.to_vec()),
+        DynSolValue::from(U256::MAX),
+        DynSolValue::Array(vec![DynSolValue::Bytes(forged.as_bytes().to_vec())]),
+    ];
+    let attack = execute(
+        stranger,
+        url.clone(),
+        executor_abi,
+        executor_address,
+        "executeFor",
+        &args,
+        None,
+    )
+    .await;
+    assert!(attack.is_err());
+
+    let after = get_token_balance(url, abi, token_address, account.address()).await?;
+    assert_eq!(after, balance);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fund_delegated_accounts() -> Result<()> {
+    let anvil = Anvil::default().args(["--hardfork", "prague"]);
+    let test_env = TestEnvironment::with_anvil(anvil, Some(3))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (_, bytecode) = parse_artifact(EXECUTOR_ARTIFACT_PATH)?;
+    let executor_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let (abi, bytecode) = parse_artifact(TOKEN_ARTIFACT_PATH)?;
+    let token_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let (distributor_abi, bytecode) = parse_artifact(DISTRIBUTOR_ARTIFACT_PATH)?;
+    let distributor_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let (sponsor, accounts) = (signers[0].clone(), signers[1..].to_vec());
+    mint_delegated(
+        sponsor.clone(),
+        accounts.clone(),
+        url.clone(),
+        executor_address,
+        abi,
+        token_address,
+        None,
+        None,
+    )
+    .await?;
+    for account in &accounts {
+        assert!(!provider.get_code_at(account.address()).await?.is_empty());
+    }
+
+    let amount = parse_ether("0.01")?;
+    let params = DistributeParam::from_accounts(&accounts, amount);
+    let mut before = Vec::with_capacity(accounts.len());
+    for account in &accounts {
+        before.push(provider.get_balance(account.address()).await?);
+    }
+
+    // the distributor funds delegated accounts with a plain call
+    distribute(
+        sponsor.clone(),
+        url.clone(),
+        distributor_abi,
+        distributor_address,
+        params.clone(),
+    )
+    .await?;
+    // and so do direct transfers
+    let direct = distribute_direct(sponsor, url, params).await?;
+    assert!(direct
+        .transfers
+        .iter()
+        .all(|transfer| transfer.result.is_ok()));
+
+    for (account, before) in accounts.iter().zip(before) {
+        let balance = provider.get_balance(account.address()).await?;
+        assert_eq!(balance, before + amount * U256::from(2));
+    }
+
+    Ok(())
+}
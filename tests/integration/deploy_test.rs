@@ -0,0 +1,104 @@
+use crate::common::{parse_artifact, TestEnvironment};
+use eyre::Result;
+use stormint::executor::{
+    compute_create2_address, create2_deploy, deploy, is_contract, CREATE2_FACTORY,
+};
+
+const DISTRIBUTOR_ARTIFACT_PATH: &str = "contracts/out/Distributor.sol/Distributor.json";
+
+#[tokio::test]
+async fn test_deploy() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (url, signer) = (test_env.url, test_env.signers[0].clone());
+
+    let (_, bytecode) = parse_artifact(DISTRIBUTOR_ARTIFACT_PATH)?;
+    let result = deploy(signer, url.clone(), bytecode.into()).await?;
+
+    assert!(is_contract(url, result.address).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create2_deploy() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (url, signer) = (test_env.url, test_env.signers[0].clone());
+
+    let (_, bytecode) = parse_artifact(DISTRIBUTOR_ARTIFACT_PATH)?;
+    let init_code = bytecode.into();
+    let salt = [7u8; 32];
+
+    let expected = compute_create2_address(CREATE2_FACTORY, salt, &init_code);
+    assert!(!is_contract(url.clone(), expected).await?);
+
+    let result = create2_deploy(
+        signer.clone(),
+        url.clone(),
+        CREATE2_FACTORY,
+        salt,
+        init_code.clone(),
+    )
+    .await?;
+
+    assert_eq!(result.address, expected);
+    assert!(is_contract(url.clone(), expected).await?);
+
+    // the address is taken, so the same salt cannot deploy again
+    assert!(
+        create2_deploy(signer, url, CREATE2_FACTORY, salt, init_code)
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "embedded-contracts")]
+#[tokio::test]
+async fn test_deploy_embedded_distributor() -> Result<()> {
+    use alloy::primitives::utils::parse_ether;
+    use alloy::providers::Provider;
+    use stormint::account::generate_accounts;
+    use stormint::distributor::{self, distribute, distributor_artifact, DistributeParam};
+
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url, signer) = (test_env.provider, test_env.url, test_env.signers[0].clone());
+
+    assert!(!distributor_artifact()?.compiler_version.is_empty());
+
+    let (contract_address, abi) = distributor::deploy(signer.clone(), url.clone()).await?;
+
+    let receivers = generate_accounts(
+        "test test test test test test test test test test test junk",
+        100,
+        110,
+    )?;
+    let each_amount = parse_ether("0.001")?;
+    let params = DistributeParam::from_signers(&receivers, each_amount)?;
+
+    distribute(signer, url, abi, contract_address, params).await?;
+
+    for receiver in receivers {
+        let balance = provider.get_balance(receiver.address()).await?;
+        assert_eq!(balance, each_amount);
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "embedded-contracts")]
+#[test]
+fn test_embedded_distributor_is_up_to_date() -> Result<()> {
+    use alloy::primitives::keccak256;
+    use stormint::distributor::distributor_artifact;
+
+    let source = std::fs::read("contracts/src/Distributor.sol")?;
+
+    assert_eq!(
+        distributor_artifact()?.source_hash,
+        keccak256(source),
+        "the embedded artifact is stale, run contracts/script/export-artifact.sh"
+    );
+
+    Ok(())
+}
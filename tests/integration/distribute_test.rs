@@ -1,11 +1,35 @@
 use crate::common::{deploy_contract, parse_artifact, TestEnvironment};
+use alloy::consensus::Transaction;
 use alloy::primitives::utils::parse_ether;
+use alloy::primitives::{Address, U256};
 use alloy::providers::Provider;
+use alloy::rpc::types::BlockNumberOrTag;
+use alloy::signers::local::PrivateKeySigner;
+use alloy::transports::http::reqwest::{Client, Url};
 use eyre::Result;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use stormint::account::generate_accounts;
-use stormint::distributor::{distribute, DistributeParam};
+use stormint::distributor::{
+    batch_distribute_from_csv, distribute, distribute_chunked, distribute_chunked_with_options,
+    distribute_chunked_with_progress, distribute_direct, distribute_direct_with_progress,
+    distribute_equal, distribute_multi, distribute_safe, distribute_with_memo,
+    distribute_with_options, distribute_with_receipt, encode, estimate, estimate_chunk_size,
+    estimate_distribute_gas, estimate_marginal_gas, query_distribution_events, resume,
+    suggest_chunk_size, top_up, verify, ContractRecipientPolicy, DistributeOptions,
+    DistributeParam, DistributeParamWithMemo, DistributeProgress, DistributionRun,
+    DistributionRunError, DistributorKind, InvalidParamsError, ResumeOptions, SplitStrategy,
+    VerifyMode, Violation,
+};
+use stormint::progress::Progress;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
 
 const ARTIFACT_PATH: &str = "contracts/out/Distributor.sol/Distributor.json";
+const DISPERSE_ARTIFACT_PATH: &str = "contracts/out/MockDisperse.sol/MockDisperse.json";
+const RECEIVER_ARTIFACT_PATH: &str = "contracts/out/MockReceiver.sol/MockReceiver.json";
+const FREE_MINT_ARTIFACT_PATH: &str = "contracts/out/FreeMint.sol/FreeMint.json";
 const MNEMONIC: &str = "test test test test test test test test test test test junk";
 const START_INDEX: u32 = 100;
 const END_INDEX: u32 = 200;
@@ -49,3 +73,1248 @@ async fn test_distribute() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_distribute_with_memo() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // an empty memo is sent as is
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 10)?;
+    let each_amount = parse_ether("0.001")?;
+    let params: Vec<DistributeParamWithMemo> = receivers
+        .iter()
+        .enumerate()
+        .map(|(i, r)| DistributeParamWithMemo {
+            receiver: r.address(),
+            amount: each_amount,
+            memo: if i % 2 == 0 {
+                format!("batch-{i}").into_bytes()
+            } else {
+                Vec::new()
+            },
+        })
+        .collect();
+
+    // the checks of the options apply to memo distributions too
+    let options = DistributeOptions {
+        check_duplicates: true,
+        ..Default::default()
+    };
+    let mut duplicated = params.clone();
+    duplicated.push(params[0].clone());
+    let rejected = distribute_with_memo(
+        signer.clone(),
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        duplicated,
+        options.clone(),
+    )
+    .await;
+    assert!(rejected.is_err());
+
+    let result =
+        distribute_with_memo(signer, url.clone(), abi, contract_address, params, options).await?;
+    assert_eq!(result.recipient_count, receivers.len());
+    assert_eq!(
+        result.total_distributed,
+        each_amount * U256::from(receivers.len())
+    );
+
+    // check one memo event per receiver, plus the distribute event
+    let distribute_receipt = provider
+        .get_transaction_receipt(result.tx_hash)
+        .await?
+        .unwrap();
+    assert!(distribute_receipt.status());
+    assert_eq!(distribute_receipt.inner.logs().len(), receivers.len() + 1);
+
+    for receiver in receivers {
+        let balance = provider.get_balance(receiver.address()).await?;
+        assert_eq!(balance, each_amount);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_distribute_with_receipt() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 10)?;
+    let params: Vec<DistributeParam> = receivers
+        .iter()
+        .enumerate()
+        .map(|(i, r)| DistributeParam {
+            receiver: r.address(),
+            amount: U256::from(i + 1) * parse_ether("0.001").unwrap(),
+        })
+        .collect();
+    let total: U256 = params.iter().map(|param| param.amount).sum();
+
+    let result =
+        distribute_with_receipt(signer, url.clone(), abi, contract_address, params).await?;
+
+    assert!(result.status);
+    assert_eq!(result.total_distributed, total);
+    assert_eq!(result.recipient_count, receivers.len());
+    assert!(result.gas_used > 0);
+
+    let receipt = provider
+        .get_transaction_receipt(result.tx_hash)
+        .await?
+        .unwrap();
+    assert_eq!(receipt.block_number, Some(result.block_number));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_distribute_chunked() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 300)?;
+    let each_amount = parse_ether("0.001")?;
+    let params = DistributeParam::from_accounts(&receivers, each_amount);
+
+    let chunk_size = estimate_chunk_size(
+        signer.address(),
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        &params[..10],
+    )
+    .await?;
+    assert!(chunk_size >= 100);
+
+    let results =
+        distribute_chunked(signer, url.clone(), abi, contract_address, params, 100).await?;
+
+    // three receipts of 100 recipients each
+    assert_eq!(results.len(), 3);
+    for result in &results {
+        assert!(result.status);
+        assert_eq!(result.recipient_count, 100);
+    }
+
+    for receiver in receivers {
+        let balance = provider.get_balance(receiver.address()).await?;
+        assert_eq!(balance, each_amount);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_distribute_chunked_single_block() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 30)?;
+    let each_amount = parse_ether("0.001")?;
+    let params = DistributeParam::from_signers(&receivers, each_amount)?;
+
+    // from now on blocks are only mined on request
+    provider
+        .raw_request::<_, ()>("evm_setAutomine".into(), (false,))
+        .await?;
+    let nonce = provider.get_transaction_count(signer.address()).await?;
+
+    let distribution = tokio::spawn(distribute_chunked(
+        signer.clone(),
+        url,
+        abi,
+        contract_address,
+        params,
+        10,
+    ));
+
+    // all three chunks are submitted without waiting for a block
+    while provider
+        .get_transaction_count(signer.address())
+        .pending()
+        .await?
+        < nonce + 3
+    {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    provider
+        .raw_request::<_, String>("evm_mine".into(), ())
+        .await?;
+
+    let results = distribution.await??;
+    assert_eq!(results.len(), 3);
+
+    let mut last_index = None;
+    for result in &results {
+        assert!(result.status);
+        assert_eq!(result.block_number, results[0].block_number);
+
+        let receipt = provider
+            .get_transaction_receipt(result.tx_hash)
+            .await?
+            .unwrap();
+        assert!(receipt.transaction_index > last_index);
+        last_index = receipt.transaction_index;
+    }
+
+    for receiver in receivers {
+        let balance = provider.get_balance(receiver.address()).await?;
+        assert_eq!(balance, each_amount);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_distribute_chunked_with_progress() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 25)?;
+    let params = DistributeParam::from_signers(&receivers, parse_ether("0.001")?)?;
+
+    let updates = Mutex::new(Vec::new());
+    let collect = |progress: &DistributeProgress| updates.lock().unwrap().push(*progress);
+
+    let results = distribute_chunked_with_progress(
+        signer,
+        url,
+        abi,
+        contract_address,
+        params,
+        10,
+        Some(&collect),
+    )
+    .await?;
+
+    // one update per chunk, with increasing counts
+    let updates = updates.into_inner().unwrap();
+    assert_eq!(updates.len(), 3);
+    for (i, update) in updates.iter().enumerate() {
+        assert_eq!(update.chunks_completed, i + 1);
+        assert_eq!(update.chunk_count, 3);
+    }
+    assert!(updates
+        .windows(2)
+        .all(|w| w[0].recipients_funded < w[1].recipients_funded && w[0].gas_used < w[1].gas_used));
+    assert_eq!(updates[2].recipients_funded, 25);
+    assert_eq!(
+        updates[2].gas_used,
+        results.iter().map(|result| result.gas_used).sum::<u64>()
+    );
+
+    Ok(())
+}
+
+/// Collects the progress of a distribution, and whether it was finished.
+#[derive(Default)]
+struct CollectProgress {
+    updates: Mutex<Vec<DistributeProgress>>,
+    finished: AtomicBool,
+}
+
+impl Progress<DistributeProgress> for CollectProgress {
+    fn update(&self, update: &DistributeProgress) {
+        self.updates.lock().unwrap().push(*update);
+    }
+
+    fn finish(&self) {
+        self.finished.store(true, Ordering::Relaxed);
+    }
+}
+
+#[tokio::test]
+async fn test_distribute_chunked_finishes_progress_on_failure() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // the token has no receive function, so the second chunk reverts
+    let (_, token_bytecode) = parse_artifact(FREE_MINT_ARTIFACT_PATH)?;
+    let token_address = deploy_contract(provider.clone(), token_bytecode).await?;
+
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 10)?;
+    let each_amount = parse_ether("0.001")?;
+    let mut params = DistributeParam::from_signers(&receivers, each_amount)?;
+    params.push(DistributeParam {
+        receiver: token_address,
+        amount: each_amount,
+    });
+
+    let progress = CollectProgress::default();
+    let result = distribute_chunked_with_progress(
+        signer,
+        url,
+        abi,
+        contract_address,
+        params,
+        10,
+        Some(&progress),
+    )
+    .await;
+    assert!(result.is_err());
+
+    // the second chunk fails on submission, before any receipt is awaited
+    assert!(progress.updates.lock().unwrap().is_empty());
+    assert!(progress.finished.load(Ordering::Relaxed));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resume() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let funder = test_env.signers.first().unwrap().clone();
+    let sender = PrivateKeySigner::random();
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 10)?;
+    let each_amount = parse_ether("0.1")?;
+    let params = DistributeParam::from_accounts(&receivers, each_amount);
+
+    // enough for the first chunk of 0.5 ether, not the second
+    let fund = |amount| {
+        distribute_direct(
+            funder.clone(),
+            url.clone(),
+            vec![DistributeParam {
+                receiver: sender.address(),
+                amount,
+            }],
+        )
+    };
+    fund(parse_ether("0.7")?).await?;
+
+    let options = ResumeOptions {
+        retries: 1,
+        retry_delay: Duration::from_millis(10),
+        check_balance: true,
+    };
+
+    let run = DistributionRun::new(params.clone(), 5)?;
+    let error = resume(
+        run,
+        sender.clone(),
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        options.clone(),
+    )
+    .await
+    .unwrap_err()
+    .downcast::<DistributionRunError>()?;
+
+    let run = error.run;
+    assert_eq!(run.funded(), 5);
+    assert_eq!(run.pending().len(), 5);
+
+    fund(parse_ether("1")?).await?;
+
+    let run = resume(run, sender, url.clone(), abi, contract_address, options).await?;
+    assert!(run.is_complete());
+    assert_eq!(run.results().len(), 2);
+    assert!(run.already_funded.is_empty());
+
+    // nobody was funded twice
+    let report = verify(url, &params, VerifyMode::Exact).await?;
+    assert!(report.passed());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resume_after_lost_receipt() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let sender = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 5)?;
+    let params = DistributeParam::from_accounts(&receivers, parse_ether("0.01")?);
+
+    // the node is unreachable for a while right after the transaction is sent
+    let proxy = drop_after_send_proxy(&url, Duration::from_secs(1)).await?;
+    let nonce = provider.get_transaction_count(sender.address()).await?;
+
+    let options = ResumeOptions {
+        retries: 3,
+        retry_delay: Duration::from_millis(500),
+        check_balance: false,
+    };
+    let run = DistributionRun::new(params.clone(), 5)?;
+    let run = resume(run, sender.clone(), proxy, abi, contract_address, options).await?;
+    assert!(run.is_complete());
+
+    // the retries awaited the transaction that was sent instead of sending another one
+    assert_eq!(run.chunks[0].tx_hash, Some(run.results()[0].tx_hash));
+    assert_eq!(
+        provider.get_transaction_count(sender.address()).await?,
+        nonce + 1
+    );
+    let report = verify(url, &params, VerifyMode::Exact).await?;
+    assert!(report.passed());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_distribute_safe() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 100)?;
+    let each_amount = parse_ether("0.001")?;
+    let params = DistributeParam::from_accounts(&receivers, each_amount);
+
+    let gas = estimate_distribute_gas(
+        signer.address(),
+        url.clone(),
+        &abi,
+        contract_address,
+        &params,
+    )
+    .await?;
+
+    // a threshold below the full batch forces a split
+    let results =
+        distribute_safe(signer, url.clone(), abi, contract_address, params, gas / 2).await?;
+
+    assert!(results.len() > 1);
+    assert!(results.iter().all(|result| result.status));
+    assert!(results.iter().all(|result| result.gas_used <= gas / 2));
+    let funded: usize = results.iter().map(|result| result.recipient_count).sum();
+    assert_eq!(funded, receivers.len());
+
+    for receiver in receivers {
+        let balance = provider.get_balance(receiver.address()).await?;
+        assert_eq!(balance, each_amount);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_encode() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 10)?;
+    let params = DistributeParam::from_accounts(&receivers, parse_ether("0.001")?);
+
+    let encoded = encode(&params, &abi)?;
+    assert_eq!(encoded.function, "distributeEther");
+    assert_eq!(encoded.total_value, parse_ether("0.01")?);
+
+    let tx_hash = distribute(signer, url, abi, contract_address, params).await?;
+    let tx = provider.get_transaction_by_hash(tx_hash).await?.unwrap();
+
+    assert_eq!(tx.input(), &encoded.calldata);
+    assert_eq!(tx.value(), encoded.total_value);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_distribute_rejects_zero_address() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 3)?;
+    let mut params = DistributeParam::from_accounts(&receivers, parse_ether("0.001")?);
+    params.push(DistributeParam {
+        receiver: Address::ZERO,
+        amount: parse_ether("0.001")?,
+    });
+
+    // the preview rejects what `distribute` rejects
+    assert!(encode(&params, &abi).is_err());
+
+    let nonce = provider.get_transaction_count(signer.address()).await?;
+    let error = distribute(signer.clone(), url, abi, contract_address, params)
+        .await
+        .unwrap_err();
+    let error = error.downcast::<InvalidParamsError>().unwrap();
+    assert_eq!(error.violations, [Violation::ZeroAddress { index: 3 }]);
+
+    // nothing was sent
+    assert_eq!(
+        provider.get_transaction_count(signer.address()).await?,
+        nonce
+    );
+    for receiver in receivers {
+        assert_eq!(provider.get_balance(receiver.address()).await?, U256::ZERO);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_estimate() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let sender = test_env.signers.first().unwrap().address();
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+    let nonce = provider.get_transaction_count(sender).await?;
+
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 50)?;
+    let params = |amount| DistributeParam::from_accounts(&receivers, amount);
+
+    let affordable = estimate(
+        sender,
+        url.clone(),
+        &abi,
+        contract_address,
+        &params(parse_ether("0.001")?),
+    )
+    .await?;
+    assert!(affordable.sufficient);
+    assert!(affordable.gas > 0);
+    assert_eq!(affordable.total_value, parse_ether("0.05")?);
+    assert!(affordable.total_cost > affordable.total_value);
+
+    // 50 * 1000 ether exceeds the balance of an anvil account
+    let unaffordable = estimate(
+        sender,
+        url.clone(),
+        &abi,
+        contract_address,
+        &params(parse_ether("1000")?),
+    )
+    .await?;
+    assert!(!unaffordable.sufficient);
+    assert_eq!(unaffordable.sender_balance, affordable.sender_balance);
+
+    // nothing was broadcast
+    assert_eq!(provider.get_transaction_count(sender).await?, nonce);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_estimate_marginal_gas() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // an unfunded sender works thanks to the balance override
+    let sample_sender = PrivateKeySigner::random().address();
+    let marginal =
+        estimate_marginal_gas(sample_sender, url.clone(), &abi, contract_address).await?;
+    assert!(marginal.per_recipient > 5_000);
+    assert!(marginal.per_recipient < 60_000);
+    assert!(marginal.base > 21_000);
+
+    let chunk_size =
+        suggest_chunk_size(sample_sender, url.clone(), &abi, contract_address, 0.5).await?;
+    let block = provider
+        .get_block_by_number(BlockNumberOrTag::Latest, false.into())
+        .await?
+        .unwrap();
+    assert!(marginal.gas_for(chunk_size) <= block.header.gas_limit / 2);
+    assert!(marginal.gas_for(chunk_size + 1) > block.header.gas_limit / 2);
+
+    assert!(
+        suggest_chunk_size(sample_sender, url, &abi, contract_address, 0.0)
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_verify() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 10)?;
+    let params = |amount| DistributeParam::from_accounts(&receivers, amount);
+    let each_amount = parse_ether("0.001")?;
+
+    distribute(
+        signer,
+        url.clone(),
+        abi,
+        contract_address,
+        params(each_amount),
+    )
+    .await?;
+
+    let report = verify(url.clone(), &params(each_amount), VerifyMode::Exact).await?;
+    assert!(report.passed());
+    assert_eq!(report.checked, receivers.len());
+
+    let report = verify(
+        url.clone(),
+        &params(each_amount * U256::from(2)),
+        VerifyMode::AtLeast,
+    )
+    .await?;
+    assert!(!report.passed());
+    assert_eq!(report.failures.len(), receivers.len());
+    assert!(report
+        .failures
+        .iter()
+        .all(|failure| failure.actual == each_amount
+            && failure.expected == each_amount * U256::from(2)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_distribute_multi() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(2))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, END_INDEX)?;
+    let each_amount = parse_ether("0.001")?;
+    let params = DistributeParam::from_accounts(&receivers, each_amount);
+
+    let distribution = distribute_multi(
+        signers.clone(),
+        url.clone(),
+        abi,
+        contract_address,
+        params,
+        SplitStrategy::RoundRobin,
+    )
+    .await?;
+
+    assert_eq!(distribution.senders.len(), 2);
+    for (sender, signer) in distribution.senders.iter().zip(&signers) {
+        assert_eq!(sender.sender, signer.address());
+        assert_eq!(sender.receivers.len(), 50);
+        assert!(sender.result.is_ok());
+    }
+    assert!(distribution.failed_receivers().is_empty());
+    assert_eq!(
+        distribution.total_distributed(),
+        each_amount * U256::from(receivers.len())
+    );
+
+    for receiver in receivers {
+        let balance = provider.get_balance(receiver.address()).await?;
+        assert_eq!(balance, each_amount);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_distribute_multi_with_unfunded_sender() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let funded = test_env.signers.first().unwrap().clone();
+    let unfunded = PrivateKeySigner::random();
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 10)?;
+    let each_amount = parse_ether("0.001")?;
+    let params = DistributeParam::from_accounts(&receivers, each_amount);
+
+    let distribution = distribute_multi(
+        vec![funded, unfunded],
+        url.clone(),
+        abi,
+        contract_address,
+        params,
+        SplitStrategy::RoundRobin,
+    )
+    .await?;
+
+    // only the partition of the unfunded sender fails
+    assert!(distribution.senders[0].result.is_ok());
+    assert!(distribution.senders[1].result.is_err());
+    assert_eq!(
+        distribution.failed_receivers(),
+        distribution.senders[1].receivers
+    );
+
+    for receiver in &distribution.senders[0].receivers {
+        let balance = provider.get_balance(*receiver).await?;
+        assert_eq!(balance, each_amount);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_check_duplicates() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 2)?;
+    let each_amount = parse_ether("0.001")?;
+    let mut params = DistributeParam::from_signers(&receivers, each_amount)?;
+    params.push(params[0].clone());
+
+    let options = DistributeOptions {
+        check_duplicates: true,
+        ..Default::default()
+    };
+    let error = distribute_with_options(
+        signer.clone(),
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        params.clone(),
+        options,
+    )
+    .await
+    .unwrap_err();
+    assert!(error
+        .to_string()
+        .contains(&receivers[0].address().to_string()));
+    assert!(provider
+        .get_balance(receivers[0].address())
+        .await?
+        .is_zero());
+
+    // without the check the duplicate is funded twice
+    distribute_with_options(
+        signer,
+        url,
+        abi,
+        contract_address,
+        params,
+        DistributeOptions::default(),
+    )
+    .await?;
+    let balance = provider.get_balance(receivers[0].address()).await?;
+    assert_eq!(balance, each_amount * U256::from(2));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_check_duplicates_across_chunks() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // the duplicate lands in the second chunk
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 2)?;
+    let mut params = DistributeParam::from_signers(&receivers, parse_ether("0.001")?)?;
+    params.push(params[0].clone());
+
+    let options = DistributeOptions {
+        check_duplicates: true,
+        ..Default::default()
+    };
+    let error = distribute_chunked_with_options(
+        signer,
+        url,
+        abi,
+        contract_address,
+        params,
+        2,
+        options,
+        None,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains(&receivers[0].address().to_string()));
+    for receiver in receivers {
+        assert!(provider.get_balance(receiver.address()).await?.is_zero());
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reject_contract_recipients() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 2)?;
+    let each_amount = parse_ether("0.001")?;
+    let mut params = DistributeParam::from_signers(&receivers, each_amount)?;
+    params.push(DistributeParam {
+        receiver: contract_address,
+        amount: each_amount,
+    });
+
+    let options = DistributeOptions {
+        contract_recipients: ContractRecipientPolicy::Reject,
+        ..Default::default()
+    };
+    let error = distribute_with_options(signer, url, abi, contract_address, params, options)
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains(&contract_address.to_string()));
+
+    for receiver in &receivers {
+        assert!(provider.get_balance(receiver.address()).await?.is_zero());
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_distribute_parallel_arrays() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(DISPERSE_ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 10)?;
+    let each_amount = parse_ether("0.001")?;
+    let params = DistributeParam::from_accounts(&receivers, each_amount);
+
+    let options = DistributeOptions {
+        kind: DistributorKind::ParallelArrays {
+            function_name: "disperseEther".to_string(),
+        },
+        ..Default::default()
+    };
+    let result =
+        distribute_with_options(signer, url.clone(), abi, contract_address, params, options)
+            .await?;
+    assert!(result.status);
+    assert_eq!(result.recipient_count, receivers.len());
+
+    for receiver in receivers {
+        let balance = provider.get_balance(receiver.address()).await?;
+        assert_eq!(balance, each_amount);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_distribution_events() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 5)?;
+    let each_amount = parse_ether("0.001")?;
+    let params = DistributeParam::from_accounts(&receivers, each_amount);
+
+    let result = distribute_with_receipt(
+        signer.clone(),
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        params,
+    )
+    .await?;
+
+    let events = query_distribution_events(url.clone(), abi, contract_address, 0, None).await?;
+
+    assert_eq!(events.len(), 1);
+    let event = &events[0];
+    assert_eq!(event.tx_hash, result.tx_hash);
+    assert_eq!(event.sender, signer.address());
+    assert_eq!(event.block_number, result.block_number);
+    assert_eq!(
+        event.recipients,
+        receivers.iter().map(|r| r.address()).collect::<Vec<_>>()
+    );
+    assert!(event.amounts.iter().all(|amount| *amount == each_amount));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_top_up() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let receivers: Vec<Address> = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 10)?
+        .iter()
+        .map(|r| r.address())
+        .collect();
+    let target = parse_ether("0.002")?;
+
+    // fund half of the receivers first, one of them exactly at the target
+    let params: Vec<DistributeParam> = receivers[..5]
+        .iter()
+        .enumerate()
+        .map(|(i, receiver)| DistributeParam {
+            receiver: *receiver,
+            amount: if i == 0 {
+                target
+            } else {
+                target / U256::from(2)
+            },
+        })
+        .collect();
+    distribute(
+        signer.clone(),
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        params,
+    )
+    .await?;
+
+    // receivers listed twice are still topped up only once
+    let mut listed = receivers.clone();
+    listed.extend([receivers[1], receivers[7]]);
+    let tx_hash = top_up(
+        signer.clone(),
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        &listed,
+        target,
+    )
+    .await?;
+    assert!(tx_hash.is_some());
+
+    for receiver in &receivers {
+        let balance = provider.get_balance(*receiver).await?;
+        assert_eq!(balance, target);
+    }
+
+    // nothing left to top up
+    let tx_hash = top_up(
+        signer,
+        url.clone(),
+        abi,
+        contract_address,
+        &receivers,
+        target,
+    )
+    .await?;
+    assert!(tx_hash.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_distribute_equal() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let receivers: Vec<Address> = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 10)?
+        .iter()
+        .map(|r| r.address())
+        .collect();
+    let each_amount = parse_ether("0.002")?;
+
+    let tx_hash = distribute_equal(
+        signer,
+        url.clone(),
+        abi,
+        contract_address,
+        &receivers,
+        each_amount,
+    )
+    .await?;
+    let receipt = provider.get_transaction_receipt(tx_hash).await?.unwrap();
+    assert!(receipt.status());
+
+    for receiver in receivers {
+        let balance = provider.get_balance(receiver).await?;
+        assert_eq!(balance, each_amount);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_distribute_direct() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    // no distributor contract is deployed
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 10)?;
+    let each_amount = parse_ether("0.001")?;
+    let params = DistributeParam::from_signers(&receivers, each_amount)?;
+
+    let distribution = distribute_direct(signer, url.clone(), params).await?;
+
+    assert_eq!(distribution.transfers.len(), receivers.len());
+    assert!(distribution.transfers.iter().all(|t| t.result.is_ok()));
+    assert_eq!(distribution.gas_used, 21_000 * receivers.len() as u64);
+    assert!(!distribution.warning.is_empty());
+
+    for receiver in receivers {
+        let balance = provider.get_balance(receiver.address()).await?;
+        assert_eq!(balance, each_amount);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_distribute_direct_to_contracts() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    // MockReceiver needs more than 21,000 gas, FreeMint rejects Ether
+    let (_, bytecode) = parse_artifact(RECEIVER_ARTIFACT_PATH)?;
+    let receiver = deploy_contract(provider.clone(), bytecode).await?;
+    let (_, bytecode) = parse_artifact(FREE_MINT_ARTIFACT_PATH)?;
+    let rejecting = deploy_contract(provider.clone(), bytecode).await?;
+
+    let each_amount = parse_ether("0.001")?;
+    let params = DistributeParam::equal(&[receiver, rejecting], each_amount)?;
+
+    let distribution = distribute_direct(signer, url, params).await?;
+
+    assert!(distribution.transfers[0].result.is_ok());
+    assert!(distribution.transfers[1].result.is_err());
+    assert!(distribution.gas_used > 21_000);
+    assert_eq!(provider.get_balance(receiver).await?, each_amount);
+    assert!(provider.get_balance(rejecting).await?.is_zero());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_distribute_direct_with_progress() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let url = test_env.url;
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 5)?;
+    let params = DistributeParam::from_signers(&receivers, parse_ether("0.001")?)?;
+
+    let updates = Mutex::new(Vec::new());
+    let collect = |progress: &DistributeProgress| updates.lock().unwrap().push(*progress);
+
+    let distribution = distribute_direct_with_progress(signer, url, params, Some(&collect)).await?;
+
+    // one update per transfer
+    let updates = updates.into_inner().unwrap();
+    assert_eq!(updates.len(), 5);
+    for (i, update) in updates.iter().enumerate() {
+        assert_eq!(update.chunks_completed, i + 1);
+        assert_eq!(update.recipients_funded, i + 1);
+        assert_eq!(update.gas_used, 21_000 * (i as u64 + 1));
+    }
+    assert_eq!(updates[4].gas_used, distribution.gas_used);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_batch_distribute_from_csv() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 25)?;
+    let mut content = String::from("address,amount\n");
+    for receiver in &receivers {
+        content.push_str(&format!("{},0.001\n", receiver.address()));
+    }
+    let path = std::env::temp_dir().join("stormint_batch_distribute_from_csv.csv");
+    std::fs::write(&path, content)?;
+
+    let tx_hashes =
+        batch_distribute_from_csv(signer, url, abi, contract_address, &path, Some(10)).await;
+    std::fs::remove_file(&path)?;
+
+    // three waves of 10, 10 and 5 receivers
+    assert_eq!(tx_hashes?.len(), 3);
+    for receiver in receivers {
+        let balance = provider.get_balance(receiver.address()).await?;
+        assert_eq!(balance, parse_ether("0.001")?);
+    }
+
+    Ok(())
+}
+
+/// Forwards JSON-RPC requests to `target`, dropping the connections that send a request
+/// during `down_for` after the first `eth_sendRawTransaction` went through.
+async fn drop_after_send_proxy(target: &Url, down_for: Duration) -> Result<Url> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let proxy_url = format!("http://{}", listener.local_addr()?).parse()?;
+    let target = target.clone();
+    let client = Client::new();
+    let down_until: Arc<Mutex<Option<Instant>>> = Arc::default();
+
+    tokio::spawn(async move {
+        while let Ok((mut inbound, _)) = listener.accept().await {
+            let (target, client, down_until) = (target.clone(), client.clone(), down_until.clone());
+            tokio::spawn(async move {
+                while let Some(body) = read_request_body(&mut inbound).await {
+                    let down = *down_until.lock().unwrap();
+                    if down.is_some_and(|until| Instant::now() < until) {
+                        return;
+                    }
+
+                    let response = client
+                        .post(target.clone())
+                        .header("content-type", "application/json")
+                        .body(body.clone())
+                        .send()
+                        .await;
+                    let Ok(response) = response else { return };
+                    let Ok(response) = response.bytes().await else {
+                        return;
+                    };
+
+                    if String::from_utf8_lossy(&body).contains("eth_sendRawTransaction") {
+                        down_until
+                            .lock()
+                            .unwrap()
+                            .get_or_insert(Instant::now() + down_for);
+                    }
+
+                    let head = format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n",
+                        response.len()
+                    );
+                    if inbound.write_all(head.as_bytes()).await.is_err()
+                        || inbound.write_all(&response).await.is_err()
+                    {
+                        return;
+                    }
+                }
+            });
+        }
+    });
+
+    Ok(proxy_url)
+}
+
+/// Reads the body of the next HTTP request on `stream`, `None` once the connection is closed.
+async fn read_request_body(stream: &mut TcpStream) -> Option<Vec<u8>> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0; 4096];
+
+    let head_end = loop {
+        if let Some(end) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+            break end + 4;
+        }
+        let read = stream.read(&mut chunk).await.ok()?;
+        if read == 0 {
+            return None;
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..head_end]).to_lowercase();
+    let length: usize = head
+        .lines()
+        .find_map(|line| line.strip_prefix("content-length:"))
+        .and_then(|length| length.trim().parse().ok())
+        .unwrap_or_default();
+
+    while buffer.len() < head_end + length {
+        let read = stream.read(&mut chunk).await.ok()?;
+        if read == 0 {
+            return None;
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    }
+
+    Some(buffer[head_end..head_end + length].to_vec())
+}
@@ -0,0 +1,85 @@
+use crate::common::{deploy_contract, parse_artifact, TestEnvironment};
+use alloy::dyn_abi::DynSolValue;
+use alloy::json_abi::JsonAbi;
+use alloy::primitives::{Address, U256};
+use alloy::transports::http::reqwest::Url;
+use eyre::Result;
+use stormint::distributor::distribute_erc721;
+use stormint::executor::{call, execute};
+
+const NFT_ARTIFACT_PATH: &str = "contracts/out/MockNFT.sol/MockNFT.json";
+
+#[tokio::test]
+async fn test_distribute_erc721() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let vault = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(NFT_ARTIFACT_PATH)?;
+    let nft = deploy_contract(provider.clone(), bytecode).await?;
+
+    // the vault holds tokens 0 to 2
+    for _ in 0..3 {
+        execute(
+            vault.clone(),
+            url.clone(),
+            abi.clone(),
+            nft,
+            "mint",
+            &[],
+            None,
+        )
+        .await?;
+    }
+
+    let receivers: Vec<Address> = (0..5).map(|_| Address::random()).collect();
+    let assignments = vec![
+        (receivers[0], U256::from(0)),
+        (receivers[1], U256::from(1)),
+        // never minted
+        (receivers[2], U256::from(7)),
+        // already transferred above
+        (receivers[3], U256::from(0)),
+        (receivers[4], U256::from(2)),
+    ];
+
+    let transfers = distribute_erc721(vault, url.clone(), nft, assignments).await?;
+
+    assert_eq!(transfers.len(), 5);
+    for index in [0, 1, 4] {
+        assert!(transfers[index].result.is_ok());
+    }
+    let error = transfers[2].result.as_ref().unwrap_err();
+    assert!(error.to_string().contains("ERC721NonexistentToken(7)"));
+    let error = transfers[3].result.as_ref().unwrap_err();
+    assert!(error.to_string().contains("ERC721IncorrectOwner"));
+
+    // the failed assignments did not block the transfer after them
+    assert_eq!(
+        owner_of(url.clone(), abi.clone(), nft, 0).await?,
+        receivers[0]
+    );
+    assert_eq!(
+        owner_of(url.clone(), abi.clone(), nft, 1).await?,
+        receivers[1]
+    );
+    assert_eq!(owner_of(url, abi, nft, 2).await?, receivers[4]);
+
+    Ok(())
+}
+
+async fn owner_of(url: Url, abi: JsonAbi, nft: Address, token_id: u64) -> Result<Address> {
+    let owner = call(
+        url,
+        abi,
+        nft,
+        "ownerOf",
+        &[DynSolValue::from(U256::from(token_id))],
+    )
+    .await?;
+
+    match owner.first() {
+        Some(DynSolValue::Address(owner)) => Ok(*owner),
+        _ => Ok(Address::ZERO),
+    }
+}
@@ -0,0 +1,54 @@
+use crate::common::{deploy_contract, parse_artifact, TestEnvironment};
+use alloy::eips::{BlockId, BlockNumberOrTag};
+use alloy::primitives::{keccak256, Address};
+use eyre::Result;
+use stormint::executor::{get_logs, LogFilter};
+use stormint::mint::{mint_loop_with, MintOptions};
+
+const ARTIFACT_PATH: &str = "contracts/out/FreeMint.sol/FreeMint.json";
+
+#[tokio::test]
+async fn test_get_logs() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(3))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+    let (alice, bob) = (signers[1].clone(), signers[2].clone());
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    mint_loop_with(
+        vec![alice.clone(), bob.clone()],
+        url.clone(),
+        abi,
+        contract_address,
+        MintOptions::default(),
+    )
+    .await?;
+
+    // Transfer(from, to, amount) with `to` as the third topic
+    let transfer = keccak256("Transfer(address,address,uint256)");
+    let filter = |addresses: Vec<Address>, to: Option<Address>| LogFilter {
+        from_block: BlockId::Number(BlockNumberOrTag::Earliest),
+        to_block: BlockId::Number(BlockNumberOrTag::Latest),
+        addresses,
+        topics: vec![Some(transfer), None, to.map(Address::into_word)],
+    };
+
+    let logs = get_logs(url.clone(), filter(vec![contract_address], None)).await?;
+    assert_eq!(logs.len(), 2);
+
+    let logs = get_logs(
+        url.clone(),
+        filter(vec![contract_address], Some(alice.address())),
+    )
+    .await?;
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].address(), contract_address);
+    assert_eq!(logs[0].topics()[2], alice.address().into_word());
+
+    // no other contract emitted a Transfer
+    let logs = get_logs(url, filter(vec![Address::repeat_byte(0x42)], None)).await?;
+    assert!(logs.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use crate::common::{deploy_contract, parse_artifact, TestEnvironment};
+use alloy::dyn_abi::DynSolValue;
+use alloy::network::TransactionBuilder;
+use alloy::primitives::utils::parse_ether;
+use alloy::primitives::U256;
+use alloy::providers::Provider;
+use eyre::{ensure, Result};
+use std::sync::{Arc, Mutex};
+use stormint::executor::{call, execute_with_options, ExecuteOptions, PreHook};
+
+const FREE_MINT_ARTIFACT_PATH: &str = "contracts/out/FreeMint.sol/FreeMint.json";
+
+#[tokio::test]
+async fn test_pre_hook() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(2))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(FREE_MINT_ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // a compliance rule: never send more than 0.1 ETH
+    let max_value = parse_ether("0.1")?;
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let hook = {
+        let seen = seen.clone();
+        PreHook::new(move |tx| {
+            seen.lock().unwrap().push(tx.clone());
+            let value = tx.value.unwrap_or_default();
+            ensure!(value <= max_value, "value {value} exceeds the limit");
+            Ok(())
+        })
+    };
+
+    let (allowed, rejected) = (signers[0].clone(), signers[1].clone());
+    let options = |value| ExecuteOptions {
+        value: Some(value),
+        pre_hook: Some(hook.clone()),
+        ..Default::default()
+    };
+
+    execute_with_options(
+        allowed.clone(),
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        "mint",
+        &[],
+        options(U256::ZERO),
+    )
+    .await?;
+
+    let nonce = provider.get_transaction_count(rejected.address()).await?;
+    let error = execute_with_options(
+        rejected.clone(),
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        "mint",
+        &[],
+        options(parse_ether("1")?),
+    )
+    .await
+    .unwrap_err();
+    assert!(format!("{error:#}").contains("exceeds the limit"));
+
+    // the rejected transaction was never sent
+    assert_eq!(
+        provider.get_transaction_count(rejected.address()).await?,
+        nonce
+    );
+    for (account, minted) in [(allowed.address(), true), (rejected.address(), false)] {
+        let has_minted = call(
+            url.clone(),
+            abi.clone(),
+            contract_address,
+            "hasMinted",
+            &[DynSolValue::from(account)],
+        )
+        .await?;
+        assert_eq!(has_minted, vec![DynSolValue::Bool(minted)]);
+    }
+
+    // the hook saw both requests before they were signed
+    let seen = seen.lock().unwrap();
+    assert_eq!(seen.len(), 2);
+    assert_eq!(seen[0].from, Some(allowed.address()));
+    assert_eq!(seen[0].to(), Some(contract_address));
+    assert!(seen[0].input().is_some());
+    assert_eq!(seen[1].value, Some(parse_ether("1")?));
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use crate::common::TestEnvironment;
+use alloy::eips::BlockId;
+use alloy::network::TransactionBuilder;
+use alloy::primitives::{Address, U256};
+use alloy::providers::Provider;
+use alloy::rpc::types::TransactionRequest;
+use eyre::Result;
+use stormint::executor::{get_block, get_fee_history, get_gas_price};
+
+#[tokio::test]
+async fn test_get_gas_price() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+
+    let gas_price = get_gas_price(url).await?;
+    assert_eq!(gas_price, U256::from(provider.get_gas_price().await?));
+    assert!(gas_price > U256::ZERO);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_fee_history() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    // one transfer per block
+    for _ in 0..3 {
+        let tx = TransactionRequest::default()
+            .with_from(signers[0].address())
+            .with_to(Address::random())
+            .with_value(U256::from(1));
+        provider.send_transaction(tx).await?.get_receipt().await?;
+    }
+    let latest = provider.get_block_number().await?;
+
+    let history = get_fee_history(url.clone(), 3, BlockId::latest(), &[25.0, 75.0]).await?;
+    assert_eq!(history.oldest_block, latest - 2);
+    assert_eq!(history.base_fees.len(), 4);
+    assert_eq!(history.gas_used_ratios.len(), 3);
+    assert!(history
+        .gas_used_ratios
+        .iter()
+        .all(|ratio| *ratio > 0.0 && *ratio <= 1.0));
+    assert_eq!(history.rewards.len(), 3);
+    assert!(history.rewards.iter().all(|rewards| rewards.len() == 2));
+
+    // the same range, ending at a block given by its hash
+    let hash = provider
+        .get_block(BlockId::latest(), false.into())
+        .await?
+        .unwrap()
+        .header
+        .hash;
+    let by_hash = get_fee_history(url.clone(), 3, BlockId::hash(hash), &[25.0, 75.0]).await?;
+    assert_eq!(by_hash, history);
+
+    let base_fee = get_block(url.clone(), BlockId::latest())
+        .await?
+        .base_fee_per_gas;
+    assert_eq!(history.base_fees.get(2).copied(), base_fee);
+
+    let no_rewards = get_fee_history(url.clone(), 3, BlockId::latest(), &[]).await?;
+    assert!(no_rewards.rewards.is_empty());
+
+    assert!(get_fee_history(url, 3, BlockId::latest(), &[75.0, 25.0])
+        .await
+        .is_err());
+
+    Ok(())
+}
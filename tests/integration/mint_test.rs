@@ -1,15 +1,43 @@
 use crate::common::{deploy_contract, get_token_balance, parse_artifact, TestEnvironment};
 use alloy::dyn_abi::DynSolValue;
 use alloy::json_abi::JsonAbi;
-use alloy::primitives::{Address, U256};
+use alloy::network::TransactionBuilder;
+use alloy::primitives::utils::parse_ether;
+use alloy::primitives::{keccak256, Address, B256, U256};
+use alloy::providers::Provider;
+use alloy::rpc::types::TransactionRequest;
 use alloy::transports::http::reqwest::Url;
+use alloy_node_bindings::Anvil;
 use eyre::Result;
-use stormint::executor::call;
-use stormint::mint::mint_loop;
+use futures::{stream, StreamExt};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use stormint::account::generate_accounts;
+use stormint::distributor::{calc_funding_params, distribute_direct};
+use stormint::executor::{
+    build_calldata, call, execute, get_nonce, has_minted, has_minted_batch, nonce_gap, GasPolicy,
+};
+use stormint::mint::{
+    mint_loop, mint_loop_from_stream, mint_loop_merkle, mint_loop_with, mint_loop_with_progress,
+    mint_stream, MintConfig, MintOptions, MintProgress, SpawnStrategy,
+};
+use tokio::io::copy_bidirectional;
+use tokio::net::{TcpListener, TcpStream};
 
 const ARTIFACT_PATH: &str = "contracts/out/FreeMint.sol/FreeMint.json";
+const NFT_ARTIFACT_PATH: &str = "contracts/out/MockNFT.sol/MockNFT.json";
+const MNEMONIC: &str = "test test test test test test test test test test test junk";
+const PAID_MINT_ARTIFACT_PATH: &str = "contracts/out/MockPaidMint.sol/MockPaidMint.json";
+const TOKEN_ARTIFACT_PATH: &str = "contracts/out/MockToken.sol/MockToken.json";
+const LIMITED_MINT_ARTIFACT_PATH: &str = "contracts/out/MockLimitedMint.sol/MockLimitedMint.json";
+const CLOSED_MINT_ARTIFACT_PATH: &str = "contracts/out/MockClosedMint.sol/MockClosedMint.json";
+const MERKLE_ARTIFACT_PATH: &str = "contracts/out/MockMerkleMint.sol/MockMerkleMint.json";
 
+// the deprecated signature keeps working
 #[tokio::test]
+#[allow(deprecated)]
 async fn test_mint() -> Result<()> {
     let test_env = TestEnvironment::new(Some(3))?;
     let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
@@ -43,6 +71,1157 @@ async fn test_mint() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_mint_retry() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(3))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (alice, bob) = (signers[1].clone(), signers[2].clone());
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // bob has already minted, so his mint reverts
+    execute(
+        bob.clone(),
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        "mint",
+        &[],
+        None,
+    )
+    .await?;
+
+    // the first attempt of alice hits a dead endpoint, the retry a second later goes through
+    let proxy = flaky_proxy(&url, Duration::from_millis(500)).await?;
+    // one after another, so bob only reaches the endpoint once it is up
+    let options = MintOptions::builder().max_concurrent(1).build()?;
+    let results = mint_loop_with(
+        vec![alice, bob],
+        proxy,
+        abi.clone(),
+        contract_address,
+        options,
+    )
+    .await?;
+
+    assert!(results[0].result.is_ok());
+    assert_eq!(results[0].attempts, 2);
+
+    // reverts are not retried
+    assert!(results[1].result.is_err());
+    assert_eq!(results[1].attempts, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_loop_with() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(4))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(TOKEN_ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // every signer mints to the same receiver, two at a time, at a fixed gas price
+    let receiver = Address::random();
+    let gas_price = 10_000_000_000;
+    let options = MintOptions::builder()
+        .function_name("mintTo")
+        .args(vec![
+            DynSolValue::from(receiver),
+            DynSolValue::from(U256::from(7)),
+        ])
+        .max_concurrent(2)
+        .retry(0, Duration::ZERO)
+        .gas(GasPolicy::Legacy { gas_price })
+        .per_mint_timeout(Duration::from_secs(30))
+        .build()?;
+
+    let accounts = signers[1..].to_vec();
+    let results = mint_loop_with(
+        accounts,
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        options,
+    )
+    .await?;
+    assert_eq!(results.len(), 3);
+
+    for result in &results {
+        assert_eq!(result.attempts, 1);
+        let tx_hash = *result.result.as_ref().unwrap();
+        let receipt = provider.get_transaction_receipt(tx_hash).await?.unwrap();
+        assert_eq!(receipt.effective_gas_price, gas_price);
+    }
+
+    let balance = get_token_balance(url, abi, contract_address, receiver).await?;
+    assert_eq!(balance, U256::from(21));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_per_signer_args() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(4))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(TOKEN_ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // every signer mints to itself, an amount growing with its index
+    let options = MintOptions::builder()
+        .function_name("mintTo")
+        .args_with(|signer, index| {
+            vec![
+                DynSolValue::from(*signer),
+                DynSolValue::from(U256::from(index + 1)),
+            ]
+        })
+        .build()?;
+
+    let accounts = signers[1..].to_vec();
+    let results = mint_loop_with(
+        accounts.clone(),
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        options,
+    )
+    .await?;
+    assert!(results.iter().all(|result| result.result.is_ok()));
+
+    for (index, account) in accounts.iter().enumerate() {
+        let balance = get_token_balance(
+            url.clone(),
+            abi.clone(),
+            contract_address,
+            account.address(),
+        )
+        .await?;
+        assert_eq!(balance, U256::from(index + 1));
+    }
+
+    // one argument list short, so nothing is sent
+    let options = MintOptions::builder()
+        .function_name("mintTo")
+        .args_per_signer(vec![
+            vec![
+                DynSolValue::from(accounts[0].address()),
+                DynSolValue::from(U256::from(1)),
+            ];
+            accounts.len() - 1
+        ])
+        .build()?;
+
+    let nonce = provider
+        .get_transaction_count(accounts[0].address())
+        .await?;
+    let result = mint_loop_with(accounts.clone(), url, abi, contract_address, options).await;
+    assert!(result.is_err());
+    assert_eq!(
+        provider
+            .get_transaction_count(accounts[0].address())
+            .await?,
+        nonce
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_per_signer_value() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(4))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(PAID_MINT_ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // the first two signers get the early price
+    let accounts = signers[1..].to_vec();
+    let (early, late) = (parse_ether("0.01")?, parse_ether("0.02")?);
+    let values = vec![early, early, late];
+
+    let options = MintOptions::builder()
+        .value_per_signer(values[..2].to_vec())
+        .build()?;
+    let result = mint_loop_with(
+        accounts.clone(),
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        options,
+    )
+    .await;
+    assert!(result.is_err());
+    assert_eq!(provider.get_balance(contract_address).await?, U256::ZERO);
+
+    let options = MintOptions::builder()
+        .value_per_signer(values.clone())
+        .build()?;
+    let results = mint_loop_with(
+        accounts.clone(),
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        options,
+    )
+    .await?;
+    assert!(results.iter().all(|result| result.result.is_ok()));
+
+    let total: U256 = values.iter().sum();
+    assert_eq!(provider.get_balance(contract_address).await?, total);
+    for (account, value) in accounts.iter().zip(values) {
+        let paid = call(
+            url.clone(),
+            abi.clone(),
+            contract_address,
+            "paid",
+            &[DynSolValue::from(account.address())],
+        )
+        .await?;
+        assert_eq!(paid[0].as_uint().unwrap().0, value);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mints_per_account() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(3))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(LIMITED_MINT_ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // the contract allows 3 mints per address, so the fourth reverts and the fifth is skipped
+    let ticks = Mutex::new(Vec::new());
+    let collect = |progress: &MintProgress| ticks.lock().unwrap().push(*progress);
+    let options = MintOptions::builder().mints_per_account(5).build()?;
+    let accounts = signers[1..].to_vec();
+    let results = mint_loop_with_progress(
+        accounts.clone(),
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        options,
+        Some(&collect),
+    )
+    .await?;
+    assert_eq!(results.len(), 2 * 4);
+
+    // the skipped mints still complete the progress
+    let last = *ticks.into_inner().unwrap().last().unwrap();
+    assert_eq!((last.completed, last.total), (2 * 5, 2 * 5));
+    assert_eq!((last.succeeded, last.failed, last.skipped), (2 * 3, 2, 2));
+
+    let mint_amount = get_mint_amount(url.clone(), abi.clone(), contract_address).await?;
+    for (account, results) in accounts.iter().zip(results.chunks(4)) {
+        assert!(results
+            .iter()
+            .all(|result| result.signer == account.address()));
+        let mint_indexes: Vec<u32> = results.iter().map(|result| result.mint_index).collect();
+        assert_eq!(mint_indexes, [0, 1, 2, 3]);
+        assert!(results[..3].iter().all(|result| result.result.is_ok()));
+        assert!(results[3].result.is_err());
+
+        let balance = get_token_balance(
+            url.clone(),
+            abi.clone(),
+            contract_address,
+            account.address(),
+        )
+        .await?;
+        assert_eq!(balance, mint_amount * U256::from(3));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_progress() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(4))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // the last signer has already minted, so its mint fails
+    let accounts = signers[1..].to_vec();
+    execute(
+        accounts[2].clone(),
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        "mint",
+        &[],
+        None,
+    )
+    .await?;
+
+    let ticks = Mutex::new(Vec::new());
+    let collect = |progress: &MintProgress| ticks.lock().unwrap().push(*progress);
+
+    let options = MintOptions::builder().max_concurrent(2).build()?;
+    mint_loop_with_progress(
+        accounts.clone(),
+        url,
+        abi,
+        contract_address,
+        options,
+        Some(&collect),
+    )
+    .await?;
+
+    let ticks = ticks.into_inner().unwrap();
+    assert_eq!(ticks.len(), accounts.len());
+    for (index, tick) in ticks.iter().enumerate() {
+        assert_eq!(tick.completed, index + 1);
+        assert_eq!(tick.total, accounts.len());
+    }
+    let last = ticks.last().unwrap();
+    assert_eq!((last.succeeded, last.failed), (2, 1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_on_result() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(4))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+    let accounts = signers[1..].to_vec();
+
+    // the callback panics on the first signer, which must not disturb the other mints
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let (recorded, panicking) = (calls.clone(), accounts[0].address());
+    let options = MintOptions::builder()
+        .max_concurrent(2)
+        .on_result(move |result| {
+            recorded.lock().unwrap().push(result.signer);
+            assert_ne!(result.signer, panicking, "alert bot unavailable");
+        })
+        .build()?;
+    let results = mint_loop_with(accounts.clone(), url, abi, contract_address, options).await?;
+
+    assert_eq!(results.len(), accounts.len());
+    assert!(results.iter().all(|result| result.result.is_ok()));
+
+    let mut calls = calls.lock().unwrap().clone();
+    calls.sort();
+    let mut expected: Vec<Address> = accounts.iter().map(|account| account.address()).collect();
+    expected.sort();
+    assert_eq!(calls, expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_stream_on_result_panic() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(4))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+    let accounts = signers[1..].to_vec();
+
+    // the callback panics on one signer, whose result must still reach the stream
+    let panicking = accounts[1].address();
+    let options = MintOptions::builder()
+        .on_result(move |result| assert_ne!(result.signer, panicking, "alert bot unavailable"))
+        .build()?;
+    let results: Vec<_> = mint_stream(accounts.clone(), url, abi, contract_address, options)?
+        .collect()
+        .await;
+
+    let mut minted: Vec<Address> = results
+        .iter()
+        .filter(|result| result.result.is_ok())
+        .map(|result| result.signer)
+        .collect();
+    minted.sort();
+    let mut expected: Vec<Address> = accounts.iter().map(|account| account.address()).collect();
+    expected.sort();
+    assert_eq!(minted, expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_cancel() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(5))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+    let accounts = signers[1..].to_vec();
+
+    // the run is cancelled as soon as the first result arrives
+    let cancel = Arc::new(AtomicBool::new(false));
+    let flag = cancel.clone();
+    let options = MintOptions::builder()
+        .max_concurrent(1)
+        .on_result(move |_| flag.store(true, Ordering::Relaxed))
+        .cancel(cancel)
+        .build()?;
+    let results = mint_loop_with(
+        accounts.clone(),
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        options,
+    )
+    .await?;
+
+    assert_eq!(results.len(), accounts.len());
+    assert!(results[0].result.is_ok());
+    assert!(!results[0].is_cancelled());
+    for (account, result) in accounts.iter().zip(&results).skip(1) {
+        assert_eq!(result.signer, account.address());
+        assert!(result.is_cancelled());
+        assert_eq!(result.attempts, 0);
+
+        let balance = get_token_balance(
+            url.clone(),
+            abi.clone(),
+            contract_address,
+            account.address(),
+        )
+        .await?;
+        assert_eq!(balance, U256::ZERO);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_fail_fast() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(6))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(CLOSED_MINT_ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+    let accounts = signers[1..].to_vec();
+
+    // probe with a single account first
+    let options = MintOptions::builder()
+        .max_concurrent(1)
+        .fail_fast(true)
+        .build()?;
+    let results = mint_loop_with(
+        accounts.clone(),
+        url.clone(),
+        abi,
+        contract_address,
+        options,
+    )
+    .await?;
+
+    assert_eq!(results.len(), accounts.len());
+    let error = results[0].result.as_ref().unwrap_err();
+    assert!(format!("{error:#}").contains("Sale not started"));
+    for (account, result) in accounts.iter().zip(&results).skip(1) {
+        assert_eq!(result.signer, account.address());
+        assert!(result.is_skipped());
+        assert_eq!(result.attempts, 0);
+    }
+
+    // the revert surfaced in the gas estimation, so at most the probe was broadcast
+    let mut broadcast = 0;
+    for account in &accounts {
+        broadcast += get_nonce(url.clone(), account.address()).await?;
+    }
+    assert!(broadcast <= 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_simulate_first() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(3))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+    let (minted, fresh) = (signers[1].clone(), signers[2].clone());
+
+    execute(
+        minted.clone(),
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        "mint",
+        &[],
+        None,
+    )
+    .await?;
+    let nonce = get_nonce(url.clone(), minted.address()).await?;
+
+    let options = MintOptions::builder().simulate_first(true).build()?;
+    let results = mint_loop_with(
+        vec![minted.clone(), fresh.clone()],
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        options,
+    )
+    .await?;
+
+    // the account already minted is skipped with the revert reason, without a transaction
+    let error = results[0].result.as_ref().unwrap_err();
+    assert!(error.to_string().contains("Address has already minted"));
+    assert_eq!(results[0].attempts, 0);
+    assert_eq!(get_nonce(url.clone(), minted.address()).await?, nonce);
+
+    assert!(results[1].result.is_ok());
+    let mint_amount = get_mint_amount(url.clone(), abi.clone(), contract_address).await?;
+    let balance = get_token_balance(url, abi, contract_address, fresh.address()).await?;
+    assert_eq!(balance, mint_amount);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_simulate_first_retry() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(2))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // the simulation hits a dead endpoint and is retried once it is up
+    let proxy = flaky_proxy(&url, Duration::from_millis(500)).await?;
+    let options = MintOptions::builder().simulate_first(true).build()?;
+    let results = mint_loop_with(
+        vec![signers[1].clone()],
+        proxy,
+        abi,
+        contract_address,
+        options,
+    )
+    .await?;
+
+    assert!(results[0].result.is_ok());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_stream() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(3))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+    let accounts = signers[1..].to_vec();
+
+    // blocks are only mined on request, and hold a single mint each
+    let tx = TransactionRequest::default()
+        .with_from(accounts[0].address())
+        .with_to(contract_address)
+        .with_input(build_calldata(&abi, "mint", &[])?);
+    let mint_gas = provider.estimate_gas(&tx).await?;
+    provider
+        .raw_request::<_, bool>(
+            "evm_setBlockGasLimit".into(),
+            (U256::from(mint_gas * 3 / 2),),
+        )
+        .await?;
+    provider
+        .raw_request::<_, ()>("evm_setAutomine".into(), (false,))
+        .await?;
+
+    let options = MintOptions::builder().max_concurrent(2).build()?;
+    let mut results = mint_stream(
+        accounts.clone(),
+        url.clone(),
+        abi,
+        contract_address,
+        options,
+    )?;
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        for account in &accounts {
+            while nonce_gap(url.clone(), account.address()).await? == 0 {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+        }
+        eyre::Ok(())
+    })
+    .await??;
+
+    // the mint left out of the first block must not hold back the other one
+    provider
+        .raw_request::<_, String>("evm_mine".into(), ())
+        .await?;
+    let first = results.next().await.unwrap();
+    assert!(first.result.is_ok());
+    let slow = accounts
+        .iter()
+        .find(|account| account.address() != first.signer)
+        .unwrap();
+    assert_eq!(nonce_gap(url.clone(), slow.address()).await?, 1);
+
+    provider
+        .raw_request::<_, String>("evm_mine".into(), ())
+        .await?;
+    let second = results.next().await.unwrap();
+    assert_eq!(second.signer, slow.address());
+    assert!(second.result.is_ok());
+
+    assert!(results.next().await.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_confirmations() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(2))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+    let account = signers[1].clone();
+
+    // from now on blocks are only mined on request
+    provider
+        .raw_request::<_, ()>("evm_setAutomine".into(), (false,))
+        .await?;
+
+    let options = MintOptions::builder().confirmations(3).build()?;
+    let mint = tokio::spawn(mint_loop_with(
+        vec![account.clone()],
+        url.clone(),
+        abi,
+        contract_address,
+        options,
+    ));
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while nonce_gap(url.clone(), account.address()).await? == 0 {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        eyre::Ok(())
+    })
+    .await??;
+
+    // the block including the mint and the next one are not enough
+    for _ in 0..2 {
+        provider
+            .raw_request::<_, String>("evm_mine".into(), ())
+            .await?;
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        assert!(!mint.is_finished());
+    }
+
+    provider
+        .raw_request::<_, String>("evm_mine".into(), ())
+        .await?;
+    let results = tokio::time::timeout(Duration::from_secs(10), mint).await???;
+    let tx_hash = *results[0].result.as_ref().unwrap();
+
+    let receipt = provider.get_transaction_receipt(tx_hash).await?.unwrap();
+    let mint_block = receipt.block_number.unwrap();
+    assert_eq!(provider.get_block_number().await?, mint_block + 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_timeout() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(2))?;
+    let (provider, signers) = (test_env.provider, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // the endpoint accepts connections but never answers
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let stalled = format!("http://{}", listener.local_addr()?).parse()?;
+    tokio::spawn(async move {
+        let mut connections = Vec::new();
+        while let Ok((connection, _)) = listener.accept().await {
+            connections.push(connection);
+        }
+    });
+
+    let options = MintOptions::builder()
+        .per_mint_timeout(Duration::from_millis(500))
+        .build()?;
+
+    let start = Instant::now();
+    let results = mint_loop_with(
+        vec![signers[1].clone()],
+        stalled,
+        abi,
+        contract_address,
+        options,
+    )
+    .await?;
+
+    assert!(start.elapsed() < Duration::from_secs(5));
+    let error = results[0].result.as_ref().unwrap_err();
+    assert!(error.to_string().contains("timed out"));
+    assert_eq!(results[0].attempts, 0);
+
+    // nothing reached the node
+    let nonce = provider.get_transaction_count(signers[1].address()).await?;
+    assert_eq!(nonce, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_timeout_pending() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(4))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+    let accounts = signers[1..].to_vec();
+
+    // mining is paused, so every mint stays in the mempool
+    provider
+        .raw_request::<_, ()>("evm_setAutomine".into(), (false,))
+        .await?;
+
+    let options = MintOptions::builder()
+        .per_mint_timeout(Duration::from_secs(2))
+        .build()?;
+
+    let start = Instant::now();
+    let results = mint_loop_with(
+        accounts.clone(),
+        url.clone(),
+        abi,
+        contract_address,
+        options,
+    )
+    .await?;
+
+    // the stuck mints time out together instead of holding the batch
+    assert!(start.elapsed() < Duration::from_secs(10));
+    assert_eq!(results.len(), accounts.len());
+    for (account, result) in accounts.iter().zip(&results) {
+        let error = result.result.as_ref().unwrap_err();
+        assert_eq!(error.to_string(), "mint timed out after 2s");
+        assert_eq!(result.attempts, 1);
+        assert_eq!(nonce_gap(url.clone(), account.address()).await?, 1);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_timeout_stops_account() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(3))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+    let accounts = signers[1..].to_vec();
+
+    provider
+        .raw_request::<_, ()>("evm_setAutomine".into(), (false,))
+        .await?;
+
+    let options = MintOptions::builder()
+        .per_mint_timeout(Duration::from_secs(1))
+        .mints_per_account(3)
+        .build()?;
+
+    let results = mint_loop_with(
+        accounts.clone(),
+        url.clone(),
+        abi,
+        contract_address,
+        options,
+    )
+    .await?;
+
+    // the first timeout ends the account, so no second mint is queued behind the stuck one
+    assert_eq!(results.len(), accounts.len());
+    for (account, result) in accounts.iter().zip(&results) {
+        assert!(result.is_timed_out());
+        assert_eq!(nonce_gap(url.clone(), account.address()).await?, 1);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_from_stream() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(5))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    // mint with the same accounts on two identical contracts
+    let vec_address = deploy_contract(provider.clone(), bytecode.clone()).await?;
+    let stream_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let accounts = signers[1..].to_vec();
+    let vec_results = mint_loop_with(
+        accounts.clone(),
+        url.clone(),
+        abi.clone(),
+        vec_address,
+        MintOptions::default(),
+    )
+    .await?;
+
+    let config = MintConfig::builder().max_concurrent(3).build()?;
+    let stream_results = mint_loop_from_stream(
+        stream::iter(accounts),
+        url.clone(),
+        abi.clone(),
+        stream_address,
+        None,
+        None,
+        None,
+        config,
+    )
+    .await?;
+
+    assert_eq!(vec_results.len(), stream_results.len());
+    for (vec_result, stream_result) in vec_results.iter().zip(&stream_results) {
+        assert_eq!(vec_result.signer, stream_result.signer);
+        assert_eq!(vec_result.result.is_ok(), stream_result.result.is_ok());
+    }
+
+    let mint_amount = get_mint_amount(url.clone(), abi.clone(), stream_address).await?;
+    for result in stream_results {
+        let balance =
+            get_token_balance(url.clone(), abi.clone(), stream_address, result.signer).await?;
+        assert_eq!(balance, mint_amount);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_wave_delay() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(7))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    // 6 accounts in waves of 2, so 2 pauses between the 3 waves
+    let accounts = signers[1..].to_vec();
+    let mut elapsed = Vec::new();
+    for wave_delay in [Duration::ZERO, Duration::from_millis(500)] {
+        let contract_address = deploy_contract(provider.clone(), bytecode.clone()).await?;
+        let config = MintConfig::builder()
+            .max_concurrent(2)
+            .wave_delay(wave_delay)
+            .build()?;
+
+        let start = Instant::now();
+        let results = mint_loop_from_stream(
+            stream::iter(accounts.clone()),
+            url.clone(),
+            abi.clone(),
+            contract_address,
+            None,
+            None,
+            None,
+            config,
+        )
+        .await?;
+        elapsed.push(start.elapsed());
+
+        assert!(results.iter().all(|result| result.result.is_ok()));
+    }
+
+    assert!(elapsed[1] >= Duration::from_millis(1000));
+    assert!(elapsed[1] > elapsed[0]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_rate_limit() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(9))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // 8 mints started at once, submitted at 4 per second
+    let config = MintConfig::builder()
+        .max_concurrent(8)
+        .rate_limit(4.0)
+        .build()?;
+
+    let start = Instant::now();
+    let results = mint_loop_from_stream(
+        stream::iter(signers[1..].to_vec()),
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        None,
+        None,
+        None,
+        config,
+    )
+    .await?;
+    let elapsed = start.elapsed();
+
+    assert!(results.iter().all(|result| result.result.is_ok()));
+    // the last submission is 7 intervals of 250ms after the first
+    assert!(elapsed >= Duration::from_millis(1750));
+    assert!(elapsed < Duration::from_secs(5));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_has_minted() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(3))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (alice, bob) = (signers[1].clone(), signers[2].clone());
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    assert!(!has_minted(url.clone(), abi.clone(), contract_address, alice.address()).await?);
+
+    mint_loop_with(
+        vec![alice.clone()],
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        MintOptions::default(),
+    )
+    .await?;
+
+    assert!(has_minted(url.clone(), abi.clone(), contract_address, alice.address()).await?);
+
+    let accounts = [alice.address(), bob.address()];
+    let minted = has_minted_batch(url, abi, contract_address, &accounts).await?;
+    assert_eq!(minted, [(alice.address(), true), (bob.address(), false)]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_nonces() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(2))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(NFT_ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // the same account mints three times in a row
+    let accounts = vec![signers[1].clone(); 3];
+    let options = MintOptions::builder().max_concurrent(1).build()?;
+    let results = mint_loop_with(
+        accounts,
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        options,
+    )
+    .await?;
+
+    assert!(results.iter().all(|result| result.result.is_ok()));
+    let nonces: Vec<u64> = results.iter().map(|result| result.nonce.unwrap()).collect();
+    assert!(nonces.windows(2).all(|pair| pair[1] == pair[0] + 1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_merkle() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(4))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (alice, bob, carol) = (signers[1].clone(), signers[2].clone(), signers[3].clone());
+
+    // a two-leaf tree: each leaf is the proof of the other
+    let (alice_leaf, bob_leaf) = (leaf(alice.address()), leaf(bob.address()));
+    let root = hash_pair(alice_leaf, bob_leaf);
+    let merkle_proofs = HashMap::from([
+        (alice.address(), vec![bob_leaf.0]),
+        (bob.address(), vec![alice_leaf.0]),
+    ]);
+
+    let (abi, mut bytecode) = parse_artifact(MERKLE_ARTIFACT_PATH)?;
+    bytecode.extend_from_slice(root.as_slice());
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let results = mint_loop_merkle(
+        vec![alice, bob, carol.clone()],
+        merkle_proofs,
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        None,
+    )
+    .await?;
+
+    assert!(results[0].result.is_ok());
+    assert!(results[1].result.is_ok());
+
+    // carol is not whitelisted, so nothing was sent
+    assert_eq!(results[2].signer, carol.address());
+    assert!(results[2].result.is_err());
+    assert!(results[2].nonce.is_none());
+    assert_eq!(provider.get_transaction_count(carol.address()).await?, 0);
+
+    let mint_amount = get_mint_amount(url.clone(), abi.clone(), contract_address).await?;
+    for result in &results[..2] {
+        let balance =
+            get_token_balance(url.clone(), abi.clone(), contract_address, result.signer).await?;
+        assert_eq!(balance, mint_amount);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_calc_funding() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let sender = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // fresh accounts without any Ether
+    let accounts = generate_accounts(MNEMONIC, 100, 103)?;
+    let receivers: Vec<Address> = accounts.iter().map(|account| account.address()).collect();
+
+    let params = calc_funding_params(
+        url.clone(),
+        &abi,
+        contract_address,
+        "mint",
+        &[],
+        U256::ZERO,
+        30,
+        1,
+        &receivers,
+    )
+    .await?;
+    assert!(params.iter().all(|param| !param.amount.is_zero()));
+
+    let funding = distribute_direct(sender, url.clone(), params).await?;
+    assert!(funding.transfers.iter().all(|t| t.result.is_ok()));
+
+    // the calculated funding is enough to mint
+    let results = mint_loop_with(
+        accounts,
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        MintOptions::default(),
+    )
+    .await?;
+    assert!(results.iter().all(|result| result.result.is_ok()));
+
+    let mint_amount = get_mint_amount(url.clone(), abi.clone(), contract_address).await?;
+    for receiver in receivers {
+        let balance =
+            get_token_balance(url.clone(), abi.clone(), contract_address, receiver).await?;
+        assert_eq!(balance, mint_amount);
+    }
+
+    Ok(())
+}
+
+/// Compares the spawn strategies of `mint_loop_from_stream` on growing account sets.
+///
+/// Run with `cargo test --release bench_spawn_strategies -- --ignored --nocapture`.
+#[tokio::test(flavor = "multi_thread")]
+#[ignore = "benchmark"]
+async fn bench_spawn_strategies() -> Result<()> {
+    const SIZES: [usize; 3] = [100, 500, 1000];
+
+    let accounts = SIZES[SIZES.len() - 1] + 1;
+    let anvil = Anvil::default().args(["--accounts", &accounts.to_string()]);
+    let test_env = TestEnvironment::with_anvil(anvil, Some(accounts))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    for size in SIZES {
+        for strategy in [SpawnStrategy::JoinAll, SpawnStrategy::SpawnTasks] {
+            // a fresh contract, so every account can mint again
+            let contract_address = deploy_contract(provider.clone(), bytecode.clone()).await?;
+            let config = MintConfig::builder()
+                .max_concurrent(size)
+                .spawn_strategy(strategy)
+                .build()?;
+
+            let start = Instant::now();
+            let results = mint_loop_from_stream(
+                stream::iter(signers[1..=size].to_vec()),
+                url.clone(),
+                abi.clone(),
+                contract_address,
+                None,
+                None,
+                None,
+                config,
+            )
+            .await?;
+            let elapsed = start.elapsed();
+
+            assert!(results.iter().all(|result| result.result.is_ok()));
+            println!("{size:>5} accounts, {strategy:?}: {elapsed:?}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Forwards connections to `target`, dropping the ones opened during the first `down_for`.
+async fn flaky_proxy(target: &Url, down_for: Duration) -> Result<Url> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let proxy_url = format!("http://{}", listener.local_addr()?).parse()?;
+    let target = format!(
+        "{}:{}",
+        target.host_str().unwrap_or("127.0.0.1"),
+        target.port_or_known_default().unwrap_or(80)
+    );
+    let up_at = Instant::now() + down_for;
+
+    tokio::spawn(async move {
+        while let Ok((mut inbound, _)) = listener.accept().await {
+            if Instant::now() < up_at {
+                continue;
+            }
+
+            let target = target.clone();
+            tokio::spawn(async move {
+                if let Ok(mut outbound) = TcpStream::connect(target).await {
+                    let _ = copy_bidirectional(&mut inbound, &mut outbound).await;
+                }
+            });
+        }
+    });
+
+    Ok(proxy_url)
+}
+
+fn leaf(account: Address) -> B256 {
+    keccak256(account)
+}
+
+fn hash_pair(a: B256, b: B256) -> B256 {
+    let (first, second) = if a < b { (a, b) } else { (b, a) };
+    keccak256([first.as_slice(), second.as_slice()].concat())
+}
+
 async fn get_mint_amount(url: Url, abi: JsonAbi, contract_address: Address) -> Result<U256> {
     let mint_amount = call(url, abi, contract_address, "MINT_AMOUNT", &[]).await?;
 
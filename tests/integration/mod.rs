@@ -1,2 +1,23 @@
+pub mod balance_test;
+pub mod block_test;
+pub mod call_test;
+pub mod code_test;
+pub mod delegate_test;
+pub mod deploy_test;
 pub mod distribute_test;
+pub mod erc721_test;
+pub mod events_test;
+pub mod execute_test;
+pub mod fees_test;
 pub mod mint_test;
+pub mod multisend_test;
+pub mod nonce_test;
+pub mod offline_test;
+pub mod probe_test;
+#[cfg(feature = "simulation")]
+pub mod simulate_test;
+pub mod storage_test;
+pub mod sweep_test;
+pub mod token_test;
+pub mod transfer_test;
+pub mod watch_test;
@@ -0,0 +1,98 @@
+use crate::common::{deploy_contract, parse_artifact, TestEnvironment};
+use alloy::dyn_abi::DynSolValue;
+use alloy::primitives::utils::parse_ether;
+use alloy::primitives::{Address, U256};
+use alloy::providers::Provider;
+use eyre::Result;
+use stormint::distributor::{multisend, MultiCall};
+use stormint::executor::{build_calldata, call};
+
+const MULTISEND_ARTIFACT_PATH: &str = "contracts/out/MockMultiSend.sol/MockMultiSend.json";
+const DISTRIBUTOR_ARTIFACT_PATH: &str = "contracts/out/Distributor.sol/Distributor.json";
+const MINT_ARTIFACT_PATH: &str = "contracts/out/FreeMint.sol/FreeMint.json";
+
+#[tokio::test]
+async fn test_multisend() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (_, bytecode) = parse_artifact(MULTISEND_ARTIFACT_PATH)?;
+    let multisend_address = deploy_contract(provider.clone(), bytecode).await?;
+    let (distributor_abi, bytecode) = parse_artifact(DISTRIBUTOR_ARTIFACT_PATH)?;
+    let distributor_address = deploy_contract(provider.clone(), bytecode).await?;
+    let (mint_abi, bytecode) = parse_artifact(MINT_ARTIFACT_PATH)?;
+    let mint_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let (alice, bob) = (Address::repeat_byte(0xa1), Address::repeat_byte(0xb0));
+    let amount = parse_ether("0.001")?;
+    let txns = |receiver: Address| {
+        DynSolValue::Array(vec![DynSolValue::Tuple(vec![
+            DynSolValue::from(receiver),
+            DynSolValue::from(amount),
+        ])])
+    };
+
+    let calls = vec![
+        MultiCall {
+            target: mint_address,
+            value: U256::ZERO,
+            calldata: build_calldata(
+                &mint_abi,
+                "approve",
+                &[DynSolValue::from(alice), DynSolValue::from(U256::from(100))],
+            )?,
+        },
+        MultiCall {
+            target: distributor_address,
+            value: amount,
+            calldata: build_calldata(&distributor_abi, "distributeEther", &[txns(alice)])?,
+        },
+        MultiCall {
+            target: distributor_address,
+            value: amount,
+            calldata: build_calldata(&distributor_abi, "distributeEther", &[txns(bob)])?,
+        },
+    ];
+
+    let result = multisend(signer.clone(), url.clone(), multisend_address, calls).await?;
+    assert!(result.status);
+    assert_eq!(result.call_count, 3);
+    assert_eq!(result.total_value, amount * U256::from(2));
+
+    // the calls were made by the multisend contract
+    let allowance = call(
+        url.clone(),
+        mint_abi.clone(),
+        mint_address,
+        "allowance",
+        &[
+            DynSolValue::from(multisend_address),
+            DynSolValue::from(alice),
+        ],
+    )
+    .await?;
+    assert_eq!(allowance[0], DynSolValue::from(U256::from(100)));
+    assert_eq!(provider.get_balance(alice).await?, amount);
+    assert_eq!(provider.get_balance(bob).await?, amount);
+
+    // `mint` rejects contract callers, which reverts the whole batch
+    let calls = vec![
+        MultiCall {
+            target: distributor_address,
+            value: amount,
+            calldata: build_calldata(&distributor_abi, "distributeEther", &[txns(alice)])?,
+        },
+        MultiCall {
+            target: mint_address,
+            value: U256::ZERO,
+            calldata: build_calldata(&mint_abi, "mint", &[])?,
+        },
+    ];
+    assert!(multisend(signer, url, multisend_address, calls)
+        .await
+        .is_err());
+    assert_eq!(provider.get_balance(alice).await?, amount);
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use crate::common::TestEnvironment;
+use alloy::network::TransactionBuilder;
+use alloy::primitives::{Address, U256};
+use alloy::providers::Provider;
+use alloy::rpc::types::TransactionRequest;
+use eyre::Result;
+use stormint::executor::{get_nonce, get_pending_nonce, nonce_gap};
+
+#[tokio::test]
+async fn test_nonce_gap() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+    let sender = signers[0].address();
+
+    assert_eq!(nonce_gap(url.clone(), sender).await?, 0);
+
+    // from now on blocks are only mined on request
+    provider
+        .raw_request::<_, ()>("evm_setAutomine".into(), (false,))
+        .await?;
+    let confirmed = get_nonce(url.clone(), sender).await?;
+
+    for _ in 0..3 {
+        let tx = TransactionRequest::default()
+            .with_from(sender)
+            .with_to(Address::random())
+            .with_value(U256::from(1));
+        provider.send_transaction(tx).await?;
+    }
+
+    assert_eq!(get_nonce(url.clone(), sender).await?, confirmed);
+    assert_eq!(get_pending_nonce(url.clone(), sender).await?, confirmed + 3);
+    assert_eq!(nonce_gap(url.clone(), sender).await?, 3);
+
+    provider
+        .raw_request::<_, String>("evm_mine".into(), ())
+        .await?;
+
+    assert_eq!(get_nonce(url.clone(), sender).await?, confirmed + 3);
+    assert_eq!(nonce_gap(url, sender).await?, 0);
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use crate::common::{deploy_contract, get_token_balance, parse_artifact, TestEnvironment};
+use alloy::providers::Provider;
+use eyre::Result;
+use stormint::executor::{broadcast_signed, sign_transaction, GasParams};
+
+const ARTIFACT_PATH: &str = "contracts/out/FreeMint.sol/FreeMint.json";
+
+#[tokio::test]
+async fn test_sign_and_broadcast() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(2))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+    let minter = signers[1].clone();
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // everything the signer needs is fetched up front, as on an online machine
+    let nonce = provider.get_transaction_count(minter.address()).await?;
+    let chain_id = provider.get_chain_id().await?;
+    let fees = provider.estimate_eip1559_fees(None).await?;
+    let gas = GasParams {
+        gas_limit: Some(200_000),
+        max_fee_per_gas: fees.max_fee_per_gas,
+        max_priority_fee_per_gas: fees.max_priority_fee_per_gas,
+    };
+
+    let signed_tx = sign_transaction(
+        &minter,
+        url.clone(),
+        &abi,
+        contract_address,
+        "mint",
+        &[],
+        None,
+        nonce,
+        chain_id,
+        gas,
+    )
+    .await?;
+
+    // signing alone does not touch the chain
+    assert_eq!(
+        provider.get_transaction_count(minter.address()).await?,
+        nonce
+    );
+
+    let tx_hash = broadcast_signed(url.clone(), &signed_tx).await?;
+
+    let receipt = provider.get_transaction_receipt(tx_hash).await?.unwrap();
+    assert!(receipt.status());
+    assert_eq!(receipt.from, minter.address());
+
+    let balance = get_token_balance(url, abi, contract_address, minter.address()).await?;
+    assert!(!balance.is_zero());
+
+    Ok(())
+}
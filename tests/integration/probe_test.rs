@@ -0,0 +1,47 @@
+use crate::common::{deploy_contract, parse_artifact, TestEnvironment};
+use eyre::Result;
+use stormint::executor::{probe_erc_interface, ErcInterface};
+
+const TOKEN_ARTIFACT_PATH: &str = "contracts/out/MockToken.sol/MockToken.json";
+const NFT_ARTIFACT_PATH: &str = "contracts/out/MockNFT.sol/MockNFT.json";
+const DISTRIBUTOR_ARTIFACT_PATH: &str = "contracts/out/Distributor.sol/Distributor.json";
+
+#[tokio::test]
+async fn test_probe_erc_interface() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+
+    let (_, bytecode) = parse_artifact(TOKEN_ARTIFACT_PATH)?;
+    let token_address = deploy_contract(provider.clone(), bytecode).await?;
+    let token = probe_erc_interface(url.clone(), token_address).await?;
+    assert_eq!(
+        token,
+        ErcInterface {
+            is_erc20: true,
+            is_erc721: false,
+            is_erc1155: false,
+            supports_mint: true,
+        }
+    );
+
+    let (_, bytecode) = parse_artifact(NFT_ARTIFACT_PATH)?;
+    let nft_address = deploy_contract(provider.clone(), bytecode).await?;
+    let nft = probe_erc_interface(url.clone(), nft_address).await?;
+    assert_eq!(
+        nft,
+        ErcInterface {
+            is_erc20: false,
+            is_erc721: true,
+            is_erc1155: false,
+            supports_mint: true,
+        }
+    );
+
+    // a contract that is not a token at all
+    let (_, bytecode) = parse_artifact(DISTRIBUTOR_ARTIFACT_PATH)?;
+    let distributor_address = deploy_contract(provider.clone(), bytecode).await?;
+    let distributor = probe_erc_interface(url.clone(), distributor_address).await?;
+    assert_eq!(distributor, ErcInterface::default());
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use crate::common::{deploy_contract, get_token_balance, parse_artifact, TestEnvironment};
+use alloy::primitives::utils::parse_ether;
+use alloy::primitives::U256;
+use alloy::signers::local::PrivateKeySigner;
+use eyre::Result;
+use stormint::executor::{simulate_on_fork, PlannedCall, SimulationPlan};
+
+const ARTIFACT_PATH: &str = "contracts/out/FreeMint.sol/FreeMint.json";
+
+#[tokio::test]
+async fn test_simulate_on_fork() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // fresh accounts without any ether on the forked chain
+    let (alice, bob) = (PrivateKeySigner::random(), PrivateKeySigner::random());
+    let calls = [&alice, &bob]
+        .into_iter()
+        .map(|signer| PlannedCall {
+            signer: signer.clone(),
+            abi: abi.clone(),
+            contract_address,
+            function_name: "mint".to_string(),
+            args: vec![],
+            value: None,
+        })
+        .collect();
+    let plan = SimulationPlan {
+        calls,
+        fund_amount: parse_ether("1")?,
+    };
+
+    let results = simulate_on_fork(url.clone(), None, plan).await?;
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|result| result.succeeded()));
+
+    // the forked chain is left untouched
+    for signer in [alice, bob] {
+        let balance =
+            get_token_balance(url.clone(), abi.clone(), contract_address, signer.address()).await?;
+        assert_eq!(balance, U256::ZERO);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use crate::common::{deploy_contract, parse_artifact, TestEnvironment};
+use alloy::dyn_abi::DynSolValue;
+use alloy::eips::BlockId;
+use alloy::primitives::utils::parse_ether;
+use alloy::primitives::{B256, U256};
+use alloy::providers::Provider;
+use eyre::Result;
+use stormint::executor::{execute, read_mapping_slot, read_storage};
+
+const TOKEN_ARTIFACT_PATH: &str = "contracts/out/MockToken.sol/MockToken.json";
+
+/// Slot of `_balances` in OpenZeppelin's ERC20.
+const BALANCES_SLOT: u64 = 0;
+/// Slot of `_totalSupply` in OpenZeppelin's ERC20.
+const TOTAL_SUPPLY_SLOT: u64 = 2;
+
+#[tokio::test]
+async fn test_read_storage() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(2))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (token_abi, bytecode) = parse_artifact(TOKEN_ARTIFACT_PATH)?;
+    let token_address = deploy_contract(provider.clone(), bytecode).await?;
+    let deployed_block = provider.get_block_number().await?;
+
+    let amount = parse_ether("42")?;
+    execute(
+        signers[0].clone(),
+        url.clone(),
+        token_abi,
+        token_address,
+        "mintTo",
+        &[
+            DynSolValue::from(signers[0].address()),
+            DynSolValue::from(amount),
+        ],
+        None,
+    )
+    .await?;
+
+    let balance = read_mapping_slot(
+        url.clone(),
+        token_address,
+        BALANCES_SLOT,
+        signers[0].address(),
+    )
+    .await?;
+    assert_eq!(balance, B256::from(amount));
+
+    let balance = read_mapping_slot(
+        url.clone(),
+        token_address,
+        BALANCES_SLOT,
+        signers[1].address(),
+    )
+    .await?;
+    assert_eq!(balance, B256::ZERO);
+
+    let total_supply = read_storage(
+        url.clone(),
+        token_address,
+        U256::from(TOTAL_SUPPLY_SLOT),
+        None,
+    )
+    .await?;
+    assert_eq!(total_supply, B256::from(amount));
+
+    // nothing was minted when the token was deployed
+    let total_supply = read_storage(
+        url,
+        token_address,
+        U256::from(TOTAL_SUPPLY_SLOT),
+        Some(BlockId::number(deployed_block)),
+    )
+    .await?;
+    assert_eq!(total_supply, B256::ZERO);
+
+    Ok(())
+}
@@ -0,0 +1,200 @@
+use crate::common::{deploy_contract, get_token_balance, parse_artifact, TestEnvironment};
+use alloy::dyn_abi::DynSolValue;
+use alloy::primitives::utils::parse_ether;
+use alloy::primitives::{Address, U256};
+use alloy::providers::Provider;
+use alloy::signers::local::PrivateKeySigner;
+use eyre::Result;
+use stormint::account::generate_accounts;
+use stormint::distributor::{
+    distribute, drain_accounts, sweep, sweep_token, DistributeParam, InsufficientGasError,
+};
+use stormint::executor::{call, execute};
+use stormint::mint::{mint_loop_with, MintOptions};
+
+const DISTRIBUTOR_ARTIFACT_PATH: &str = "contracts/out/Distributor.sol/Distributor.json";
+const FREE_MINT_ARTIFACT_PATH: &str = "contracts/out/FreeMint.sol/FreeMint.json";
+const TOKEN_ARTIFACT_PATH: &str = "contracts/out/MockToken.sol/MockToken.json";
+const MNEMONIC: &str = "test test test test test test test test test test test junk";
+const START_INDEX: u32 = 100;
+const END_INDEX: u32 = 112;
+
+#[tokio::test]
+async fn test_sweep() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(DISTRIBUTOR_ARTIFACT_PATH)?;
+    let distributor_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // the last two accounts only get dust that cannot pay for the transfer
+    let accounts = generate_accounts(MNEMONIC, START_INDEX, END_INDEX)?;
+    let (each_amount, dust) = (parse_ether("0.001")?, U256::from(1000));
+    let params: Vec<DistributeParam> = accounts
+        .iter()
+        .enumerate()
+        .map(|(i, account)| DistributeParam {
+            receiver: account.address(),
+            amount: if i < 10 { each_amount } else { dust },
+        })
+        .collect();
+    distribute(signer, url.clone(), abi, distributor_address, params).await?;
+
+    let master = Address::repeat_byte(0x42);
+    let results = sweep(accounts.clone(), url.clone(), master, U256::ZERO).await?;
+
+    assert_eq!(results.len(), accounts.len());
+    for result in &results {
+        let balance = provider.get_balance(result.account).await?;
+        match result.result.as_ref().unwrap() {
+            Some(_) => assert!(balance < each_amount / U256::from(10)),
+            None => assert_eq!(balance, dust),
+        }
+    }
+    let swept = results
+        .iter()
+        .filter(|result| matches!(result.result, Ok(Some(_))))
+        .count();
+    assert_eq!(swept, 10);
+
+    // the master regains almost everything
+    let master_balance = provider.get_balance(master).await?;
+    assert!(master_balance >= U256::from(10) * parse_ether("0.0009")?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sweep_leave_behind_overflow() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let url = test_env.url;
+    let account = test_env.signers[1].clone();
+
+    // the transfer cost plus `leave_behind` does not fit in a U256
+    let results = sweep(vec![account], url, Address::repeat_byte(0x42), U256::MAX).await?;
+
+    let error = results[0].result.as_ref().unwrap_err();
+    assert!(error.to_string().contains("overflows"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_drain_accounts() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(DISTRIBUTOR_ARTIFACT_PATH)?;
+    let distributor_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // the last account holds less than what is left for gas
+    let accounts = generate_accounts(MNEMONIC, END_INDEX, END_INDEX + 4)?;
+    let (each_amount, leave_for_gas) = (parse_ether("0.01")?, parse_ether("0.001")?);
+    let mut params = DistributeParam::from_accounts(&accounts[..3], each_amount);
+    params.extend(DistributeParam::from_accounts(
+        &accounts[3..],
+        leave_for_gas,
+    ));
+    distribute(signer, url.clone(), abi, distributor_address, params).await?;
+
+    let destination = Address::repeat_byte(0x24);
+    let results = drain_accounts(accounts.clone(), url.clone(), destination, leave_for_gas).await?;
+
+    assert_eq!(results.len(), accounts.len());
+    for (result, account) in results[..3].iter().zip(&accounts) {
+        assert_eq!(result.source, account.address());
+        assert!(result.result.is_ok());
+        assert_eq!(result.amount_sent, each_amount - leave_for_gas);
+
+        // the gas was paid out of what was left
+        let balance = provider.get_balance(account.address()).await?;
+        assert!(balance < leave_for_gas);
+    }
+    assert!(results[3].result.is_err());
+    assert_eq!(results[3].amount_sent, U256::ZERO);
+
+    let balance = provider.get_balance(destination).await?;
+    assert_eq!(balance, U256::from(3) * (each_amount - leave_for_gas));
+
+    // a reserve that cannot pay for the transfer is rejected
+    let results = drain_accounts(accounts[..1].to_vec(), url, destination, U256::from(1)).await?;
+    assert!(results[0].result.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sweep_token() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(4))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(FREE_MINT_ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let accounts = signers[1..].to_vec();
+    mint_loop_with(
+        accounts.clone(),
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        MintOptions::default(),
+    )
+    .await?;
+
+    let collector = Address::repeat_byte(0x42);
+    let results = sweep_token(accounts, url.clone(), contract_address, collector).await?;
+    assert!(results
+        .iter()
+        .all(|result| matches!(result.result, Ok(Some(_)))));
+
+    let mint_amount = call(
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        "MINT_AMOUNT",
+        &[],
+    )
+    .await?;
+    let mint_amount = mint_amount[0].as_uint().unwrap().0;
+    let balance = get_token_balance(url.clone(), abi, contract_address, collector).await?;
+    assert_eq!(balance, U256::from(3) * mint_amount);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sweep_token_insufficient_gas() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(TOKEN_ARTIFACT_PATH)?;
+    let token_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // an account holding tokens but no ether, and one holding nothing at all
+    let (holder, empty) = (PrivateKeySigner::random(), PrivateKeySigner::random());
+    execute(
+        signer,
+        url.clone(),
+        abi,
+        token_address,
+        "mintTo",
+        &[
+            DynSolValue::from(holder.address()),
+            DynSolValue::from(parse_ether("1")?),
+        ],
+        None,
+    )
+    .await?;
+
+    let collector = Address::repeat_byte(0x42);
+    let results = sweep_token(vec![holder, empty], url, token_address, collector).await?;
+
+    let error = results[0].result.as_ref().unwrap_err();
+    assert!(error.downcast_ref::<InsufficientGasError>().is_some());
+    assert!(matches!(results[1].result, Ok(None)));
+
+    Ok(())
+}
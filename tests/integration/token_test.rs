@@ -0,0 +1,220 @@
+use crate::common::{deploy_contract, get_token_balance, parse_artifact, TestEnvironment};
+use alloy::dyn_abi::DynSolValue;
+use alloy::primitives::utils::parse_ether;
+use alloy::primitives::U256;
+use alloy::providers::Provider;
+use eyre::Result;
+use stormint::account::generate_accounts;
+use stormint::distributor::{
+    distribute_to_token_holders, distribute_token, distribute_token_with_approval, ApprovalPolicy,
+    DistributeParam,
+};
+use stormint::executor::execute;
+
+const DISTRIBUTOR_ARTIFACT_PATH: &str = "contracts/out/Distributor.sol/Distributor.json";
+const TOKEN_ARTIFACT_PATH: &str = "contracts/out/MockToken.sol/MockToken.json";
+const MNEMONIC: &str = "test test test test test test test test test test test junk";
+const START_INDEX: u32 = 100;
+const END_INDEX: u32 = 110;
+
+#[tokio::test]
+async fn test_distribute_token() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(DISTRIBUTOR_ARTIFACT_PATH)?;
+    let distributor_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let (token_abi, bytecode) = parse_artifact(TOKEN_ARTIFACT_PATH)?;
+    let token_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // fund the sender with tokens
+    let each_amount = parse_ether("10")?;
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, END_INDEX)?;
+    let total = each_amount * U256::from(receivers.len());
+    execute(
+        signer.clone(),
+        url.clone(),
+        token_abi.clone(),
+        token_address,
+        "mintTo",
+        &[
+            DynSolValue::from(signer.address()),
+            DynSolValue::from(total),
+        ],
+        None,
+    )
+    .await?;
+
+    let params = DistributeParam::from_accounts(&receivers, each_amount);
+
+    let distribute_tx = distribute_token(
+        signer.clone(),
+        url.clone(),
+        abi,
+        distributor_address,
+        token_address,
+        params,
+        true,
+    )
+    .await?;
+
+    let distribute_receipt = provider
+        .get_transaction_receipt(distribute_tx)
+        .await?
+        .unwrap();
+    assert!(distribute_receipt.status());
+
+    // check token balances
+    for receiver in receivers {
+        let balance = get_token_balance(
+            url.clone(),
+            token_abi.clone(),
+            token_address,
+            receiver.address(),
+        )
+        .await?;
+        assert_eq!(balance, each_amount);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_distribute_token_max_approval() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let (abi, bytecode) = parse_artifact(DISTRIBUTOR_ARTIFACT_PATH)?;
+    let distributor_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let (token_abi, bytecode) = parse_artifact(TOKEN_ARTIFACT_PATH)?;
+    let token_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let each_amount = parse_ether("10")?;
+    let receivers = generate_accounts(MNEMONIC, START_INDEX, END_INDEX)?;
+    let params = DistributeParam::from_signers(&receivers, each_amount)?;
+    execute(
+        signer.clone(),
+        url.clone(),
+        token_abi.clone(),
+        token_address,
+        "mintTo",
+        &[
+            DynSolValue::from(signer.address()),
+            DynSolValue::from(each_amount * U256::from(2 * receivers.len())),
+        ],
+        None,
+    )
+    .await?;
+
+    let nonce_before = provider.get_transaction_count(signer.address()).await?;
+
+    for _ in 0..2 {
+        distribute_token_with_approval(
+            signer.clone(),
+            url.clone(),
+            abi.clone(),
+            distributor_address,
+            token_address,
+            params.clone(),
+            ApprovalPolicy::Max,
+        )
+        .await?;
+    }
+
+    // one approve and two distributions
+    let nonce_after = provider.get_transaction_count(signer.address()).await?;
+    assert_eq!(nonce_after - nonce_before, 3);
+
+    for receiver in receivers {
+        let balance = get_token_balance(
+            url.clone(),
+            token_abi.clone(),
+            token_address,
+            receiver.address(),
+        )
+        .await?;
+        assert_eq!(balance, each_amount * U256::from(2));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_distribute_to_token_holders() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(2))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+    let (signer, former_holder) = (signers[0].clone(), signers[1].clone());
+
+    let (abi, bytecode) = parse_artifact(DISTRIBUTOR_ARTIFACT_PATH)?;
+    let distributor_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let (token_abi, bytecode) = parse_artifact(TOKEN_ARTIFACT_PATH)?;
+    let token_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // mint to the holders, the sender and an account that later sends everything away
+    let holders = generate_accounts(MNEMONIC, START_INDEX, START_INDEX + 3)?;
+    let token_amount = parse_ether("10")?;
+    let accounts = holders
+        .iter()
+        .map(|holder| holder.address())
+        .chain([signer.address(), former_holder.address()]);
+    for account in accounts {
+        execute(
+            signer.clone(),
+            url.clone(),
+            token_abi.clone(),
+            token_address,
+            "mintTo",
+            &[DynSolValue::from(account), DynSolValue::from(token_amount)],
+            None,
+        )
+        .await?;
+    }
+    execute(
+        former_holder.clone(),
+        url.clone(),
+        token_abi.clone(),
+        token_address,
+        "transfer",
+        &[
+            DynSolValue::from(holders[0].address()),
+            DynSolValue::from(token_amount),
+        ],
+        None,
+    )
+    .await?;
+    let former_balance = provider.get_balance(former_holder.address()).await?;
+
+    let amount_each = parse_ether("0.001")?;
+    let distribute_tx = distribute_to_token_holders(
+        signer,
+        url.clone(),
+        abi,
+        distributor_address,
+        token_abi,
+        token_address,
+        amount_each,
+        0,
+        None,
+    )
+    .await?;
+
+    let distribute_receipt = provider
+        .get_transaction_receipt(distribute_tx)
+        .await?
+        .unwrap();
+    assert!(distribute_receipt.status());
+
+    for holder in holders {
+        let balance = provider.get_balance(holder.address()).await?;
+        assert_eq!(balance, amount_each);
+    }
+    let balance = provider.get_balance(former_holder.address()).await?;
+    assert_eq!(balance, former_balance);
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use crate::common::{deploy_contract, parse_artifact, TestEnvironment};
+use alloy::primitives::{utils::parse_ether, Address, U256};
+use alloy::providers::Provider;
+use eyre::Result;
+use stormint::executor::simulate_eth_transfer;
+
+const FREE_MINT_ARTIFACT_PATH: &str = "contracts/out/FreeMint.sol/FreeMint.json";
+
+#[tokio::test]
+async fn test_simulate_eth_transfer() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+    let sender = signers[0].address();
+    let value = parse_ether("1")?;
+
+    assert!(simulate_eth_transfer(url.clone(), sender, Address::random(), value).await?);
+
+    // more than the sender holds
+    let balance = provider.get_balance(sender).await?;
+    let too_much = balance + U256::from(1);
+    assert!(!simulate_eth_transfer(url.clone(), sender, Address::random(), too_much).await?);
+    // nothing was sent
+    assert_eq!(provider.get_balance(sender).await?, balance);
+
+    // FreeMint has neither a receive nor a payable fallback function
+    let (_, bytecode) = parse_artifact(FREE_MINT_ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+    assert!(!simulate_eth_transfer(url, sender, contract_address, value).await?);
+
+    Ok(())
+}
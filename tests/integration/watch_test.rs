@@ -0,0 +1,102 @@
+use crate::common::TestEnvironment;
+use alloy::network::TransactionBuilder;
+use alloy::primitives::{Address, TxHash, U256};
+use alloy::providers::Provider;
+use alloy::rpc::types::TransactionRequest;
+use alloy_node_bindings::Anvil;
+use eyre::Result;
+use std::time::Duration;
+use stormint::executor::{transaction_status, watch_pending_tx, TransactionStatus, TxStatus};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+const TIMEOUT: Duration = Duration::from_secs(1);
+
+#[tokio::test]
+async fn test_watch_confirmed_tx() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+
+    let tx = TransactionRequest::default()
+        .with_to(Address::repeat_byte(0x42))
+        .with_value(U256::from(1));
+    let tx_hash = *provider.send_transaction(tx).await?.tx_hash();
+
+    let status = watch_pending_tx(url, tx_hash, POLL_INTERVAL, TIMEOUT).await?;
+    assert!(matches!(status, TxStatus::Confirmed(receipt) if receipt.status()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_watch_dropped_tx() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+
+    let status = watch_pending_tx(
+        test_env.url,
+        TxHash::repeat_byte(0x42),
+        POLL_INTERVAL,
+        TIMEOUT,
+    )
+    .await?;
+    assert!(matches!(status, TxStatus::Dropped));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_watch_timed_out_tx() -> Result<()> {
+    // with mining disabled the transaction stays in the mempool
+    let anvil = Anvil::default().arg("--no-mining");
+    let test_env = TestEnvironment::with_anvil(anvil, None)?;
+    let (provider, url) = (test_env.provider, test_env.url);
+
+    let tx = TransactionRequest::default()
+        .with_to(Address::repeat_byte(0x42))
+        .with_value(U256::from(1));
+    let tx_hash = *provider.send_transaction(tx).await?.tx_hash();
+
+    let status = watch_pending_tx(url, tx_hash, POLL_INTERVAL, TIMEOUT).await?;
+    assert!(matches!(status, TxStatus::TimedOut));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transaction_status() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+
+    let tx = TransactionRequest::default()
+        .with_to(Address::repeat_byte(0x42))
+        .with_value(U256::from(1));
+    let receipt = provider.send_transaction(tx).await?.get_receipt().await?;
+
+    let status = transaction_status(url.clone(), receipt.transaction_hash).await?;
+    assert!(status.confirmed);
+    assert_eq!(status.block_number, receipt.block_number);
+    assert_eq!(status.success, Some(true));
+    assert_eq!(status.gas_used, Some(21_000));
+
+    let status = transaction_status(url, TxHash::repeat_byte(0x42)).await?;
+    assert_eq!(status, TransactionStatus::default());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transaction_status_pending() -> Result<()> {
+    let anvil = Anvil::default().arg("--no-mining");
+    let test_env = TestEnvironment::with_anvil(anvil, None)?;
+    let (provider, url) = (test_env.provider, test_env.url);
+
+    let tx = TransactionRequest::default()
+        .with_to(Address::repeat_byte(0x42))
+        .with_value(U256::from(1));
+    let tx_hash = *provider.send_transaction(tx).await?.tx_hash();
+
+    let status = transaction_status(url, tx_hash).await?;
+    assert!(!status.confirmed);
+    assert_eq!(status.success, None);
+
+    Ok(())
+}